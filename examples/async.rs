@@ -0,0 +1,32 @@
+pub type User = &'static str;
+
+#[derive(::core::default::Default)]
+pub struct UserService {
+    users: ::tokio::sync::Mutex<::std::vec::Vec<User>>,
+}
+
+impl UserService {
+    pub async fn register(&self, user: User) {
+        ::tokio::task::yield_now().await;
+        self.users.lock().await.push(user);
+    }
+
+    pub async fn contains(&self, user: User) -> bool {
+        ::tokio::task::yield_now().await;
+        self.users.lock().await.contains(&user)
+    }
+}
+
+#[rustfmt::skip]
+fn main() -> ::std::process::ExitCode {
+    ::litmus::Runner::new()
+        .executor(::litmus::TokioExecutor::new())
+        .feature(::litmus::Feature::new()
+            .description("Registering users through an asynchronous service")
+            .scenario(::litmus::Scenario::<UserService>::new()
+                .given("an empty service", |_| {})
+                .when_async("registering user `Alice`", async |service| service.register("Alice").await)
+                .then_async("it contains `Alice`", async |service| ::litmus::assert!(service.contains("Alice").await))
+                .but_async("it does not contain `Bob`", async |service| ::litmus::assert!(!service.contains("Bob").await))))
+        .run()
+}
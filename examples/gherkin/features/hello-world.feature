@@ -0,0 +1,22 @@
+@cucumbers
+Feature: Eating too much cucumbers may not be good for you
+
+  Background:
+    Given Alice is hungry
+
+  Scenario: Eating a few isn't a problem
+    When she eats 3 cucumbers
+    Then she is full
+
+  Rule: Eating too many is a problem
+
+    Scenario Outline: Eating a few more is fine
+      When she eats <eaten> cucumbers
+      And she eats 1 cucumber
+      Then she is full
+      But she did not explode
+
+      Examples:
+        | eaten |
+        | 2     |
+        | 3     |
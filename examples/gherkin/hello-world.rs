@@ -0,0 +1,22 @@
+#[derive(::core::default::Default)]
+struct World {
+    user: ::core::option::Option<String>,
+    capacity: usize,
+}
+
+#[rustfmt::skip]
+fn main() -> ::std::process::ExitCode {
+    let registry = ::litmus::gherkin::StepRegistry::<World>::new()
+        .given("{word} is hungry", |w: &mut World, user: String| w.user = Some(user))
+        .when(r"^(?:he|she|they) eats? (\d+) cucumbers?$", |w: &mut World, count: usize| w.capacity += count)
+        .then("she is full", |w: &World| ::litmus::assert!(w.capacity >= 3, "Alice isn't full!"))
+        .then("she did not explode", |w: &World| ::litmus::assert!(w.capacity < 5, "Alice exploded"));
+
+    let feature = registry
+        .load(::core::concat!(::core::env!("CARGO_MANIFEST_DIR"), "/examples/gherkin/features/hello-world.feature"))
+        .unwrap_or_else(|err| ::core::panic!("{}", err));
+
+    ::litmus::Runner::new()
+        .feature(feature)
+        .run()
+}
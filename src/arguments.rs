@@ -0,0 +1,289 @@
+use crate::builders::*;
+use crate::models::*;
+
+/// A table written under a step, as in
+///
+/// ```gherkin
+/// Given the users
+///   | name  | age |
+///   | Alice | 30  |
+/// ```
+///
+/// Most tables have a header row naming their columns, which
+/// [`parse`](Self::parse) and [`column`](Self::column) rely on. Tables that
+/// rather name their rows in their first column are read with
+/// [`row`](Self::row), or turned around with [`transpose`](Self::transpose).
+#[derive(::core::fmt::Debug, ::core::clone::Clone, ::core::cmp::PartialEq, ::core::cmp::Eq)]
+pub struct DataTable {
+    rows: ::std::vec::Vec<::std::vec::Vec<::std::string::String>>,
+}
+
+/// A block of text written under a step between `"""` or ```` ``` ````, with
+/// an optional media type after the opening delimiter.
+#[derive(::core::fmt::Debug, ::core::clone::Clone, ::core::cmp::PartialEq, ::core::cmp::Eq)]
+pub struct DocString {
+    content: ::std::string::String,
+    media_type: ::core::option::Option<::std::string::String>,
+}
+
+/// What a step carries besides its text, if anything.
+#[derive(::core::fmt::Debug, ::core::clone::Clone)]
+pub enum StepArgument {
+    DataTable(DataTable),
+    DocString(DocString),
+}
+
+/// Converts a row of a [`DataTable`] with a header into a value.
+///
+/// ```ignore
+/// impl FromRow for User {
+///     fn from_row(row: &Row<'_>) -> Fallible<Self> {
+///         Ok(Self {
+///             name: row.get("name")?.to_owned(),
+///             age: row.parse("age")?,
+///         })
+///     }
+/// }
+/// ```
+pub trait FromRow: ::core::marker::Sized {
+    fn from_row(row: &Row<'_>) -> Fallible<Self>;
+}
+
+/// A row of a [`DataTable`], whose cells are looked up by the header cell of
+/// their column.
+pub struct Row<'a> {
+    header: &'a [::std::string::String],
+    cells: &'a [::std::string::String],
+
+    /// From 1, not counting the header.
+    number: usize,
+}
+
+impl DataTable {
+    pub fn new<Cells, Cell>(rows: impl IntoIterator<Item = Cells>) -> Self
+    where
+        Cells: IntoIterator<Item = Cell>,
+        Cell: Into<::std::string::String>,
+    {
+        Self {
+            rows: rows.into_iter().map(|cells| cells.into_iter().map(Into::into).collect()).collect(),
+        }
+    }
+
+    /// Every row, the header included.
+    pub fn rows(&self) -> &[::std::vec::Vec<::std::string::String>] {
+        &self.rows
+    }
+
+    pub fn header(&self) -> ::core::option::Option<&[::std::string::String]> {
+        self.rows.first().map(::std::vec::Vec::as_slice)
+    }
+
+    /// The cells under the header cell `name`, or `None` if there is no such
+    /// column.
+    pub fn column(&self, name: &str) -> ::core::option::Option<::std::vec::Vec<&str>> {
+        let index = self.header()?.iter().position(|cell| cell == name)?;
+
+        Some(self.rows.iter().skip(1).map(|cells| cells.get(index).map_or("", ::std::string::String::as_str)).collect())
+    }
+
+    /// The cells after the first one of the row starting with `name`, or
+    /// `None` if there is no such row.
+    pub fn row(&self, name: &str) -> ::core::option::Option<::std::vec::Vec<&str>> {
+        let cells = self.rows.iter().find(|cells| cells.first().is_some_and(|cell| cell == name))?;
+
+        Some(cells.iter().skip(1).map(::std::string::String::as_str).collect())
+    }
+
+    /// Swaps rows and columns, so that a table naming its rows in its first
+    /// column gets a header. Missing cells of shorter rows are left empty.
+    pub fn transpose(&self) -> Self {
+        let width = self.rows.iter().map(::std::vec::Vec::len).max().unwrap_or_default();
+
+        Self {
+            rows: (0..width)
+                .map(|index| self.rows.iter().map(|cells| cells.get(index).cloned().unwrap_or_default()).collect())
+                .collect(),
+        }
+    }
+
+    /// Converts every row after the header.
+    pub fn parse<T>(&self) -> Fallible<::std::vec::Vec<T>>
+    where
+        T: FromRow,
+    {
+        let Some((header, rows)) = self.rows.split_first() else {
+            return Ok(::std::vec::Vec::new());
+        };
+
+        rows.iter()
+            .enumerate()
+            .map(|(index, cells)| {
+                T::from_row(&Row {
+                    header,
+                    cells,
+                    number: index + 1,
+                })
+            })
+            .collect()
+    }
+
+    /// Compares the table, as expected, with the actual one, failing with a
+    /// line-by-line diff of their rows if they differ.
+    #[track_caller]
+    pub fn diff(&self, actual: &Self) -> Fallible {
+        if self == actual {
+            return Ok(());
+        }
+
+        let widths = column_widths(self.rows.iter().chain(&actual.rows));
+
+        let diff = crate::utils::diff(&self.rows, &actual.rows)
+            .into_iter()
+            .map(|(change, cells)| (change, render_row(cells, &widths)))
+            .collect();
+
+        Err(Failed::new("Tables differ").with_diff(diff))
+    }
+
+    /// The table as written under a step, with columns aligned.
+    pub(crate) fn lines(&self) -> impl Iterator<Item = ::std::string::String> + '_ {
+        let widths = column_widths(self.rows.iter());
+        self.rows.iter().map(move |cells| render_row(cells, &widths))
+    }
+}
+
+impl<Cells, Cell> FromIterator<Cells> for DataTable
+where
+    Cells: IntoIterator<Item = Cell>,
+    Cell: Into<::std::string::String>,
+{
+    fn from_iter<Rows: IntoIterator<Item = Cells>>(rows: Rows) -> Self {
+        Self::new(rows)
+    }
+}
+
+impl<'a> Row<'a> {
+    /// The cell under the header cell `column`.
+    #[track_caller]
+    pub fn get(&self, column: &str) -> Fallible<&'a str> {
+        let Some(index) = self.header.iter().position(|cell| cell == column) else {
+            return Err(::std::format!("Row {} has no `{}` column", self.number, column).into_failed());
+        };
+
+        Ok(self.cells.get(index).map_or("", ::std::string::String::as_str))
+    }
+
+    /// The cell under the header cell `column`, parsed.
+    #[track_caller]
+    pub fn parse<T>(&self, column: &str) -> Fallible<T>
+    where
+        T: ::core::str::FromStr,
+        T::Err: ::core::fmt::Display,
+    {
+        let cell = self.get(column)?;
+
+        // Matched rather than mapped, as a closure would be reported as where
+        // the failure was raised.
+        match cell.parse() {
+            Ok(value) => Ok(value),
+            Err(err) => Err(::std::format!(
+                "Failed to convert `{}` in the `{}` column of row {} into `{}`: {}",
+                cell,
+                column,
+                self.number,
+                ::core::any::type_name::<T>(),
+                err
+            )
+            .into_failed()),
+        }
+    }
+
+    /// Cells in the order of the header.
+    pub fn cells(&self) -> &'a [::std::string::String] {
+        self.cells
+    }
+}
+
+impl DocString {
+    pub fn new(content: impl Into<::std::string::String>) -> Self {
+        Self {
+            content: content.into(),
+            media_type: None,
+        }
+    }
+
+    pub fn media_type(self, media_type: impl Into<::std::string::String>) -> Self {
+        Self {
+            media_type: Some(media_type.into()),
+            ..self
+        }
+    }
+
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+
+    pub fn content_type(&self) -> ::core::option::Option<&str> {
+        self.media_type.as_deref()
+    }
+
+    /// The doc string as written under a step, delimiters included.
+    pub(crate) fn lines(&self) -> impl Iterator<Item = ::std::string::String> + '_ {
+        let opening = ::std::format!("\"\"\"{}", self.media_type.as_deref().unwrap_or_default());
+        let content = self.content.lines().map(|line| line.replace("\"\"\"", "\\\"\\\"\\\""));
+
+        ::core::iter::once(opening).chain(content).chain(::core::iter::once("\"\"\"".to_owned()))
+    }
+}
+
+impl AsRef<str> for DocString {
+    fn as_ref(&self) -> &str {
+        &self.content
+    }
+}
+
+impl ::core::fmt::Display for DocString {
+    fn fmt(&self, formatter: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        formatter.write_str(&self.content)
+    }
+}
+
+impl StepArgument {
+    pub(crate) fn lines(&self) -> ::std::vec::Vec<::std::string::String> {
+        match self {
+            Self::DataTable(table) => table.lines().collect(),
+            Self::DocString(doc_string) => doc_string.lines().collect(),
+        }
+    }
+}
+
+fn column_widths<'a>(rows: impl Iterator<Item = &'a ::std::vec::Vec<::std::string::String>>) -> ::std::vec::Vec<usize> {
+    rows.fold(::std::vec::Vec::new(), |mut widths, cells| {
+        widths.resize(widths.len().max(cells.len()), 0);
+
+        for (width, cell) in widths.iter_mut().zip(cells) {
+            *width = (*width).max(escape(cell).chars().count());
+        }
+
+        widths
+    })
+}
+
+fn render_row(cells: &[::std::string::String], widths: &[usize]) -> ::std::string::String {
+    let cells = widths
+        .iter()
+        .enumerate()
+        .map(|(index, width)| {
+            let cell = escape(cells.get(index).map_or("", ::std::string::String::as_str));
+            ::std::format!(" {}{} |", cell, " ".repeat(width - cell.chars().count()))
+        })
+        .collect::<::std::string::String>();
+
+    ::std::format!("|{}", cells)
+}
+
+/// Pipes, backslashes and newlines are escaped within cells, as in Gherkin.
+fn escape(cell: &str) -> ::std::string::String {
+    cell.replace('\\', "\\\\").replace('|', "\\|").replace('\n', "\\n")
+}
@@ -1,3 +1,5 @@
+use ::sealed::sealed;
+
 #[macro_export]
 macro_rules! assert {
     ($expr:expr, $message:expr) => {{
@@ -20,6 +22,257 @@ macro_rules! panic {
     };
 }
 
+/// Fails unless `actual == expected`, with both values rendered with `Debug`.
+/// Multi-line strings and long values are shown as a line diff instead.
+#[macro_export]
+macro_rules! assert_eq {
+    ($actual:expr, $expected:expr $(,)?) => {
+        $crate::assertions::assert_eq!(
+            $actual,
+            $expected,
+            ::std::concat!(
+                "assertion failed: `",
+                ::std::stringify!($actual),
+                " == ",
+                ::std::stringify!($expected),
+                "`"
+            )
+        )
+    };
+
+    ($actual:expr, $expected:expr, $message:expr $(,)?) => {
+        match (&$actual, &$expected) {
+            (actual, expected) => match *actual == *expected {
+                true => ::core::result::Result::Ok(()),
+                false => {
+                    #[allow(unused_imports)]
+                    use $crate::assertions::__DebugOperand as _;
+                    #[allow(unused_imports)]
+                    use $crate::assertions::__TextOperand as _;
+
+                    ::core::result::Result::Err(
+                        $crate::assertions::__mismatch(
+                            $message,
+                            (&$crate::assertions::__Operand(expected)).__lines(),
+                            (&$crate::assertions::__Operand(actual)).__lines(),
+                        )
+                        .with_expected(expected)
+                        .with_actual(actual),
+                    )
+                },
+            },
+        }
+    };
+}
+
+/// Fails if `actual == unexpected`.
+#[macro_export]
+macro_rules! assert_ne {
+    ($actual:expr, $unexpected:expr $(,)?) => {
+        $crate::assertions::assert_ne!(
+            $actual,
+            $unexpected,
+            ::std::concat!(
+                "assertion failed: `",
+                ::std::stringify!($actual),
+                " != ",
+                ::std::stringify!($unexpected),
+                "`"
+            )
+        )
+    };
+
+    ($actual:expr, $unexpected:expr, $message:expr $(,)?) => {
+        match (&$actual, &$unexpected) {
+            (actual, unexpected) => match *actual != *unexpected {
+                true => ::core::result::Result::Ok(()),
+                false => ::core::result::Result::Err(
+                    $crate::builders::models::IntoFailed::into_failed($message).with_actual(actual),
+                ),
+            },
+        }
+    };
+}
+
+/// Fails unless `actual < bound`.
+#[macro_export]
+macro_rules! assert_lt {
+    ($actual:expr, $bound:expr $(, $message:expr)? $(,)?) => {
+        $crate::assertions::__assert_ordering!(<, $actual, $bound $(, $message)?)
+    };
+}
+
+/// Fails unless `actual <= bound`.
+#[macro_export]
+macro_rules! assert_le {
+    ($actual:expr, $bound:expr $(, $message:expr)? $(,)?) => {
+        $crate::assertions::__assert_ordering!(<=, $actual, $bound $(, $message)?)
+    };
+}
+
+/// Fails unless `actual > bound`.
+#[macro_export]
+macro_rules! assert_gt {
+    ($actual:expr, $bound:expr $(, $message:expr)? $(,)?) => {
+        $crate::assertions::__assert_ordering!(>, $actual, $bound $(, $message)?)
+    };
+}
+
+/// Fails unless `actual >= bound`.
+#[macro_export]
+macro_rules! assert_ge {
+    ($actual:expr, $bound:expr $(, $message:expr)? $(,)?) => {
+        $crate::assertions::__assert_ordering!(>=, $actual, $bound $(, $message)?)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __assert_ordering {
+    ($operator:tt, $actual:expr, $bound:expr) => {
+        $crate::assertions::__assert_ordering!(
+            $operator,
+            $actual,
+            $bound,
+            ::std::concat!(
+                "assertion failed: `",
+                ::std::stringify!($actual),
+                " ",
+                ::std::stringify!($operator),
+                " ",
+                ::std::stringify!($bound),
+                "`"
+            )
+        )
+    };
+
+    ($operator:tt, $actual:expr, $bound:expr, $message:expr) => {
+        match (&$actual, &$bound) {
+            (actual, bound) => match *actual $operator *bound {
+                true => ::core::result::Result::Ok(()),
+                false => ::core::result::Result::Err(
+                    $crate::builders::models::IntoFailed::into_failed($message)
+                        .with_actual(actual)
+                        .with_context("bound", ::std::format!("{:?}", bound)),
+                ),
+            },
+        }
+    };
+}
+
+/// Fails unless `actual` matches the pattern, guard included.
+///
+/// ```ignore
+/// litmus::assert_matches!(response.status, Status::Created | Status::Accepted)
+/// ```
+#[macro_export]
+macro_rules! assert_matches {
+    ($actual:expr, $pattern:pat $(if $guard:expr)? $(,)?) => {
+        $crate::assertions::assert_matches!(
+            $actual,
+            $pattern $(if $guard)?,
+            ::std::concat!(
+                "assertion failed: `",
+                ::std::stringify!($actual),
+                "` matches `",
+                ::std::stringify!($pattern $(if $guard)?),
+                "`"
+            )
+        )
+    };
+
+    ($actual:expr, $pattern:pat $(if $guard:expr)?, $message:expr $(,)?) => {
+        match $actual {
+            $pattern $(if $guard)? => ::core::result::Result::Ok(()),
+            ref actual => ::core::result::Result::Err(
+                $crate::builders::models::IntoFailed::into_failed($message).with_actual(actual),
+            ),
+        }
+    };
+}
+
+/// Fails unless `haystack.contains(&needle)`, so that it applies to strings,
+/// slices, vectors and sets alike.
+#[macro_export]
+macro_rules! assert_contains {
+    ($haystack:expr, $needle:expr $(,)?) => {
+        $crate::assertions::assert_contains!(
+            $haystack,
+            $needle,
+            ::std::concat!(
+                "assertion failed: `",
+                ::std::stringify!($haystack),
+                "` contains `",
+                ::std::stringify!($needle),
+                "`"
+            )
+        )
+    };
+
+    ($haystack:expr, $needle:expr, $message:expr $(,)?) => {
+        match (&$haystack, &$needle) {
+            (haystack, needle) => match haystack.contains(needle) {
+                true => ::core::result::Result::Ok(()),
+                false => ::core::result::Result::Err(
+                    $crate::builders::models::IntoFailed::into_failed($message)
+                        .with_actual(haystack)
+                        .with_context("needle", ::std::format!("{:?}", needle)),
+                ),
+            },
+        }
+    };
+}
+
+/// Fails unless floats `actual` and `expected` are at most `epsilon` apart,
+/// which defaults to `1e-6`.
+#[macro_export]
+macro_rules! assert_approx_eq {
+    ($actual:expr, $expected:expr $(,)?) => {
+        $crate::assertions::assert_approx_eq!($actual, $expected, 1e-6)
+    };
+
+    ($actual:expr, $expected:expr, $epsilon:expr $(,)?) => {
+        $crate::assertions::assert_approx_eq!(
+            $actual,
+            $expected,
+            $epsilon,
+            ::std::concat!("assertion failed: `", ::std::stringify!($actual), " ≈ ", ::std::stringify!($expected), "`")
+        )
+    };
+
+    ($actual:expr, $expected:expr, $epsilon:expr, $message:expr $(,)?) => {
+        match (&$actual, &$expected, $epsilon) {
+            (actual, expected, epsilon) => {
+                let distance = $crate::assertions::__Approximate::__distance(*actual, *expected);
+
+                match distance <= epsilon {
+                    true => ::core::result::Result::Ok(()),
+                    false => ::core::result::Result::Err(
+                        $crate::builders::models::IntoFailed::into_failed($message)
+                            .with_expected(expected)
+                            .with_actual(actual)
+                            .with_context("difference", distance)
+                            .with_context("epsilon", epsilon),
+                    ),
+                }
+            },
+        }
+    };
+}
+
+/// Marks a step as yet to be implemented. The scenario stops there, and the
+/// step is reported as pending rather than failed.
+#[macro_export]
+macro_rules! pending {
+    () => {
+        $crate::assertions::pending!("Step is pending")
+    };
+
+    ($message:expr) => {
+        ::core::result::Result::Err(::litmus::assertions::__pending($message))
+    };
+}
+
 /// Gives nice backticks
 #[macro_export]
 macro_rules! format {
@@ -39,6 +292,1031 @@ where
     }
 }
 
+/// Collects the failures of checks made within [`soft`], rather than stopping
+/// at the first.
+pub struct Soft {
+    checks: usize,
+    failures: ::std::vec::Vec<crate::models::Failed>,
+}
+
+impl Soft {
+    /// Records the failure of `result`, if any, so that any assertion macro
+    /// may be checked softly.
+    ///
+    /// ```ignore
+    /// litmus::soft(|soft| {
+    ///     soft.check(litmus::assert_contains!(response.body, "Alice"));
+    ///     soft.assert_eq(&response.status, &200);
+    /// })
+    /// ```
+    pub fn check<T>(&mut self, result: crate::models::Fallible<T>) -> ::core::option::Option<T> {
+        self.checks += 1;
+
+        match result {
+            Ok(value) => Some(value),
+            Err(failed) => {
+                self.failures.push(failed);
+                None
+            },
+        }
+    }
+
+    #[track_caller]
+    pub fn assert(&mut self, condition: bool, message: impl crate::builders::models::IntoFailed) -> &mut Self {
+        self.check(match condition {
+            true => Ok(()),
+            false => Err(message.into_failed()),
+        });
+
+        self
+    }
+
+    #[track_caller]
+    pub fn assert_eq<T>(&mut self, actual: &T, expected: &T) -> &mut Self
+    where
+        T: ::core::cmp::PartialEq + ::core::fmt::Debug + ?::core::marker::Sized,
+    {
+        self.check(crate::assertions::assert_eq!(actual, expected));
+        self
+    }
+
+    #[track_caller]
+    pub fn assert_ne<T>(&mut self, actual: &T, unexpected: &T) -> &mut Self
+    where
+        T: ::core::cmp::PartialEq + ::core::fmt::Debug + ?::core::marker::Sized,
+    {
+        self.check(crate::assertions::assert_ne!(actual, unexpected));
+        self
+    }
+}
+
+/// Runs `checks`, failing with every check that failed within them, if any.
+#[track_caller]
+pub fn soft(checks: impl FnOnce(&mut Soft)) -> crate::models::Fallible {
+    let mut soft = Soft {
+        checks: 0,
+        failures: ::std::vec::Vec::new(),
+    };
+
+    checks(&mut soft);
+
+    if soft.failures.is_empty() {
+        return Ok(());
+    }
+
+    let summary = ::std::format!("{} of {} checks failed", soft.failures.len(), soft.checks);
+
+    Err(crate::models::Failed {
+        location: Some(::core::panic::Location::caller()),
+        ..crate::models::Failed::listing(summary, &soft.failures)
+    })
+}
+
+/// A reusable check of values of type `T`, passed to [`expect_that`] and
+/// composed with the likes of [`all_of`], [`not`] and [`each`].
+///
+/// Failures read as `expected:` followed by [`describe`](Self::describe),
+/// e.g. `greater than 3`, and `actual:` followed by the value and
+/// [`explain_mismatch`](Self::explain_mismatch), e.g. `2 which is not greater
+/// than 3`.
+pub trait Matcher<T: ?::core::marker::Sized> {
+    fn matches(&self, actual: &T) -> bool;
+
+    /// What matching values are, e.g. `a vector containing "Alice"`.
+    fn describe(&self) -> ::std::string::String;
+
+    /// Why `actual` does not match, e.g. `which does not contain "Alice"`.
+    fn explain_mismatch(&self, actual: &T) -> ::std::string::String {
+        let _ = actual;
+        ::std::format!("which is not {}", self.describe())
+    }
+}
+
+/// Fails unless `matcher` matches `actual`.
+///
+/// ```ignore
+/// litmus::expect_that(&names, all_of((contains("Alice"), not(contains("Bob")))))
+/// ```
+#[track_caller]
+pub fn expect_that<T, M>(actual: &T, matcher: M) -> crate::models::Fallible
+where
+    T: ::core::fmt::Debug + ?::core::marker::Sized,
+    M: Matcher<T>,
+{
+    if matcher.matches(actual) {
+        return Ok(());
+    }
+
+    // `Arguments` renders the same with `Debug` as with `Display`, so that
+    // descriptions are not quoted.
+    Err(crate::models::Failed::new("assertion failed: value does not match")
+        .with_expected(::core::format_args!("{}", matcher.describe()))
+        .with_actual(::core::format_args!("{} {}", rendered(actual), matcher.explain_mismatch(actual))))
+}
+
+/// Collections whose elements are matched by [`contains`], [`each`],
+/// [`elements_are`] and [`unordered_elements_are`].
+pub trait Collection {
+    type Element;
+
+    /// How the collection is referred to in descriptions, e.g. `a vector`.
+    const NOUN: &'static str;
+
+    fn elements(&self) -> impl Iterator<Item = &Self::Element>;
+}
+
+impl<T> Collection for [T] {
+    type Element = T;
+
+    const NOUN: &'static str = "a slice";
+
+    fn elements(&self) -> impl Iterator<Item = &Self::Element> {
+        self.iter()
+    }
+}
+
+impl<T, const N: usize> Collection for [T; N] {
+    type Element = T;
+
+    const NOUN: &'static str = "an array";
+
+    fn elements(&self) -> impl Iterator<Item = &Self::Element> {
+        self.iter()
+    }
+}
+
+impl<T> Collection for ::std::vec::Vec<T> {
+    type Element = T;
+
+    const NOUN: &'static str = "a vector";
+
+    fn elements(&self) -> impl Iterator<Item = &Self::Element> {
+        self.iter()
+    }
+}
+
+impl<T> Collection for ::std::collections::VecDeque<T> {
+    type Element = T;
+
+    const NOUN: &'static str = "a deque";
+
+    fn elements(&self) -> impl Iterator<Item = &Self::Element> {
+        self.iter()
+    }
+}
+
+impl<T, S> Collection for ::std::collections::HashSet<T, S> {
+    type Element = T;
+
+    const NOUN: &'static str = "a set";
+
+    fn elements(&self) -> impl Iterator<Item = &Self::Element> {
+        self.iter()
+    }
+}
+
+impl<T> Collection for ::std::collections::BTreeSet<T> {
+    type Element = T;
+
+    const NOUN: &'static str = "a set";
+
+    fn elements(&self) -> impl Iterator<Item = &Self::Element> {
+        self.iter()
+    }
+}
+
+/// Several matchers of values of type `T`, as a tuple, an array or a vector.
+#[sealed]
+pub trait Matchers<T: ?::core::marker::Sized> {
+    fn matchers(&self) -> ::std::vec::Vec<&dyn Matcher<T>>;
+}
+
+macro_rules! impl_matchers {
+    ($($matcher:ident),*) => {
+        #[sealed]
+        impl<T, $($matcher,)*> Matchers<T> for ($($matcher,)*)
+        where
+            T: ?::core::marker::Sized,
+            $($matcher: Matcher<T>,)*
+        {
+            #[allow(non_snake_case)]
+            fn matchers(&self) -> ::std::vec::Vec<&dyn Matcher<T>> {
+                let ($($matcher,)*) = self;
+                ::std::vec![$($matcher as &dyn Matcher<T>),*]
+            }
+        }
+    };
+}
+
+impl_matchers!(M0);
+impl_matchers!(M0, M1);
+impl_matchers!(M0, M1, M2);
+impl_matchers!(M0, M1, M2, M3);
+impl_matchers!(M0, M1, M2, M3, M4);
+impl_matchers!(M0, M1, M2, M3, M4, M5);
+impl_matchers!(M0, M1, M2, M3, M4, M5, M6);
+impl_matchers!(M0, M1, M2, M3, M4, M5, M6, M7);
+
+#[sealed]
+impl<T, M, const N: usize> Matchers<T> for [M; N]
+where
+    T: ?::core::marker::Sized,
+    M: Matcher<T>,
+{
+    fn matchers(&self) -> ::std::vec::Vec<&dyn Matcher<T>> {
+        self.iter().map(|matcher| matcher as &dyn Matcher<T>).collect()
+    }
+}
+
+#[sealed]
+impl<T, M> Matchers<T> for ::std::vec::Vec<M>
+where
+    T: ?::core::marker::Sized,
+    M: Matcher<T>,
+{
+    fn matchers(&self) -> ::std::vec::Vec<&dyn Matcher<T>> {
+        self.iter().map(|matcher| matcher as &dyn Matcher<T>).collect()
+    }
+}
+
+pub struct EqMatcher<E> {
+    expected: E,
+}
+
+/// Matches values equal to `expected`.
+pub fn eq<E>(expected: E) -> EqMatcher<E> {
+    EqMatcher {
+        expected,
+    }
+}
+
+impl<T, E> Matcher<T> for EqMatcher<E>
+where
+    T: ::core::cmp::PartialEq<E> + ?::core::marker::Sized,
+    E: ::core::fmt::Debug,
+{
+    fn matches(&self, actual: &T) -> bool {
+        *actual == self.expected
+    }
+
+    fn describe(&self) -> ::std::string::String {
+        ::std::format!("equal to {:?}", self.expected)
+    }
+}
+
+pub struct ComparisonMatcher<B> {
+    bound: B,
+    comparison: Comparison,
+}
+
+#[derive(::core::clone::Clone, ::core::marker::Copy)]
+enum Comparison {
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+}
+
+/// Matches values less than `bound`.
+pub fn lt<B>(bound: B) -> ComparisonMatcher<B> {
+    ComparisonMatcher {
+        bound,
+        comparison: Comparison::Less,
+    }
+}
+
+/// Matches values less than or equal to `bound`.
+pub fn le<B>(bound: B) -> ComparisonMatcher<B> {
+    ComparisonMatcher {
+        bound,
+        comparison: Comparison::LessOrEqual,
+    }
+}
+
+/// Matches values greater than `bound`.
+pub fn gt<B>(bound: B) -> ComparisonMatcher<B> {
+    ComparisonMatcher {
+        bound,
+        comparison: Comparison::Greater,
+    }
+}
+
+/// Matches values greater than or equal to `bound`.
+pub fn ge<B>(bound: B) -> ComparisonMatcher<B> {
+    ComparisonMatcher {
+        bound,
+        comparison: Comparison::GreaterOrEqual,
+    }
+}
+
+impl<T, B> Matcher<T> for ComparisonMatcher<B>
+where
+    T: ::core::cmp::PartialOrd<B> + ?::core::marker::Sized,
+    B: ::core::fmt::Debug,
+{
+    fn matches(&self, actual: &T) -> bool {
+        match self.comparison {
+            Comparison::Less => *actual < self.bound,
+            Comparison::LessOrEqual => *actual <= self.bound,
+            Comparison::Greater => *actual > self.bound,
+            Comparison::GreaterOrEqual => *actual >= self.bound,
+        }
+    }
+
+    fn describe(&self) -> ::std::string::String {
+        let comparison = match self.comparison {
+            Comparison::Less => "less than",
+            Comparison::LessOrEqual => "less than or equal to",
+            Comparison::Greater => "greater than",
+            Comparison::GreaterOrEqual => "greater than or equal to",
+        };
+
+        ::std::format!("{} {:?}", comparison, self.bound)
+    }
+}
+
+pub struct ContainsMatcher<N> {
+    needle: N,
+}
+
+/// Matches strings containing the substring `needle`, and collections
+/// containing an element equal to it.
+pub fn contains<N>(needle: N) -> ContainsMatcher<N> {
+    ContainsMatcher {
+        needle,
+    }
+}
+
+impl<C, N> Matcher<C> for ContainsMatcher<N>
+where
+    C: Collection + ?::core::marker::Sized,
+    C::Element: ::core::cmp::PartialEq<N>,
+    N: ::core::fmt::Debug,
+{
+    fn matches(&self, actual: &C) -> bool {
+        actual.elements().any(|element| *element == self.needle)
+    }
+
+    fn describe(&self) -> ::std::string::String {
+        ::std::format!("{} containing {:?}", C::NOUN, self.needle)
+    }
+
+    fn explain_mismatch(&self, _: &C) -> ::std::string::String {
+        ::std::format!("which does not contain {:?}", self.needle)
+    }
+}
+
+macro_rules! impl_contains_matcher_for_strings {
+    ($($string:ty),*) => {
+        $(
+            impl<N> Matcher<$string> for ContainsMatcher<N>
+            where
+                N: AsRef<str> + ::core::fmt::Debug,
+            {
+                fn matches(&self, actual: &$string) -> bool {
+                    actual.contains(self.needle.as_ref())
+                }
+
+                fn describe(&self) -> ::std::string::String {
+                    ::std::format!("a string containing {:?}", self.needle)
+                }
+
+                fn explain_mismatch(&self, _: &$string) -> ::std::string::String {
+                    ::std::format!("which does not contain {:?}", self.needle)
+                }
+            }
+        )*
+    };
+}
+
+impl_contains_matcher_for_strings!(str, &str, ::std::string::String);
+
+pub struct EachMatcher<M> {
+    matcher: M,
+}
+
+/// Matches collections whose elements all match `matcher`.
+pub fn each<M>(matcher: M) -> EachMatcher<M> {
+    EachMatcher {
+        matcher,
+    }
+}
+
+impl<C, M> Matcher<C> for EachMatcher<M>
+where
+    C: Collection + ?::core::marker::Sized,
+    C::Element: ::core::fmt::Debug,
+    M: Matcher<C::Element>,
+{
+    fn matches(&self, actual: &C) -> bool {
+        actual.elements().all(|element| self.matcher.matches(element))
+    }
+
+    fn describe(&self) -> ::std::string::String {
+        ::std::format!("{} whose elements are all {}", C::NOUN, self.matcher.describe())
+    }
+
+    fn explain_mismatch(&self, actual: &C) -> ::std::string::String {
+        let mismatch = actual.elements().enumerate().find(|(_, element)| !self.matcher.matches(element));
+
+        match mismatch {
+            Some((index, element)) => ::std::format!(
+                "whose element at index {} is {:?}, {}",
+                index,
+                element,
+                self.matcher.explain_mismatch(element)
+            ),
+            None => ::std::format!("which is not {}", Matcher::<C>::describe(self)),
+        }
+    }
+}
+
+pub struct AllOfMatcher<M> {
+    matchers: M,
+}
+
+/// Matches values matching every one of `matchers`.
+pub fn all_of<M>(matchers: M) -> AllOfMatcher<M> {
+    AllOfMatcher {
+        matchers,
+    }
+}
+
+impl<T, M> Matcher<T> for AllOfMatcher<M>
+where
+    T: ?::core::marker::Sized,
+    M: Matchers<T>,
+{
+    fn matches(&self, actual: &T) -> bool {
+        self.matchers.matchers().into_iter().all(|matcher| matcher.matches(actual))
+    }
+
+    fn describe(&self) -> ::std::string::String {
+        let descriptions = self.matchers.matchers().into_iter().map(|matcher| matcher.describe());
+        descriptions.collect::<::std::vec::Vec<_>>().join(" and ")
+    }
+
+    fn explain_mismatch(&self, actual: &T) -> ::std::string::String {
+        let mismatches = self.matchers.matchers().into_iter().filter(|matcher| !matcher.matches(actual));
+        mismatches.map(|matcher| matcher.explain_mismatch(actual)).collect::<::std::vec::Vec<_>>().join(" and ")
+    }
+}
+
+pub struct AnyOfMatcher<M> {
+    matchers: M,
+}
+
+/// Matches values matching at least one of `matchers`.
+pub fn any_of<M>(matchers: M) -> AnyOfMatcher<M> {
+    AnyOfMatcher {
+        matchers,
+    }
+}
+
+impl<T, M> Matcher<T> for AnyOfMatcher<M>
+where
+    T: ?::core::marker::Sized,
+    M: Matchers<T>,
+{
+    fn matches(&self, actual: &T) -> bool {
+        self.matchers.matchers().into_iter().any(|matcher| matcher.matches(actual))
+    }
+
+    fn describe(&self) -> ::std::string::String {
+        let descriptions = self.matchers.matchers().into_iter().map(|matcher| matcher.describe());
+        descriptions.collect::<::std::vec::Vec<_>>().join(" or ")
+    }
+
+    fn explain_mismatch(&self, actual: &T) -> ::std::string::String {
+        let mismatches = self.matchers.matchers().into_iter().map(|matcher| matcher.explain_mismatch(actual));
+        mismatches.collect::<::std::vec::Vec<_>>().join(" and ")
+    }
+}
+
+pub struct NotMatcher<M> {
+    matcher: M,
+}
+
+/// Matches values that `matcher` does not match.
+pub fn not<M>(matcher: M) -> NotMatcher<M> {
+    NotMatcher {
+        matcher,
+    }
+}
+
+impl<T, M> Matcher<T> for NotMatcher<M>
+where
+    T: ?::core::marker::Sized,
+    M: Matcher<T>,
+{
+    fn matches(&self, actual: &T) -> bool {
+        !self.matcher.matches(actual)
+    }
+
+    fn describe(&self) -> ::std::string::String {
+        ::std::format!("not {}", self.matcher.describe())
+    }
+
+    fn explain_mismatch(&self, _: &T) -> ::std::string::String {
+        ::std::format!("which is {}", self.matcher.describe())
+    }
+}
+
+pub struct FieldMatcher<A, M, F: ?::core::marker::Sized> {
+    name: &'static str,
+    accessor: A,
+    matcher: M,
+    field: ::core::marker::PhantomData<fn(&F)>,
+}
+
+/// Matches values whose field, as read by `accessor`, matches `matcher`.
+/// `name` only serves descriptions.
+///
+/// ```ignore
+/// expect_that(&user, has_field("age", |user: &User| &user.age, gt(18)))
+/// ```
+pub fn has_field<T, F, A, M>(name: &'static str, accessor: A, matcher: M) -> FieldMatcher<A, M, F>
+where
+    T: ?::core::marker::Sized,
+    F: ?::core::marker::Sized,
+    A: Fn(&T) -> &F,
+{
+    FieldMatcher {
+        name,
+        accessor,
+        matcher,
+        field: ::core::marker::PhantomData,
+    }
+}
+
+impl<T, F, A, M> Matcher<T> for FieldMatcher<A, M, F>
+where
+    T: ?::core::marker::Sized,
+    F: ::core::fmt::Debug + ?::core::marker::Sized,
+    A: Fn(&T) -> &F,
+    M: Matcher<F>,
+{
+    fn matches(&self, actual: &T) -> bool {
+        self.matcher.matches((self.accessor)(actual))
+    }
+
+    fn describe(&self) -> ::std::string::String {
+        ::std::format!("a value whose `{}` is {}", self.name, self.matcher.describe())
+    }
+
+    fn explain_mismatch(&self, actual: &T) -> ::std::string::String {
+        let field = (self.accessor)(actual);
+        ::std::format!("whose `{}` is {:?}, {}", self.name, field, self.matcher.explain_mismatch(field))
+    }
+}
+
+#[cfg(feature = "regex")]
+pub struct RegexMatcher {
+    pattern: ::std::string::String,
+    regex: ::core::result::Result<::regex::Regex, ::regex::Error>,
+}
+
+/// Matches strings in which `pattern` finds a match, anywhere unless anchored
+/// with `^` and `$`. An invalid pattern matches nothing, and says why.
+#[cfg(feature = "regex")]
+pub fn matches_regex(pattern: impl Into<::std::string::String>) -> RegexMatcher {
+    let pattern = pattern.into();
+    let regex = ::regex::Regex::new(&pattern);
+
+    RegexMatcher {
+        pattern,
+        regex,
+    }
+}
+
+#[cfg(feature = "regex")]
+impl<T> Matcher<T> for RegexMatcher
+where
+    T: AsRef<str> + ?::core::marker::Sized,
+{
+    fn matches(&self, actual: &T) -> bool {
+        self.regex.as_ref().is_ok_and(|regex| regex.is_match(actual.as_ref()))
+    }
+
+    fn describe(&self) -> ::std::string::String {
+        ::std::format!("a string matching the regex `{}`", self.pattern)
+    }
+
+    fn explain_mismatch(&self, _: &T) -> ::std::string::String {
+        match &self.regex {
+            Ok(_) => ::std::format!("which does not match the regex `{}`", self.pattern),
+            Err(err) => ::std::format!("which cannot be matched, as the regex is invalid: {}", err),
+        }
+    }
+}
+
+pub struct ElementsAreMatcher<M> {
+    matchers: M,
+}
+
+/// Matches collections with as many elements as `matchers`, each matching
+/// the matcher at its position.
+///
+/// ```ignore
+/// expect_that(&ages, elements_are((eq(30), gt(18))))
+/// ```
+pub fn elements_are<M>(matchers: M) -> ElementsAreMatcher<M> {
+    ElementsAreMatcher {
+        matchers,
+    }
+}
+
+impl<C, M> Matcher<C> for ElementsAreMatcher<M>
+where
+    C: Collection + ?::core::marker::Sized,
+    C::Element: ::core::fmt::Debug,
+    M: Matchers<C::Element>,
+{
+    fn matches(&self, actual: &C) -> bool {
+        let matchers = self.matchers.matchers();
+
+        actual.elements().count() == matchers.len()
+            && actual.elements().zip(matchers).all(|(element, matcher)| matcher.matches(element))
+    }
+
+    fn describe(&self) -> ::std::string::String {
+        ::std::format!("{} whose elements are, in order, {}", C::NOUN, list(&self.matchers.matchers()))
+    }
+
+    fn explain_mismatch(&self, actual: &C) -> ::std::string::String {
+        let matchers = self.matchers.matchers();
+
+        if let Some(explanation) = explain_length(actual, matchers.len()) {
+            return explanation;
+        }
+
+        let mismatch =
+            actual.elements().zip(matchers).enumerate().find(|(_, (element, matcher))| !matcher.matches(element));
+
+        match mismatch {
+            Some((index, (element, matcher))) => ::std::format!(
+                "whose element at index {} is {:?}, {}",
+                index,
+                element,
+                matcher.explain_mismatch(element)
+            ),
+            None => ::std::format!("which is not {}", Matcher::<C>::describe(self)),
+        }
+    }
+}
+
+pub struct UnorderedElementsAreMatcher<M> {
+    matchers: M,
+}
+
+/// Matches collections with as many elements as `matchers`, which can be
+/// paired so that each element matches a different matcher.
+pub fn unordered_elements_are<M>(matchers: M) -> UnorderedElementsAreMatcher<M> {
+    UnorderedElementsAreMatcher {
+        matchers,
+    }
+}
+
+impl<C, M> Matcher<C> for UnorderedElementsAreMatcher<M>
+where
+    C: Collection + ?::core::marker::Sized,
+    C::Element: ::core::fmt::Debug,
+    M: Matchers<C::Element>,
+{
+    fn matches(&self, actual: &C) -> bool {
+        let matchers = self.matchers.matchers();
+
+        actual.elements().count() == matchers.len() && pairs(&adjacency(actual, &matchers))
+    }
+
+    fn describe(&self) -> ::std::string::String {
+        ::std::format!("{} whose elements are, in any order, {}", C::NOUN, list(&self.matchers.matchers()))
+    }
+
+    fn explain_mismatch(&self, actual: &C) -> ::std::string::String {
+        let matchers = self.matchers.matchers();
+
+        if let Some(explanation) = explain_length(actual, matchers.len()) {
+            return explanation;
+        }
+
+        let adjacency = adjacency(actual, &matchers);
+
+        let unmatched_matcher = (0..matchers.len()).find(|&index| adjacency.iter().all(|row| !row[index]));
+        let unmatched_element =
+            actual.elements().zip(&adjacency).enumerate().find(|(_, (_, row))| !row.contains(&true));
+
+        match (unmatched_matcher, unmatched_element) {
+            (Some(index), _) => ::std::format!("which has no element {}", matchers[index].describe()),
+            (None, Some((index, (element, _)))) => {
+                ::std::format!("whose element at index {} is {:?}, which matches none of them", index, element)
+            },
+            (None, None) => "whose elements cannot each be paired with a different matcher".to_owned(),
+        }
+    }
+}
+
+pub struct NearMatcher<F> {
+    expected: F,
+    tolerance: f64,
+}
+
+/// Matches floats at most `tolerance` away from `expected`.
+pub fn near<F>(expected: F, tolerance: f64) -> NearMatcher<F> {
+    NearMatcher {
+        expected,
+        tolerance,
+    }
+}
+
+impl<F> Matcher<F> for NearMatcher<F>
+where
+    F: __Approximate + ::core::fmt::Debug,
+{
+    fn matches(&self, actual: &F) -> bool {
+        actual.__distance(self.expected) <= self.tolerance
+    }
+
+    fn describe(&self) -> ::std::string::String {
+        ::std::format!("within {:?} of {:?}", self.tolerance, self.expected)
+    }
+
+    fn explain_mismatch(&self, actual: &F) -> ::std::string::String {
+        ::std::format!("which is {:?} away", actual.__distance(self.expected))
+    }
+}
+
+pub struct SomeMatcher<M> {
+    matcher: M,
+}
+
+/// Matches `Some` whose value matches `matcher`.
+pub fn some<M>(matcher: M) -> SomeMatcher<M> {
+    SomeMatcher {
+        matcher,
+    }
+}
+
+impl<T, M> Matcher<::core::option::Option<T>> for SomeMatcher<M>
+where
+    T: ::core::fmt::Debug,
+    M: Matcher<T>,
+{
+    fn matches(&self, actual: &::core::option::Option<T>) -> bool {
+        actual.as_ref().is_some_and(|value| self.matcher.matches(value))
+    }
+
+    fn describe(&self) -> ::std::string::String {
+        ::std::format!("`Some` whose value is {}", self.matcher.describe())
+    }
+
+    fn explain_mismatch(&self, actual: &::core::option::Option<T>) -> ::std::string::String {
+        match actual {
+            Some(value) => ::std::format!("whose value is {:?}, {}", value, self.matcher.explain_mismatch(value)),
+            None => "which is `None`".to_owned(),
+        }
+    }
+}
+
+pub struct OkMatcher<M> {
+    matcher: M,
+}
+
+/// Matches `Ok` whose value matches `matcher`.
+pub fn ok<M>(matcher: M) -> OkMatcher<M> {
+    OkMatcher {
+        matcher,
+    }
+}
+
+impl<T, E, M> Matcher<::core::result::Result<T, E>> for OkMatcher<M>
+where
+    T: ::core::fmt::Debug,
+    M: Matcher<T>,
+{
+    fn matches(&self, actual: &::core::result::Result<T, E>) -> bool {
+        actual.as_ref().is_ok_and(|value| self.matcher.matches(value))
+    }
+
+    fn describe(&self) -> ::std::string::String {
+        ::std::format!("`Ok` whose value is {}", self.matcher.describe())
+    }
+
+    fn explain_mismatch(&self, actual: &::core::result::Result<T, E>) -> ::std::string::String {
+        match actual {
+            Ok(value) => ::std::format!("whose value is {:?}, {}", value, self.matcher.explain_mismatch(value)),
+            Err(_) => "which is an `Err`".to_owned(),
+        }
+    }
+}
+
+pub struct ErrMatcher<M> {
+    matcher: M,
+}
+
+/// Matches `Err` whose error matches `matcher`.
+pub fn err<M>(matcher: M) -> ErrMatcher<M> {
+    ErrMatcher {
+        matcher,
+    }
+}
+
+impl<T, E, M> Matcher<::core::result::Result<T, E>> for ErrMatcher<M>
+where
+    E: ::core::fmt::Debug,
+    M: Matcher<E>,
+{
+    fn matches(&self, actual: &::core::result::Result<T, E>) -> bool {
+        actual.as_ref().is_err_and(|error| self.matcher.matches(error))
+    }
+
+    fn describe(&self) -> ::std::string::String {
+        ::std::format!("`Err` whose error is {}", self.matcher.describe())
+    }
+
+    fn explain_mismatch(&self, actual: &::core::result::Result<T, E>) -> ::std::string::String {
+        match actual {
+            Ok(_) => "which is an `Ok`".to_owned(),
+            Err(error) => ::std::format!("whose error is {:?}, {}", error, self.matcher.explain_mismatch(error)),
+        }
+    }
+}
+
+fn list<T>(matchers: &[&dyn Matcher<T>]) -> ::std::string::String
+where
+    T: ?::core::marker::Sized,
+{
+    let descriptions = matchers.iter().map(|matcher| matcher.describe()).collect::<::std::vec::Vec<_>>();
+    ::std::format!("[{}]", descriptions.join(", "))
+}
+
+fn explain_length<C>(actual: &C, expected: usize) -> ::core::option::Option<::std::string::String>
+where
+    C: Collection + ?::core::marker::Sized,
+{
+    let count = actual.elements().count();
+    let noun = match count {
+        1 => "element",
+        _ => "elements",
+    };
+
+    (count != expected).then(|| ::std::format!("which has {} {} rather than {}", count, noun, expected))
+}
+
+/// Whether each element matches each matcher, by element then matcher.
+fn adjacency<C>(actual: &C, matchers: &[&dyn Matcher<C::Element>]) -> ::std::vec::Vec<::std::vec::Vec<bool>>
+where
+    C: Collection + ?::core::marker::Sized,
+{
+    actual.elements().map(|element| matchers.iter().map(|matcher| matcher.matches(element)).collect()).collect()
+}
+
+/// Whether every element can be paired with a different matcher it matches,
+/// found with augmenting paths as in Kuhn's algorithm.
+fn pairs(adjacency: &[::std::vec::Vec<bool>]) -> bool {
+    fn augment(
+        element: usize,
+        adjacency: &[::std::vec::Vec<bool>],
+        (visited, paired): (&mut [bool], &mut [::core::option::Option<usize>]),
+    ) -> bool {
+        for matcher in 0..adjacency[element].len() {
+            if !adjacency[element][matcher] || visited[matcher] {
+                continue;
+            }
+
+            visited[matcher] = true;
+
+            let augmented = match paired[matcher] {
+                Some(other) => augment(other, adjacency, (visited, paired)),
+                None => true,
+            };
+
+            if augmented {
+                paired[matcher] = Some(element);
+                return true;
+            }
+        }
+
+        false
+    }
+
+    let mut paired = ::std::vec![None; adjacency.len()];
+
+    (0..adjacency.len()).all(|element| {
+        let mut visited = ::std::vec![false; adjacency.len()];
+        augment(element, adjacency, (&mut visited, &mut paired))
+    })
+}
+
+/// Values are rendered with `Debug`, pretty-printed if long.
+fn rendered<T>(value: &T) -> ::std::string::String
+where
+    T: ::core::fmt::Debug + ?::core::marker::Sized,
+{
+    let compact = ::std::format!("{:?}", value);
+
+    match compact.chars().count() > DIFFED_WIDTH {
+        true => ::std::format!("{:#?}", value),
+        false => compact,
+    }
+}
+
+/// Values whose `Debug` rendition is longer than this are diffed over the
+/// lines of their pretty-printed rendition.
+const DIFFED_WIDTH: usize = 80;
+
+#[doc(hidden)]
+pub struct __Operand<'a, T: ?::core::marker::Sized>(pub &'a T);
+
+/// Strings are diffed over their own lines rather than their `Debug`
+/// rendition, which escapes line breaks.
+#[doc(hidden)]
+pub trait __TextOperand {
+    fn __lines(&self) -> ::std::vec::Vec<::std::string::String>;
+}
+
+#[doc(hidden)]
+pub trait __DebugOperand {
+    fn __lines(&self) -> ::std::vec::Vec<::std::string::String>;
+}
+
+impl<T> __TextOperand for __Operand<'_, T>
+where
+    T: AsRef<str> + ?::core::marker::Sized,
+{
+    fn __lines(&self) -> ::std::vec::Vec<::std::string::String> {
+        self.0.as_ref().lines().map(ToOwned::to_owned).collect()
+    }
+}
+
+impl<T> __DebugOperand for &__Operand<'_, T>
+where
+    T: ::core::fmt::Debug + ?::core::marker::Sized,
+{
+    fn __lines(&self) -> ::std::vec::Vec<::std::string::String> {
+        rendered(self.0).lines().map(ToOwned::to_owned).collect()
+    }
+}
+
+/// A diff is only kept if either side spans several lines and they differ
+/// line by line, rather than e.g. in trailing line breaks only.
+#[doc(hidden)]
+#[track_caller]
+pub fn __mismatch(
+    message: impl crate::builders::models::IntoFailed,
+    expected: ::std::vec::Vec<::std::string::String>,
+    actual: ::std::vec::Vec<::std::string::String>,
+) -> crate::models::Failed {
+    let failed = message.into_failed();
+
+    if expected.len() <= 1 && actual.len() <= 1 || expected == actual {
+        return failed;
+    }
+
+    let diff =
+        crate::utils::diff(&expected, &actual).into_iter().map(|(change, line)| (change, line.clone())).collect();
+
+    failed.with_diff(diff)
+}
+
+#[doc(hidden)]
+pub trait __Approximate: ::core::marker::Copy {
+    fn __distance(self, other: Self) -> f64;
+}
+
+impl __Approximate for f32 {
+    fn __distance(self, other: Self) -> f64 {
+        (f64::from(self) - f64::from(other)).abs()
+    }
+}
+
+impl __Approximate for f64 {
+    fn __distance(self, other: Self) -> f64 {
+        (self - other).abs()
+    }
+}
+
+#[doc(hidden)]
+#[track_caller]
+pub fn __pending(message: impl Into<crate::utils::aliases::string::String>) -> crate::models::Failed {
+    crate::models::Failed {
+        kind: crate::models::FailedKind::Pending,
+        ..crate::models::Failed::new(message)
+    }
+}
+
+#[doc(hidden)]
+pub use __assert_ordering;
 pub use assert;
+pub use assert_approx_eq;
+pub use assert_contains;
+pub use assert_eq;
+pub use assert_ge;
+pub use assert_gt;
+pub use assert_le;
+pub use assert_lt;
+pub use assert_matches;
+pub use assert_ne;
 pub use format;
 pub use panic;
+pub use pending;
@@ -213,7 +213,83 @@ impl<State: self::runner::BuilderState> RunnerBuilder<State> {
         }
     }
 
-    pub fn before_all(mut self, hook: impl IntoGlobalHook) -> RunnerBuilder<self::runner::SetHooks<State>> {
+    /// Writes a report in `format` to `path` once all trials have run. May be
+    /// called more than once to write several reports.
+    pub fn report(
+        mut self,
+        format: self::configurations::ReportFormat,
+        path: impl Into<aliases::path::Path>,
+    ) -> RunnerBuilder<self::runner::SetReports<State>> {
+        self.configurations.reports.push((format, path.into()));
+
+        RunnerBuilder {
+            configurations: self.configurations,
+
+            before_global_hooks: self.before_global_hooks,
+            after_global_hooks: self.after_global_hooks,
+
+            trials: self.trials,
+
+            __phantom: ::core::default::Default::default(),
+        }
+    }
+
+    #[cfg(feature = "async")]
+    pub fn executor(
+        mut self,
+        executor: impl crate::executors::Executor + 'static,
+    ) -> RunnerBuilder<self::runner::SetExecutor<State>>
+    where
+        State::Executor: self::marker::IsUnset,
+    {
+        self.configurations.executor = ::core::option::Option::from(
+            aliases::sync::Arc::new(executor) as aliases::sync::Arc<dyn crate::executors::Executor>
+        );
+
+        RunnerBuilder {
+            configurations: self.configurations,
+
+            before_global_hooks: self.before_global_hooks,
+            after_global_hooks: self.after_global_hooks,
+
+            trials: self.trials,
+
+            __phantom: ::core::default::Default::default(),
+        }
+    }
+
+    /// Runs trials with `engine` rather than the default, which is
+    /// libtest-mimic if its feature is enabled and the native engine of
+    /// litmus otherwise.
+    pub fn engine(
+        mut self,
+        engine: impl crate::engines::Engine + 'static,
+    ) -> RunnerBuilder<self::runner::SetEngine<State>>
+    where
+        State::Engine: self::marker::IsUnset,
+    {
+        self.configurations.engine = ::core::option::Option::from(
+            ::std::boxed::Box::new(engine) as ::std::boxed::Box<dyn crate::engines::Engine>
+        );
+
+        RunnerBuilder {
+            configurations: self.configurations,
+
+            before_global_hooks: self.before_global_hooks,
+            after_global_hooks: self.after_global_hooks,
+
+            trials: self.trials,
+
+            __phantom: ::core::default::Default::default(),
+        }
+    }
+
+    /// The hook may return a fixture, which every world factory can then ask
+    /// for by reference and an `after_all` hook can take back for teardown.
+    pub fn before_all<Fixture>(
+        mut self,
+        hook: impl IntoGlobalHook<Fixture>,
+    ) -> RunnerBuilder<self::runner::SetHooks<State>> {
         self.before_global_hooks.push(hook.into_hook());
 
         RunnerBuilder {
@@ -228,8 +304,162 @@ impl<State: self::runner::BuilderState> RunnerBuilder<State> {
         }
     }
 
-    pub fn after_all(mut self, hook: impl IntoGlobalHook) -> RunnerBuilder<self::runner::SetHooks<State>> {
-        self.after_global_hooks.push(hook.into_hook());
+    #[cfg(feature = "async")]
+    pub fn before_all_async<Callback, Output, Fixture>(
+        mut self,
+        hook: Callback,
+    ) -> RunnerBuilder<self::runner::SetHooks<State>>
+    where
+        Callback: AsyncFnOnce() -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+        Output: IntoFallible<Fixture>,
+        Fixture: ::core::marker::Send + ::core::marker::Sync + 'static,
+    {
+        self.before_global_hooks.push(Asynchronous(hook).into_hook());
+
+        RunnerBuilder {
+            configurations: self.configurations,
+
+            before_global_hooks: self.before_global_hooks,
+            after_global_hooks: self.after_global_hooks,
+
+            trials: self.trials,
+
+            __phantom: ::core::default::Default::default(),
+        }
+    }
+
+    /// The hook may take a fixture returned by a `before_all` hook by value.
+    pub fn after_all<Arguments>(
+        mut self,
+        hook: impl IntoAfterGlobalHook<Arguments>,
+    ) -> RunnerBuilder<self::runner::SetHooks<State>> {
+        self.after_global_hooks.push(hook.into_after_hook());
+
+        RunnerBuilder {
+            configurations: self.configurations,
+
+            before_global_hooks: self.before_global_hooks,
+            after_global_hooks: self.after_global_hooks,
+
+            trials: self.trials,
+
+            __phantom: ::core::default::Default::default(),
+        }
+    }
+
+    #[cfg(feature = "async")]
+    pub fn after_all_async<Callback, Output>(mut self, hook: Callback) -> RunnerBuilder<self::runner::SetHooks<State>>
+    where
+        Callback: AsyncFnOnce() -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+        Output: IntoFallible,
+    {
+        self.after_global_hooks.push(Asynchronous(hook).into_after_hook());
+
+        RunnerBuilder {
+            configurations: self.configurations,
+
+            before_global_hooks: self.before_global_hooks,
+            after_global_hooks: self.after_global_hooks,
+
+            trials: self.trials,
+
+            __phantom: ::core::default::Default::default(),
+        }
+    }
+
+    /// Decides what happens to the run when a `before_all` or `after_all` hook
+    /// fails. The run is aborted by default.
+    pub fn on_global_hook_failure(
+        mut self,
+        policy: self::configurations::GlobalHookFailurePolicy,
+    ) -> RunnerBuilder<self::runner::SetGlobalHookFailurePolicy<State>>
+    where
+        State::GlobalHookFailurePolicy: self::marker::IsUnset,
+    {
+        self.configurations.global_hook_failure_policy = policy;
+
+        RunnerBuilder {
+            configurations: self.configurations,
+
+            before_global_hooks: self.before_global_hooks,
+            after_global_hooks: self.after_global_hooks,
+
+            trials: self.trials,
+
+            __phantom: ::core::default::Default::default(),
+        }
+    }
+
+    /// Fails scenarios that run for longer than `timeout` as timed out, unless
+    /// they or their suite set a timeout of their own.
+    pub fn default_timeout(
+        mut self,
+        timeout: ::core::time::Duration,
+    ) -> RunnerBuilder<self::runner::SetDefaultTimeout<State>>
+    where
+        State::DefaultTimeout: self::marker::IsUnset,
+    {
+        self.configurations.timeout = ::core::option::Option::from(timeout);
+
+        RunnerBuilder {
+            configurations: self.configurations,
+
+            before_global_hooks: self.before_global_hooks,
+            after_global_hooks: self.after_global_hooks,
+
+            trials: self.trials,
+
+            __phantom: ::core::default::Default::default(),
+        }
+    }
+
+    /// Caps how many times scenarios are retried over the whole run, so that
+    /// a run where many scenarios fail does not take several times as long.
+    /// Once the budget is spent, failing scenarios fail without being retried.
+    pub fn retry_budget(mut self, retries: usize) -> RunnerBuilder<self::runner::SetRetryBudget<State>>
+    where
+        State::RetryBudget: self::marker::IsUnset,
+    {
+        self.configurations.retry_budget = ::core::option::Option::from(retries);
+
+        RunnerBuilder {
+            configurations: self.configurations,
+
+            before_global_hooks: self.before_global_hooks,
+            after_global_hooks: self.after_global_hooks,
+
+            trials: self.trials,
+
+            __phantom: ::core::default::Default::default(),
+        }
+    }
+
+    /// Stops starting scenarios once one fails, as with `--fail-fast` or
+    /// `LITMUS_FAIL_FAST`. Scenarios already running finish, and those that
+    /// never start are reported as skipped.
+    ///
+    /// libtest-mimic cannot tell that a scenario was skipped once it started
+    /// running it, so the [`LibtestEngine`](crate::engines::LibtestEngine)
+    /// runs every scenario before printing any. Its output does not stream,
+    /// the times it prints are those of printing the outcomes rather than of
+    /// running the scenarios, and a scenario that hangs shows no progress.
+    /// The [`NativeEngine`](crate::engines::NativeEngine) has none of these
+    /// limitations.
+    pub fn fail_fast(self) -> RunnerBuilder<self::runner::SetFailFast<State>>
+    where
+        State::FailFast: self::marker::IsUnset,
+    {
+        self.max_failures(1)
+    }
+
+    /// Stops starting scenarios once `failures` of them have failed, as
+    /// [`fail_fast`](Self::fail_fast) does after the first, and with the same
+    /// limitations under the [`LibtestEngine`](crate::engines::LibtestEngine).
+    pub fn max_failures(mut self, failures: usize) -> RunnerBuilder<self::runner::SetFailFast<State>>
+    where
+        State::FailFast: self::marker::IsUnset,
+    {
+        self.configurations.max_failures = ::core::option::Option::from(failures.max(1));
 
         RunnerBuilder {
             configurations: self.configurations,
@@ -245,7 +475,7 @@ impl<State: self::runner::BuilderState> RunnerBuilder<State> {
 
     pub fn suite<World>(self, suite: impl IntoSuite<World>) -> RunnerBuilder<self::runner::SetTrials<State>>
     where
-        World: ::core::default::Default + 'static,
+        World: 'static,
     {
         self.add(suite.into_suite())
     }
@@ -257,6 +487,31 @@ impl<State: self::runner::BuilderState> RunnerBuilder<State> {
         self.add(feature.into_feature())
     }
 
+    /// Adds a feature whose worlds are built with `factory` instead of with
+    /// `Default`, as a suite without hooks would.
+    pub fn feature_with_world<World, Arguments>(
+        self,
+        factory: impl IntoWorldFactory<World, Arguments>,
+        feature: impl IntoFeature<World>,
+    ) -> RunnerBuilder<self::runner::SetTrials<State>>
+    where
+        World: 'static,
+    {
+        self.add(Suite {
+            world: factory.into_factory(),
+
+            before_scenario_hooks: ::core::default::Default::default(),
+            after_scenario_hooks: ::core::default::Default::default(),
+
+            before_step_hooks: ::core::default::Default::default(),
+            after_step_hooks: ::core::default::Default::default(),
+
+            default_timeout: ::core::default::Default::default(),
+
+            features: ::std::vec![feature.into_feature()],
+        })
+    }
+
     fn add(mut self, trials: impl IntoTrialsWithConfigurations) -> RunnerBuilder<self::runner::SetTrials<State>> {
         self.trials.push(::std::boxed::Box::new(trials));
 
@@ -306,8 +561,16 @@ mod runner {
         type Color;
         type Threads;
         type LogFile;
+        type Reports;
+        #[cfg(feature = "async")]
+        type Executor;
+        type Engine;
 
         type Hooks;
+        type GlobalHookFailurePolicy;
+        type DefaultTimeout;
+        type RetryBudget;
+        type FailFast;
         type Trials;
 
         type InTagsFilterChain;
@@ -336,8 +599,16 @@ mod runner {
     pub struct SetColor<State: BuilderState = Empty>(aliases::marker::PhantomCovariant<State>);
     pub struct SetThreads<State: BuilderState = Empty>(aliases::marker::PhantomCovariant<State>);
     pub struct SetLogFile<State: BuilderState = Empty>(aliases::marker::PhantomCovariant<State>);
+    pub struct SetReports<State: BuilderState = Empty>(aliases::marker::PhantomCovariant<State>);
+    #[cfg(feature = "async")]
+    pub struct SetExecutor<State: BuilderState = Empty>(aliases::marker::PhantomCovariant<State>);
+    pub struct SetEngine<State: BuilderState = Empty>(aliases::marker::PhantomCovariant<State>);
 
     pub struct SetHooks<State: BuilderState = Empty>(aliases::marker::PhantomCovariant<State>);
+    pub struct SetGlobalHookFailurePolicy<State: BuilderState = Empty>(aliases::marker::PhantomCovariant<State>);
+    pub struct SetDefaultTimeout<State: BuilderState = Empty>(aliases::marker::PhantomCovariant<State>);
+    pub struct SetRetryBudget<State: BuilderState = Empty>(aliases::marker::PhantomCovariant<State>);
+    pub struct SetFailFast<State: BuilderState = Empty>(aliases::marker::PhantomCovariant<State>);
     pub struct SetTrials<State: BuilderState = Empty>(aliases::marker::PhantomCovariant<State>);
 
     #[rustfmt::skip] // `reorder_impl_items`
@@ -350,8 +621,16 @@ mod runner {
         type Color = self::marker::Unset<self::members::Color>;
         type Threads = self::marker::Unset<self::members::Threads>;
         type LogFile = self::marker::Unset<self::members::LogFile>;
+        type Reports = self::marker::Unset<self::members::Reports>;
+        #[cfg(feature = "async")]
+        type Executor = self::marker::Unset<self::members::Executor>;
+        type Engine = self::marker::Unset<self::members::Engine>;
 
         type Hooks = self::marker::Unset<self::members::Hooks>;
+        type GlobalHookFailurePolicy = self::marker::Unset<self::members::GlobalHookFailurePolicy>;
+        type DefaultTimeout = self::marker::Unset<self::members::DefaultTimeout>;
+        type RetryBudget = self::marker::Unset<self::members::RetryBudget>;
+        type FailFast = self::marker::Unset<self::members::FailFast>;
         type Trials = self::marker::Unset<self::members::Trials>;
 
         type InTagsFilterChain = self::marker::Unset<self::members::InTagsFilterChain>;
@@ -367,8 +646,16 @@ mod runner {
         type Color = State::Color;
         type Threads = State::Threads;
         type LogFile = State::LogFile;
+        type Reports = State::Reports;
+        #[cfg(feature = "async")]
+        type Executor = State::Executor;
+        type Engine = State::Engine;
 
         type Hooks = State::Hooks;
+        type GlobalHookFailurePolicy = State::GlobalHookFailurePolicy;
+        type DefaultTimeout = State::DefaultTimeout;
+        type RetryBudget = State::RetryBudget;
+        type FailFast = State::FailFast;
         type Trials = State::Trials;
 
         type InTagsFilterChain = self::marker::Unset<self::members::InTagsFilterChain>;
@@ -384,8 +671,16 @@ mod runner {
         type Color = State::Color;
         type Threads = State::Threads;
         type LogFile = State::LogFile;
+        type Reports = State::Reports;
+        #[cfg(feature = "async")]
+        type Executor = State::Executor;
+        type Engine = State::Engine;
 
         type Hooks = State::Hooks;
+        type GlobalHookFailurePolicy = State::GlobalHookFailurePolicy;
+        type DefaultTimeout = State::DefaultTimeout;
+        type RetryBudget = State::RetryBudget;
+        type FailFast = State::FailFast;
         type Trials = State::Trials;
 
         type InTagsFilterChain = self::marker::Set<self::members::InTagsFilterChain>;
@@ -401,8 +696,16 @@ mod runner {
         type Color = State::Color;
         type Threads = State::Threads;
         type LogFile = State::LogFile;
+        type Reports = State::Reports;
+        #[cfg(feature = "async")]
+        type Executor = State::Executor;
+        type Engine = State::Engine;
 
         type Hooks = State::Hooks;
+        type GlobalHookFailurePolicy = State::GlobalHookFailurePolicy;
+        type DefaultTimeout = State::DefaultTimeout;
+        type RetryBudget = State::RetryBudget;
+        type FailFast = State::FailFast;
         type Trials = State::Trials;
 
         type InTagsFilterChain = self::marker::Unset<self::members::InTagsFilterChain>;
@@ -418,8 +721,16 @@ mod runner {
         type Color = self::marker::Set<self::members::Color>;
         type Threads = State::Threads;
         type LogFile = State::LogFile;
+        type Reports = State::Reports;
+        #[cfg(feature = "async")]
+        type Executor = State::Executor;
+        type Engine = State::Engine;
 
         type Hooks = State::Hooks;
+        type GlobalHookFailurePolicy = State::GlobalHookFailurePolicy;
+        type DefaultTimeout = State::DefaultTimeout;
+        type RetryBudget = State::RetryBudget;
+        type FailFast = State::FailFast;
         type Trials = State::Trials;
 
         type InTagsFilterChain = self::marker::Unset<self::members::InTagsFilterChain>;
@@ -435,8 +746,16 @@ mod runner {
         type Color = State::Color;
         type Threads = self::marker::Set<self::members::Threads>;
         type LogFile = State::LogFile;
+        type Reports = State::Reports;
+        #[cfg(feature = "async")]
+        type Executor = State::Executor;
+        type Engine = State::Engine;
 
         type Hooks = State::Hooks;
+        type GlobalHookFailurePolicy = State::GlobalHookFailurePolicy;
+        type DefaultTimeout = State::DefaultTimeout;
+        type RetryBudget = State::RetryBudget;
+        type FailFast = State::FailFast;
         type Trials = State::Trials;
 
         type InTagsFilterChain = self::marker::Unset<self::members::InTagsFilterChain>;
@@ -452,8 +771,91 @@ mod runner {
         type Color = State::Color;
         type Threads = State::Threads;
         type LogFile = self::marker::Set<self::members::LogFile>;
+        type Reports = State::Reports;
+        #[cfg(feature = "async")]
+        type Executor = State::Executor;
+        type Engine = State::Engine;
+
+        type Hooks = State::Hooks;
+        type GlobalHookFailurePolicy = State::GlobalHookFailurePolicy;
+        type DefaultTimeout = State::DefaultTimeout;
+        type RetryBudget = State::RetryBudget;
+        type FailFast = State::FailFast;
+        type Trials = State::Trials;
+
+        type InTagsFilterChain = self::marker::Unset<self::members::InTagsFilterChain>;
+    }
+
+    #[rustfmt::skip] // `reorder_impl_items`
+    #[sealed]
+    impl<State: BuilderState> BuilderState for SetReports<State> {
+        type IgnorePolicy = State::IgnorePolicy;
+        type TagsFilter = State::TagsFilter;
+
+        type Format = State::Format;
+        type Color = State::Color;
+        type Threads = State::Threads;
+        type LogFile = State::LogFile;
+        type Reports = self::marker::Set<self::members::Reports>;
+        #[cfg(feature = "async")]
+        type Executor = State::Executor;
+        type Engine = State::Engine;
+
+        type Hooks = State::Hooks;
+        type GlobalHookFailurePolicy = State::GlobalHookFailurePolicy;
+        type DefaultTimeout = State::DefaultTimeout;
+        type RetryBudget = State::RetryBudget;
+        type FailFast = State::FailFast;
+        type Trials = State::Trials;
+
+        type InTagsFilterChain = self::marker::Unset<self::members::InTagsFilterChain>;
+    }
+
+    #[cfg(feature = "async")]
+    #[rustfmt::skip] // `reorder_impl_items`
+    #[sealed]
+    impl<State: BuilderState> BuilderState for SetExecutor<State> {
+        type IgnorePolicy = State::IgnorePolicy;
+        type TagsFilter = State::TagsFilter;
+
+        type Format = State::Format;
+        type Color = State::Color;
+        type Threads = State::Threads;
+        type LogFile = State::LogFile;
+        type Reports = State::Reports;
+        type Executor = self::marker::Set<self::members::Executor>;
+        type Engine = State::Engine;
 
         type Hooks = State::Hooks;
+        type GlobalHookFailurePolicy = State::GlobalHookFailurePolicy;
+        type DefaultTimeout = State::DefaultTimeout;
+        type RetryBudget = State::RetryBudget;
+        type FailFast = State::FailFast;
+        type Trials = State::Trials;
+
+        type InTagsFilterChain = self::marker::Unset<self::members::InTagsFilterChain>;
+    }
+
+    #[rustfmt::skip] // `reorder_impl_items`
+    #[sealed]
+    impl<State: BuilderState> BuilderState for SetEngine<State> {
+        type IgnorePolicy = State::IgnorePolicy;
+        type TagsFilter = State::TagsFilter;
+
+        type Format = State::Format;
+        type Color = State::Color;
+        type Threads = State::Threads;
+        type LogFile = State::LogFile;
+        type Reports = State::Reports;
+        #[cfg(feature = "async")]
+        type Executor = State::Executor;
+        type Engine = self::marker::Set<self::members::Engine>;
+
+        type Hooks = State::Hooks;
+        type GlobalHookFailurePolicy = State::GlobalHookFailurePolicy;
+        type DefaultTimeout = State::DefaultTimeout;
+        type RetryBudget = State::RetryBudget;
+        type FailFast = State::FailFast;
         type Trials = State::Trials;
 
         type InTagsFilterChain = self::marker::Unset<self::members::InTagsFilterChain>;
@@ -469,8 +871,116 @@ mod runner {
         type Color = State::Color;
         type Threads = State::Threads;
         type LogFile = State::LogFile;
+        type Reports = State::Reports;
+        #[cfg(feature = "async")]
+        type Executor = State::Executor;
+        type Engine = State::Engine;
 
         type Hooks = self::marker::Set<self::members::Hooks>;
+        type GlobalHookFailurePolicy = State::GlobalHookFailurePolicy;
+        type DefaultTimeout = State::DefaultTimeout;
+        type RetryBudget = State::RetryBudget;
+        type FailFast = State::FailFast;
+        type Trials = State::Trials;
+
+        type InTagsFilterChain = self::marker::Unset<self::members::InTagsFilterChain>;
+    }
+
+    #[rustfmt::skip] // `reorder_impl_items`
+    #[sealed]
+    impl<State: BuilderState> BuilderState for SetGlobalHookFailurePolicy<State> {
+        type IgnorePolicy = State::IgnorePolicy;
+        type TagsFilter = State::TagsFilter;
+
+        type Format = State::Format;
+        type Color = State::Color;
+        type Threads = State::Threads;
+        type LogFile = State::LogFile;
+        type Reports = State::Reports;
+        #[cfg(feature = "async")]
+        type Executor = State::Executor;
+        type Engine = State::Engine;
+
+        type Hooks = State::Hooks;
+        type GlobalHookFailurePolicy = self::marker::Set<self::members::GlobalHookFailurePolicy>;
+        type DefaultTimeout = State::DefaultTimeout;
+        type RetryBudget = State::RetryBudget;
+        type FailFast = State::FailFast;
+        type Trials = State::Trials;
+
+        type InTagsFilterChain = self::marker::Unset<self::members::InTagsFilterChain>;
+    }
+
+    #[rustfmt::skip] // `reorder_impl_items`
+    #[sealed]
+    impl<State: BuilderState> BuilderState for SetDefaultTimeout<State> {
+        type IgnorePolicy = State::IgnorePolicy;
+        type TagsFilter = State::TagsFilter;
+
+        type Format = State::Format;
+        type Color = State::Color;
+        type Threads = State::Threads;
+        type LogFile = State::LogFile;
+        type Reports = State::Reports;
+        #[cfg(feature = "async")]
+        type Executor = State::Executor;
+        type Engine = State::Engine;
+
+        type Hooks = State::Hooks;
+        type GlobalHookFailurePolicy = State::GlobalHookFailurePolicy;
+        type DefaultTimeout = self::marker::Set<self::members::DefaultTimeout>;
+        type RetryBudget = State::RetryBudget;
+        type FailFast = State::FailFast;
+        type Trials = State::Trials;
+
+        type InTagsFilterChain = self::marker::Unset<self::members::InTagsFilterChain>;
+    }
+
+    #[rustfmt::skip] // `reorder_impl_items`
+    #[sealed]
+    impl<State: BuilderState> BuilderState for SetRetryBudget<State> {
+        type IgnorePolicy = State::IgnorePolicy;
+        type TagsFilter = State::TagsFilter;
+
+        type Format = State::Format;
+        type Color = State::Color;
+        type Threads = State::Threads;
+        type LogFile = State::LogFile;
+        type Reports = State::Reports;
+        #[cfg(feature = "async")]
+        type Executor = State::Executor;
+        type Engine = State::Engine;
+
+        type Hooks = State::Hooks;
+        type GlobalHookFailurePolicy = State::GlobalHookFailurePolicy;
+        type DefaultTimeout = State::DefaultTimeout;
+        type RetryBudget = self::marker::Set<self::members::RetryBudget>;
+        type FailFast = State::FailFast;
+        type Trials = State::Trials;
+
+        type InTagsFilterChain = self::marker::Unset<self::members::InTagsFilterChain>;
+    }
+
+    #[rustfmt::skip] // `reorder_impl_items`
+    #[sealed]
+    impl<State: BuilderState> BuilderState for SetFailFast<State> {
+        type IgnorePolicy = State::IgnorePolicy;
+        type TagsFilter = State::TagsFilter;
+
+        type Format = State::Format;
+        type Color = State::Color;
+        type Threads = State::Threads;
+        type LogFile = State::LogFile;
+        type Reports = State::Reports;
+        #[cfg(feature = "async")]
+        type Executor = State::Executor;
+        type Engine = State::Engine;
+
+        type Hooks = State::Hooks;
+        type GlobalHookFailurePolicy = State::GlobalHookFailurePolicy;
+        type DefaultTimeout = State::DefaultTimeout;
+        type RetryBudget = State::RetryBudget;
+        type FailFast = self::marker::Set<self::members::FailFast>;
         type Trials = State::Trials;
 
         type InTagsFilterChain = self::marker::Unset<self::members::InTagsFilterChain>;
@@ -486,8 +996,16 @@ mod runner {
         type Color = State::Color;
         type Threads = State::Threads;
         type LogFile = State::LogFile;
+        type Reports = State::Reports;
+        #[cfg(feature = "async")]
+        type Executor = State::Executor;
+        type Engine = State::Engine;
 
         type Hooks = State::Hooks;
+        type GlobalHookFailurePolicy = State::GlobalHookFailurePolicy;
+        type DefaultTimeout = State::DefaultTimeout;
+        type RetryBudget = State::RetryBudget;
+        type FailFast = State::FailFast;
         type Trials = self::marker::Set<self::members::Trials>;
 
         type InTagsFilterChain = self::marker::Unset<self::members::InTagsFilterChain>;
@@ -501,8 +1019,16 @@ mod runner {
         pub struct Color;
         pub struct Threads;
         pub struct LogFile;
+        pub struct Reports;
+        #[cfg(feature = "async")]
+        pub struct Executor;
+        pub struct Engine;
 
         pub struct Hooks;
+        pub struct GlobalHookFailurePolicy;
+        pub struct DefaultTimeout;
+        pub struct RetryBudget;
+        pub struct FailFast;
         pub struct Trials;
 
         pub struct InTagsFilterChain;
@@ -511,9 +1037,12 @@ mod runner {
 
 #[sealed]
 pub trait IntoTagsFilter {
-    fn into_filter(self) -> impl Fn(&Tags) -> bool + 'static;
+    fn into_filter(self) -> impl Fn(&Tags) -> bool + ::core::marker::Send + ::core::marker::Sync + 'static;
 
-    fn and(self, other: impl IntoTagsFilter) -> impl Fn(&Tags) -> bool + 'static
+    fn and(
+        self,
+        other: impl IntoTagsFilter,
+    ) -> impl Fn(&Tags) -> bool + ::core::marker::Send + ::core::marker::Sync + 'static
     where
         Self: ::core::marker::Sized,
     {
@@ -523,7 +1052,10 @@ pub trait IntoTagsFilter {
         move |tags| this(tags) && other(tags)
     }
 
-    fn or(self, other: impl IntoTagsFilter) -> impl Fn(&Tags) -> bool + 'static
+    fn or(
+        self,
+        other: impl IntoTagsFilter,
+    ) -> impl Fn(&Tags) -> bool + ::core::marker::Send + ::core::marker::Sync + 'static
     where
         Self: ::core::marker::Sized,
     {
@@ -537,9 +1069,9 @@ pub trait IntoTagsFilter {
 #[sealed]
 impl<F> IntoTagsFilter for F
 where
-    F: Fn(&Tags) -> bool + 'static,
+    F: Fn(&Tags) -> bool + ::core::marker::Send + ::core::marker::Sync + 'static,
 {
-    fn into_filter(self) -> impl Fn(&Tags) -> bool + 'static {
+    fn into_filter(self) -> impl Fn(&Tags) -> bool + ::core::marker::Send + ::core::marker::Sync + 'static {
         self
     }
 }
@@ -547,11 +1079,11 @@ where
 #[sealed]
 impl<T> IntoTagsFilter for &'static [T]
 where
-    T: Into<aliases::string::String> + ::core::clone::Clone + 'static,
+    T: Into<aliases::string::String> + ::core::clone::Clone + ::core::marker::Send + ::core::marker::Sync + 'static,
 {
-    fn into_filter(self) -> impl Fn(&Tags) -> bool + 'static {
+    fn into_filter(self) -> impl Fn(&Tags) -> bool + ::core::marker::Send + ::core::marker::Sync + 'static {
         move |tags| {
-            let filter = self.iter().cloned().map(Into::into).collect::<Tags>();
+            let filter = self.iter().cloned().map(Into::into).map(untagged).collect::<Tags>();
             !filter.is_disjoint(tags)
         }
     }
@@ -560,16 +1092,41 @@ where
 #[sealed]
 impl<T, const N: usize> IntoTagsFilter for [T; N]
 where
-    T: Into<aliases::string::String> + ::core::clone::Clone + 'static,
+    T: Into<aliases::string::String> + ::core::clone::Clone + ::core::marker::Send + ::core::marker::Sync + 'static,
 {
-    fn into_filter(self) -> impl Fn(&Tags) -> bool + 'static {
+    fn into_filter(self) -> impl Fn(&Tags) -> bool + ::core::marker::Send + ::core::marker::Sync + 'static {
         move |tags| {
-            let filter = self.iter().cloned().map(Into::into).collect::<Tags>();
+            let filter = self.iter().cloned().map(Into::into).map(untagged).collect::<Tags>();
             !filter.is_disjoint(tags)
         }
     }
 }
 
+#[sealed]
+impl IntoTagsFilter for crate::tags::Expression {
+    fn into_filter(self) -> impl Fn(&Tags) -> bool + ::core::marker::Send + ::core::marker::Sync + 'static {
+        move |tags| self.matches(tags)
+    }
+}
+
+/// Parses the string as a tag expression.
+///
+/// # Panics
+///
+/// Panics if the tag expression is malformed. Parse it into an
+/// [`Expression`](crate::tags::Expression) first to handle the error instead.
+#[sealed]
+impl IntoTagsFilter for &str {
+    fn into_filter(self) -> impl Fn(&Tags) -> bool + ::core::marker::Send + ::core::marker::Sync + 'static {
+        let expression = match self.parse::<crate::tags::Expression>() {
+            Ok(expression) => expression,
+            Err(err) => ::std::panic!("{}", err),
+        };
+
+        expression.into_filter()
+    }
+}
+
 impl From<u64> for self::configurations::ThreadsCount {
     fn from(value: u64) -> Self {
         Self::Custom(value)
@@ -577,11 +1134,15 @@ impl From<u64> for self::configurations::ThreadsCount {
 }
 
 pub struct SuiteBuilder<World, State: self::suite::BuilderState = self::suite::Empty> {
+    world: ::core::option::Option<WorldFactory<World>>,
+
     before_scenario_hooks: ::std::vec::Vec<ScenarioOrStepHook<World>>,
-    after_scenario_hooks: ::std::vec::Vec<ScenarioOrStepHook<World>>,
+    after_scenario_hooks: ::std::vec::Vec<AfterScenarioOrStepHook<World>>,
 
     before_step_hooks: ::std::vec::Vec<ScenarioOrStepHook<World>>,
-    after_step_hooks: ::std::vec::Vec<ScenarioOrStepHook<World>>,
+    after_step_hooks: ::std::vec::Vec<AfterScenarioOrStepHook<World>>,
+
+    default_timeout: ::core::option::Option<::core::time::Duration>,
 
     features: ::std::vec::Vec<Feature<World>>,
 
@@ -597,12 +1158,16 @@ impl<World> Suite<World> {
 
     pub fn builder() -> SuiteBuilder<World> {
         SuiteBuilder {
+            world: ::core::default::Default::default(),
+
             before_scenario_hooks: ::core::default::Default::default(),
             after_scenario_hooks: ::core::default::Default::default(),
 
             before_step_hooks: ::core::default::Default::default(),
             after_step_hooks: ::core::default::Default::default(),
 
+            default_timeout: ::core::default::Default::default(),
+
             features: ::core::default::Default::default(),
 
             __phantom: ::core::default::Default::default(),
@@ -611,6 +1176,35 @@ impl<World> Suite<World> {
 }
 
 impl<World, State: self::suite::BuilderState> SuiteBuilder<World, State> {
+    /// Builds the world of each scenario with `factory` instead of with
+    /// `Default`. The factory may take a fixture returned by a `before_all`
+    /// hook by reference.
+    pub fn world<Arguments>(
+        mut self,
+        factory: impl IntoWorldFactory<World, Arguments>,
+    ) -> SuiteBuilder<World, self::suite::SetWorld<State>>
+    where
+        State::World: self::marker::IsUnset,
+    {
+        self.world = ::core::option::Option::from(factory.into_factory());
+
+        SuiteBuilder {
+            world: self.world,
+
+            before_scenario_hooks: self.before_scenario_hooks,
+            after_scenario_hooks: self.after_scenario_hooks,
+
+            before_step_hooks: self.before_step_hooks,
+            after_step_hooks: self.after_step_hooks,
+
+            default_timeout: self.default_timeout,
+
+            features: self.features,
+
+            __phantom: ::core::default::Default::default(),
+        }
+    }
+
     pub fn before_scenario(
         mut self,
         hook: impl IntoScenarioOrStepHook<World>,
@@ -618,31 +1212,99 @@ impl<World, State: self::suite::BuilderState> SuiteBuilder<World, State> {
         self.before_scenario_hooks.push(hook.into_hook());
 
         SuiteBuilder {
+            world: self.world,
+
             before_scenario_hooks: self.before_scenario_hooks,
             after_scenario_hooks: self.after_scenario_hooks,
 
             before_step_hooks: self.before_step_hooks,
             after_step_hooks: self.after_step_hooks,
 
+            default_timeout: self.default_timeout,
+
             features: self.features,
 
             __phantom: ::core::default::Default::default(),
         }
     }
 
-    pub fn after_scenario(
+    #[cfg(feature = "async")]
+    pub fn before_scenario_async<Callback, Output>(
         mut self,
-        hook: impl IntoScenarioOrStepHook<World>,
+        hook: Callback,
+    ) -> SuiteBuilder<World, self::suite::SetHooks<State>>
+    where
+        Callback: AsyncFn(&mut World) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+        Output: IntoFallible,
+    {
+        self.before_scenario_hooks.push(Asynchronous(hook).into_hook());
+
+        SuiteBuilder {
+            world: self.world,
+
+            before_scenario_hooks: self.before_scenario_hooks,
+            after_scenario_hooks: self.after_scenario_hooks,
+
+            before_step_hooks: self.before_step_hooks,
+            after_step_hooks: self.after_step_hooks,
+
+            default_timeout: self.default_timeout,
+
+            features: self.features,
+
+            __phantom: ::core::default::Default::default(),
+        }
+    }
+
+    /// Runs once the scenario is over, even if one of its steps or hooks
+    /// failed. The hook may take the outcome of the scenario as a second
+    /// argument.
+    pub fn after_scenario<Arguments>(
+        mut self,
+        hook: impl IntoAfterScenarioOrStepHook<World, Arguments>,
     ) -> SuiteBuilder<World, self::suite::SetHooks<State>> {
-        self.after_scenario_hooks.push(hook.into_hook());
+        self.after_scenario_hooks.push(hook.into_after_hook());
+
+        SuiteBuilder {
+            world: self.world,
+
+            before_scenario_hooks: self.before_scenario_hooks,
+            after_scenario_hooks: self.after_scenario_hooks,
+
+            before_step_hooks: self.before_step_hooks,
+            after_step_hooks: self.after_step_hooks,
+
+            default_timeout: self.default_timeout,
+
+            features: self.features,
+
+            __phantom: ::core::default::Default::default(),
+        }
+    }
+
+    #[cfg(feature = "async")]
+    pub fn after_scenario_async<Callback, Output>(
+        mut self,
+        hook: Callback,
+    ) -> SuiteBuilder<World, self::suite::SetHooks<State>>
+    where
+        Callback: AsyncFn(&mut World) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+        Output: IntoFallible,
+        World: 'static,
+    {
+        self.after_scenario_hooks.push(Asynchronous(hook).into_after_hook());
 
         SuiteBuilder {
+            world: self.world,
+
             before_scenario_hooks: self.before_scenario_hooks,
             after_scenario_hooks: self.after_scenario_hooks,
 
             before_step_hooks: self.before_step_hooks,
             after_step_hooks: self.after_step_hooks,
 
+            default_timeout: self.default_timeout,
+
             features: self.features,
 
             __phantom: ::core::default::Default::default(),
@@ -656,31 +1318,127 @@ impl<World, State: self::suite::BuilderState> SuiteBuilder<World, State> {
         self.before_step_hooks.push(hook.into_hook());
 
         SuiteBuilder {
+            world: self.world,
+
             before_scenario_hooks: self.before_scenario_hooks,
             after_scenario_hooks: self.after_scenario_hooks,
 
             before_step_hooks: self.before_step_hooks,
             after_step_hooks: self.after_step_hooks,
 
+            default_timeout: self.default_timeout,
+
             features: self.features,
 
             __phantom: ::core::default::Default::default(),
         }
     }
 
-    pub fn after_step(
+    #[cfg(feature = "async")]
+    pub fn before_step_async<Callback, Output>(
         mut self,
-        hook: impl IntoScenarioOrStepHook<World>,
+        hook: Callback,
+    ) -> SuiteBuilder<World, self::suite::SetHooks<State>>
+    where
+        Callback: AsyncFn(&mut World) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+        Output: IntoFallible,
+    {
+        self.before_step_hooks.push(Asynchronous(hook).into_hook());
+
+        SuiteBuilder {
+            world: self.world,
+
+            before_scenario_hooks: self.before_scenario_hooks,
+            after_scenario_hooks: self.after_scenario_hooks,
+
+            before_step_hooks: self.before_step_hooks,
+            after_step_hooks: self.after_step_hooks,
+
+            default_timeout: self.default_timeout,
+
+            features: self.features,
+
+            __phantom: ::core::default::Default::default(),
+        }
+    }
+
+    /// Runs after every step that was attempted, including one that failed. The
+    /// hook may take the outcome of the step as a second argument.
+    pub fn after_step<Arguments>(
+        mut self,
+        hook: impl IntoAfterScenarioOrStepHook<World, Arguments>,
     ) -> SuiteBuilder<World, self::suite::SetHooks<State>> {
-        self.after_step_hooks.push(hook.into_hook());
+        self.after_step_hooks.push(hook.into_after_hook());
+
+        SuiteBuilder {
+            world: self.world,
+
+            before_scenario_hooks: self.before_scenario_hooks,
+            after_scenario_hooks: self.after_scenario_hooks,
+
+            before_step_hooks: self.before_step_hooks,
+            after_step_hooks: self.after_step_hooks,
+
+            default_timeout: self.default_timeout,
+
+            features: self.features,
+
+            __phantom: ::core::default::Default::default(),
+        }
+    }
+
+    #[cfg(feature = "async")]
+    pub fn after_step_async<Callback, Output>(
+        mut self,
+        hook: Callback,
+    ) -> SuiteBuilder<World, self::suite::SetHooks<State>>
+    where
+        Callback: AsyncFn(&mut World) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+        Output: IntoFallible,
+        World: 'static,
+    {
+        self.after_step_hooks.push(Asynchronous(hook).into_after_hook());
+
+        SuiteBuilder {
+            world: self.world,
+
+            before_scenario_hooks: self.before_scenario_hooks,
+            after_scenario_hooks: self.after_scenario_hooks,
+
+            before_step_hooks: self.before_step_hooks,
+            after_step_hooks: self.after_step_hooks,
+
+            default_timeout: self.default_timeout,
+
+            features: self.features,
+
+            __phantom: ::core::default::Default::default(),
+        }
+    }
+
+    /// Fails scenarios that run for longer than `timeout` as timed out, unless
+    /// they set a timeout of their own. Takes precedence over the default of
+    /// the runner.
+    pub fn default_timeout(
+        mut self,
+        timeout: ::core::time::Duration,
+    ) -> SuiteBuilder<World, self::suite::SetDefaultTimeout<State>>
+    where
+        State::DefaultTimeout: self::marker::IsUnset,
+    {
+        self.default_timeout = ::core::option::Option::from(timeout);
 
         SuiteBuilder {
+            world: self.world,
+
             before_scenario_hooks: self.before_scenario_hooks,
             after_scenario_hooks: self.after_scenario_hooks,
 
             before_step_hooks: self.before_step_hooks,
             after_step_hooks: self.after_step_hooks,
 
+            default_timeout: self.default_timeout,
+
             features: self.features,
 
             __phantom: ::core::default::Default::default(),
@@ -691,12 +1449,16 @@ impl<World, State: self::suite::BuilderState> SuiteBuilder<World, State> {
         self.features.push(feature.into_feature());
 
         SuiteBuilder {
+            world: self.world,
+
             before_scenario_hooks: self.before_scenario_hooks,
             after_scenario_hooks: self.after_scenario_hooks,
 
             before_step_hooks: self.before_step_hooks,
             after_step_hooks: self.after_step_hooks,
 
+            default_timeout: self.default_timeout,
+
             features: self.features,
 
             __phantom: ::core::default::Default::default(),
@@ -713,12 +1475,16 @@ impl<World, State: self::suite::BuilderState> SuiteBuilder<World, State> {
         self.features.extend(features.into_iter().map(IntoFeature::into_feature));
 
         SuiteBuilder {
+            world: self.world,
+
             before_scenario_hooks: self.before_scenario_hooks,
             after_scenario_hooks: self.after_scenario_hooks,
 
             before_step_hooks: self.before_step_hooks,
             after_step_hooks: self.after_step_hooks,
 
+            default_timeout: self.default_timeout,
+
             features: self.features,
 
             __phantom: ::core::default::Default::default(),
@@ -729,15 +1495,20 @@ impl<World, State: self::suite::BuilderState> SuiteBuilder<World, State> {
 impl<World, State: self::suite::BuilderState> SuiteBuilder<World, State>
 where
     State: self::suite::IsComplete,
+    State::World: self::suite::WorldState<World>,
 {
     pub fn build(self) -> Suite<World> {
         Suite {
+            world: <State::World as self::suite::WorldState<World>>::resolve(self.world),
+
             before_scenario_hooks: self.before_scenario_hooks,
             after_scenario_hooks: self.after_scenario_hooks,
 
             before_step_hooks: self.before_step_hooks,
             after_step_hooks: self.after_step_hooks,
 
+            default_timeout: self.default_timeout,
+
             features: self.features,
         }
     }
@@ -748,7 +1519,9 @@ mod suite {
 
     #[sealed]
     pub trait BuilderState: ::core::marker::Sized {
+        type World;
         type Hooks;
+        type DefaultTimeout;
         type Features;
     }
 
@@ -775,32 +1548,86 @@ mod suite {
 
     pub struct Empty;
 
+    pub struct SetWorld<State: BuilderState = Empty>(aliases::marker::PhantomCovariant<State>);
     pub struct SetHooks<State: BuilderState = Empty>(aliases::marker::PhantomCovariant<State>);
+    pub struct SetDefaultTimeout<State: BuilderState = Empty>(aliases::marker::PhantomCovariant<State>);
     pub struct SetFeatures<State: BuilderState = Empty>(aliases::marker::PhantomCovariant<State>);
 
     #[rustfmt::skip] // `reorder_impl_items`
     #[sealed]
     impl BuilderState for Empty {
+        type World = self::marker::Unset<self::members::World>;
         type Hooks = self::marker::Unset<self::members::Hooks>;
+        type DefaultTimeout = self::marker::Unset<self::members::DefaultTimeout>;
         type Features = self::marker::Unset<self::members::Features>;
     }
 
+    #[rustfmt::skip] // `reorder_impl_items`
+    #[sealed]
+    impl<State: BuilderState> BuilderState for SetWorld<State> {
+        type World = self::marker::Set<self::members::World>;
+        type Hooks = State::Hooks;
+        type DefaultTimeout = State::DefaultTimeout;
+        type Features = State::Features;
+    }
+
     #[rustfmt::skip] // `reorder_impl_items`
     #[sealed]
     impl<State: BuilderState> BuilderState for SetHooks<State> {
+        type World = State::World;
         type Hooks = self::marker::Set<self::members::Hooks>;
+        type DefaultTimeout = State::DefaultTimeout;
+        type Features = State::Features;
+    }
+
+    #[rustfmt::skip] // `reorder_impl_items`
+    #[sealed]
+    impl<State: BuilderState> BuilderState for SetDefaultTimeout<State> {
+        type World = State::World;
+        type Hooks = State::Hooks;
+        type DefaultTimeout = self::marker::Set<self::members::DefaultTimeout>;
         type Features = State::Features;
     }
 
     #[rustfmt::skip] // `reorder_impl_items`
     #[sealed]
     impl<State: BuilderState> BuilderState for SetFeatures<State> {
+        type World = State::World;
         type Hooks = State::Hooks;
+        type DefaultTimeout = State::DefaultTimeout;
         type Features = self::marker::Set<self::members::Features>;
     }
 
+    /// Worlds are built with `Default` unless a factory was set.
+    #[sealed]
+    pub trait WorldState<World> {
+        #[allow(private_interfaces)]
+        fn resolve(factory: ::core::option::Option<WorldFactory<World>>) -> WorldFactory<World>;
+    }
+
+    #[sealed]
+    impl<World> WorldState<World> for self::marker::Unset<self::members::World>
+    where
+        World: ::core::default::Default + 'static,
+    {
+        #[allow(private_interfaces)]
+        fn resolve(_: ::core::option::Option<WorldFactory<World>>) -> WorldFactory<World> {
+            aliases::sync::Arc::new(|_: &Fixtures| Ok(::core::default::Default::default()))
+        }
+    }
+
+    #[sealed]
+    impl<World> WorldState<World> for self::marker::Set<self::members::World> {
+        #[allow(private_interfaces)]
+        fn resolve(factory: ::core::option::Option<WorldFactory<World>>) -> WorldFactory<World> {
+            unsafe { factory.unwrap_unchecked() }
+        }
+    }
+
     mod members {
+        pub struct World;
         pub struct Hooks;
+        pub struct DefaultTimeout;
         pub struct Features;
     }
 }
@@ -822,6 +1649,7 @@ impl<World> IntoSuite<World> for Suite<World> {
 impl<World, State: self::suite::BuilderState> IntoSuite<World> for SuiteBuilder<World, State>
 where
     State: self::suite::IsComplete,
+    State::World: self::suite::WorldState<World>,
 {
     fn into_suite(self) -> Suite<World> {
         self.build()
@@ -237,6 +237,8 @@ where
             ignored: self.ignored,
             tags: self.tags,
 
+            narrative: None,
+
             background: self.background,
             scenarios: self.scenarios,
             rules: self.rules,
@@ -253,7 +255,12 @@ where
     fn propagate_tags(&mut self) {
         if let Some(tags) = self.tags.as_ref() {
             self.scenarios.iter_mut().for_each(|scenario| scenario.tags.get_or_insert_default().extend(tags.clone()));
-            self.rules.iter_mut().for_each(|rule| rule.tags.get_or_insert_default().extend(tags.clone()));
+            self.rules.iter_mut().for_each(|rule| {
+                rule.tags.get_or_insert_default().extend(tags.clone());
+                rule.scenarios
+                    .iter_mut()
+                    .for_each(|scenario| scenario.tags.get_or_insert_default().extend(tags.clone()));
+            });
         }
     }
 }
@@ -620,6 +627,8 @@ where
             ignored: self.ignored,
             tags: self.tags,
 
+            narrative: None,
+
             background: self.background,
             scenarios: self.scenarios,
         }
@@ -778,6 +787,10 @@ pub struct ScenarioBuilder<World, State: self::scenario::BuilderState = self::sc
     description: ::core::option::Option<aliases::string::String>,
     ignored: ::core::option::Option<bool>,
     tags: ::core::option::Option<Tags>,
+    soft_then: ::core::option::Option<bool>,
+    timeout: ::core::option::Option<::core::time::Duration>,
+    retries: ::core::option::Option<usize>,
+    location: ::core::option::Option<Location>,
 
     pub(crate) given: ::std::vec::Vec<ScenarioGivenOrWhenStep<World>>,
     pub(crate) when: ::std::vec::Vec<ScenarioGivenOrWhenStep<World>>,
@@ -789,15 +802,22 @@ pub struct ScenarioBuilder<World, State: self::scenario::BuilderState = self::sc
 impl<World> Scenario<World> {
     #[cfg(feature = "allow-natural")]
     #[allow(clippy::new_ret_no_self)]
+    #[track_caller]
     pub fn new() -> ScenarioBuilder<World> {
         Self::builder()
     }
 
+    /// The scenario is located where this is called.
+    #[track_caller]
     pub fn builder() -> ScenarioBuilder<World> {
         ScenarioBuilder {
             description: ::core::default::Default::default(),
             ignored: ::core::default::Default::default(),
             tags: ::core::default::Default::default(),
+            soft_then: ::core::default::Default::default(),
+            timeout: ::core::default::Default::default(),
+            retries: ::core::default::Default::default(),
+            location: ::core::option::Option::from(Location::from(::core::panic::Location::caller())),
 
             given: ::core::default::Default::default(),
             when: ::core::default::Default::default(),
@@ -827,6 +847,10 @@ where
             description: self.description,
             ignored: self.ignored,
             tags: self.tags,
+            soft_then: self.soft_then,
+            timeout: self.timeout,
+            retries: self.retries,
+            location: self.location,
 
             given: self.given,
             when: self.when,
@@ -846,6 +870,10 @@ where
             description: self.description,
             ignored: self.ignored,
             tags: self.tags,
+            soft_then: self.soft_then,
+            timeout: self.timeout,
+            retries: self.retries,
+            location: self.location,
 
             given: self.given,
             when: self.when,
@@ -865,6 +893,90 @@ where
             description: self.description,
             ignored: self.ignored,
             tags: self.tags,
+            soft_then: self.soft_then,
+            timeout: self.timeout,
+            retries: self.retries,
+            location: self.location,
+
+            given: self.given,
+            when: self.when,
+            then: self.then,
+
+            __phantom: ::core::default::Default::default(),
+        }
+    }
+
+    /// Runs every `Then` step even if one before it fails, so that the
+    /// scenario fails with all of their failures rather than the first.
+    pub fn soft_then(mut self, soft_then: impl Into<bool>) -> ScenarioBuilder<World, self::scenario::SetSoftThen<State>>
+    where
+        State::SoftThen: self::marker::IsUnset,
+    {
+        self.soft_then = ::core::option::Option::from(soft_then.into());
+
+        ScenarioBuilder {
+            description: self.description,
+            ignored: self.ignored,
+            tags: self.tags,
+            soft_then: self.soft_then,
+            timeout: self.timeout,
+            retries: self.retries,
+            location: self.location,
+
+            given: self.given,
+            when: self.when,
+            then: self.then,
+
+            __phantom: ::core::default::Default::default(),
+        }
+    }
+
+    /// Fails the scenario as timed out if it runs for longer than `timeout`,
+    /// instead of the default of its suite or runner, if any.
+    pub fn timeout(
+        mut self,
+        timeout: ::core::time::Duration,
+    ) -> ScenarioBuilder<World, self::scenario::SetTimeout<State>>
+    where
+        State::Timeout: self::marker::IsUnset,
+    {
+        self.timeout = ::core::option::Option::from(timeout);
+
+        ScenarioBuilder {
+            description: self.description,
+            ignored: self.ignored,
+            tags: self.tags,
+            soft_then: self.soft_then,
+            timeout: self.timeout,
+            retries: self.retries,
+            location: self.location,
+
+            given: self.given,
+            when: self.when,
+            then: self.then,
+
+            __phantom: ::core::default::Default::default(),
+        }
+    }
+
+    /// Runs the scenario again, with a fresh world, up to `retries` times for
+    /// as long as it fails, instead of as many times as its `@retry(n)` tag
+    /// says, if any. A scenario that passes after being retried is reported
+    /// as flaky.
+    pub fn retries(mut self, retries: usize) -> ScenarioBuilder<World, self::scenario::SetRetries<State>>
+    where
+        State::Retries: self::marker::IsUnset,
+    {
+        self.retries = ::core::option::Option::from(retries);
+
+        ScenarioBuilder {
+            description: self.description,
+            ignored: self.ignored,
+            tags: self.tags,
+            soft_then: self.soft_then,
+            timeout: self.timeout,
+            retries: self.retries,
+            location: self.location,
 
             given: self.given,
             when: self.when,
@@ -891,6 +1003,105 @@ where
             description: self.description,
             ignored: self.ignored,
             tags: self.tags,
+            soft_then: self.soft_then,
+            timeout: self.timeout,
+            retries: self.retries,
+            location: self.location,
+
+            given: self.given,
+            when: self.when,
+            then: self.then,
+
+            __phantom: ::core::default::Default::default(),
+        }
+    }
+
+    pub fn given_with<Description, Argument, Callback, Output>(
+        mut self,
+        (description, argument): (Description, Argument),
+        callback: Callback,
+    ) -> ScenarioBuilder<World, self::scenario::SetGiven<State>>
+    where
+        Description: Into<aliases::string::String>,
+        Argument: IntoStepArgument,
+        Callback: Fn(&mut World, &Argument) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+        Output: IntoFallible,
+    {
+        let step = IntoScenarioGivenOrWhenStep::into_step((description, argument, callback), StepLabel::Given);
+        self.given.push(step);
+
+        ScenarioBuilder {
+            description: self.description,
+            ignored: self.ignored,
+            tags: self.tags,
+            soft_then: self.soft_then,
+            timeout: self.timeout,
+            retries: self.retries,
+            location: self.location,
+
+            given: self.given,
+            when: self.when,
+            then: self.then,
+
+            __phantom: ::core::default::Default::default(),
+        }
+    }
+
+    #[cfg(feature = "async")]
+    pub fn given_async<Description, Callback, Output>(
+        mut self,
+        description: Description,
+        callback: Callback,
+    ) -> ScenarioBuilder<World, self::scenario::SetGiven<State>>
+    where
+        Description: Into<aliases::string::String>,
+        Callback: AsyncFn(&mut World) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+        Output: IntoFallible,
+    {
+        let step = IntoScenarioGivenOrWhenStep::into_step((description, Asynchronous(callback)), StepLabel::Given);
+        self.given.push(step);
+
+        ScenarioBuilder {
+            description: self.description,
+            ignored: self.ignored,
+            tags: self.tags,
+            soft_then: self.soft_then,
+            timeout: self.timeout,
+            retries: self.retries,
+            location: self.location,
+
+            given: self.given,
+            when: self.when,
+            then: self.then,
+
+            __phantom: ::core::default::Default::default(),
+        }
+    }
+
+    #[cfg(feature = "async")]
+    pub fn given_with_async<Description, Argument, Callback, Output>(
+        mut self,
+        (description, argument): (Description, Argument),
+        callback: Callback,
+    ) -> ScenarioBuilder<World, self::scenario::SetGiven<State>>
+    where
+        Description: Into<aliases::string::String>,
+        Argument: IntoStepArgument,
+        Callback: AsyncFn(&mut World, &Argument) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+        Output: IntoFallible,
+    {
+        let step =
+            IntoScenarioGivenOrWhenStep::into_step((description, argument, Asynchronous(callback)), StepLabel::Given);
+        self.given.push(step);
+
+        ScenarioBuilder {
+            description: self.description,
+            ignored: self.ignored,
+            tags: self.tags,
+            soft_then: self.soft_then,
+            timeout: self.timeout,
+            retries: self.retries,
+            location: self.location,
 
             given: self.given,
             when: self.when,
@@ -924,6 +1135,10 @@ where
             description: self.description,
             ignored: self.ignored,
             tags: self.tags,
+            soft_then: self.soft_then,
+            timeout: self.timeout,
+            retries: self.retries,
+            location: self.location,
 
             given: self.given,
             when: self.when,
@@ -933,23 +1148,28 @@ where
         }
     }
 
-    pub fn but<Description, Callback, Output>(
+    pub fn and_with<Description, Argument, Callback, Output>(
         mut self,
-        description: Description,
+        (description, argument): (Description, Argument),
         callback: Callback,
     ) -> ScenarioBuilder<World, self::scenario::SetGiven<self::scenario::SetGiven<InnerState>>>
     where
         Description: Into<aliases::string::String>,
-        Callback: Fn(&mut World) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+        Argument: IntoStepArgument,
+        Callback: Fn(&mut World, &Argument) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
         Output: IntoFallible,
     {
-        let step = IntoScenarioGivenOrWhenStep::into_step((description, callback), StepLabel::But);
+        let step = IntoScenarioGivenOrWhenStep::into_step((description, argument, callback), StepLabel::And);
         self.given.push(step);
 
         ScenarioBuilder {
             description: self.description,
             ignored: self.ignored,
             tags: self.tags,
+            soft_then: self.soft_then,
+            timeout: self.timeout,
+            retries: self.retries,
+            location: self.location,
 
             given: self.given,
             when: self.when,
@@ -959,23 +1179,28 @@ where
         }
     }
 
-    pub fn when<Description, Callback, Output>(
+    #[cfg(feature = "async")]
+    pub fn and_async<Description, Callback, Output>(
         mut self,
         description: Description,
         callback: Callback,
-    ) -> ScenarioBuilder<World, self::scenario::SetWhen<self::scenario::SetGiven<InnerState>>>
+    ) -> ScenarioBuilder<World, self::scenario::SetGiven<self::scenario::SetGiven<InnerState>>>
     where
         Description: Into<aliases::string::String>,
-        Callback: Fn(&mut World) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+        Callback: AsyncFn(&mut World) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
         Output: IntoFallible,
     {
-        let step = IntoScenarioGivenOrWhenStep::into_step((description, callback), StepLabel::When);
-        self.when.push(step);
+        let step = IntoScenarioGivenOrWhenStep::into_step((description, Asynchronous(callback)), StepLabel::And);
+        self.given.push(step);
 
         ScenarioBuilder {
             description: self.description,
             ignored: self.ignored,
             tags: self.tags,
+            soft_then: self.soft_then,
+            timeout: self.timeout,
+            retries: self.retries,
+            location: self.location,
 
             given: self.given,
             when: self.when,
@@ -984,31 +1209,31 @@ where
             __phantom: ::core::default::Default::default(),
         }
     }
-}
 
-impl<World, InnerState: self::scenario::BuilderState> ScenarioBuilder<World, self::scenario::SetWhen<InnerState>>
-where
-    <self::scenario::SetWhen<InnerState> as self::scenario::BuilderState>::Given: self::marker::IsSet,
-    <self::scenario::SetWhen<InnerState> as self::scenario::BuilderState>::When: self::marker::IsSet,
-    <self::scenario::SetWhen<InnerState> as self::scenario::BuilderState>::Then: self::marker::IsUnset,
-{
-    pub fn and<Description, Callback, Output>(
+    #[cfg(feature = "async")]
+    pub fn and_with_async<Description, Argument, Callback, Output>(
         mut self,
-        description: Description,
+        (description, argument): (Description, Argument),
         callback: Callback,
-    ) -> ScenarioBuilder<World, self::scenario::SetWhen<self::scenario::SetWhen<InnerState>>>
+    ) -> ScenarioBuilder<World, self::scenario::SetGiven<self::scenario::SetGiven<InnerState>>>
     where
         Description: Into<aliases::string::String>,
-        Callback: Fn(&mut World) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+        Argument: IntoStepArgument,
+        Callback: AsyncFn(&mut World, &Argument) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
         Output: IntoFallible,
     {
-        let step = IntoScenarioGivenOrWhenStep::into_step((description, callback), StepLabel::And);
-        self.when.push(step);
+        let step =
+            IntoScenarioGivenOrWhenStep::into_step((description, argument, Asynchronous(callback)), StepLabel::And);
+        self.given.push(step);
 
         ScenarioBuilder {
             description: self.description,
             ignored: self.ignored,
             tags: self.tags,
+            soft_then: self.soft_then,
+            timeout: self.timeout,
+            retries: self.retries,
+            location: self.location,
 
             given: self.given,
             when: self.when,
@@ -1022,19 +1247,23 @@ where
         mut self,
         description: Description,
         callback: Callback,
-    ) -> ScenarioBuilder<World, self::scenario::SetWhen<self::scenario::SetWhen<InnerState>>>
+    ) -> ScenarioBuilder<World, self::scenario::SetGiven<self::scenario::SetGiven<InnerState>>>
     where
         Description: Into<aliases::string::String>,
         Callback: Fn(&mut World) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
         Output: IntoFallible,
     {
         let step = IntoScenarioGivenOrWhenStep::into_step((description, callback), StepLabel::But);
-        self.when.push(step);
+        self.given.push(step);
 
         ScenarioBuilder {
             description: self.description,
             ignored: self.ignored,
             tags: self.tags,
+            soft_then: self.soft_then,
+            timeout: self.timeout,
+            retries: self.retries,
+            location: self.location,
 
             given: self.given,
             when: self.when,
@@ -1044,23 +1273,28 @@ where
         }
     }
 
-    pub fn then<Description, Callback, Output>(
+    pub fn but_with<Description, Argument, Callback, Output>(
         mut self,
-        description: Description,
+        (description, argument): (Description, Argument),
         callback: Callback,
-    ) -> ScenarioBuilder<World, self::scenario::SetThen<self::scenario::SetWhen<InnerState>>>
+    ) -> ScenarioBuilder<World, self::scenario::SetGiven<self::scenario::SetGiven<InnerState>>>
     where
         Description: Into<aliases::string::String>,
-        Callback: Fn(&World) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+        Argument: IntoStepArgument,
+        Callback: Fn(&mut World, &Argument) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
         Output: IntoFallible,
     {
-        let step = IntoScenarioThenStep::into_step((description, callback), StepLabel::Then);
-        self.then.push(step);
+        let step = IntoScenarioGivenOrWhenStep::into_step((description, argument, callback), StepLabel::But);
+        self.given.push(step);
 
         ScenarioBuilder {
             description: self.description,
             ignored: self.ignored,
             tags: self.tags,
+            soft_then: self.soft_then,
+            timeout: self.timeout,
+            retries: self.retries,
+            location: self.location,
 
             given: self.given,
             when: self.when,
@@ -1069,31 +1303,29 @@ where
             __phantom: ::core::default::Default::default(),
         }
     }
-}
 
-impl<World, InnerState: self::scenario::BuilderState> ScenarioBuilder<World, self::scenario::SetThen<InnerState>>
-where
-    <self::scenario::SetThen<InnerState> as self::scenario::BuilderState>::Given: self::marker::IsSet,
-    <self::scenario::SetThen<InnerState> as self::scenario::BuilderState>::When: self::marker::IsSet,
-    <self::scenario::SetThen<InnerState> as self::scenario::BuilderState>::Then: self::marker::IsSet,
-{
-    pub fn and<Description, Callback, Output>(
+    #[cfg(feature = "async")]
+    pub fn but_async<Description, Callback, Output>(
         mut self,
         description: Description,
         callback: Callback,
-    ) -> ScenarioBuilder<World, self::scenario::SetThen<self::scenario::SetThen<InnerState>>>
+    ) -> ScenarioBuilder<World, self::scenario::SetGiven<self::scenario::SetGiven<InnerState>>>
     where
         Description: Into<aliases::string::String>,
-        Callback: Fn(&World) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+        Callback: AsyncFn(&mut World) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
         Output: IntoFallible,
     {
-        let step = IntoScenarioThenStep::into_step((description, callback), StepLabel::And);
-        self.then.push(step);
+        let step = IntoScenarioGivenOrWhenStep::into_step((description, Asynchronous(callback)), StepLabel::But);
+        self.given.push(step);
 
         ScenarioBuilder {
             description: self.description,
             ignored: self.ignored,
             tags: self.tags,
+            soft_then: self.soft_then,
+            timeout: self.timeout,
+            retries: self.retries,
+            location: self.location,
 
             given: self.given,
             when: self.when,
@@ -1103,23 +1335,30 @@ where
         }
     }
 
-    pub fn but<Description, Callback, Output>(
+    #[cfg(feature = "async")]
+    pub fn but_with_async<Description, Argument, Callback, Output>(
         mut self,
-        description: Description,
+        (description, argument): (Description, Argument),
         callback: Callback,
-    ) -> ScenarioBuilder<World, self::scenario::SetThen<self::scenario::SetThen<InnerState>>>
+    ) -> ScenarioBuilder<World, self::scenario::SetGiven<self::scenario::SetGiven<InnerState>>>
     where
         Description: Into<aliases::string::String>,
-        Callback: Fn(&World) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+        Argument: IntoStepArgument,
+        Callback: AsyncFn(&mut World, &Argument) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
         Output: IntoFallible,
     {
-        let step = IntoScenarioThenStep::into_step((description, callback), StepLabel::But);
-        self.then.push(step);
+        let step =
+            IntoScenarioGivenOrWhenStep::into_step((description, argument, Asynchronous(callback)), StepLabel::But);
+        self.given.push(step);
 
         ScenarioBuilder {
             description: self.description,
             ignored: self.ignored,
             tags: self.tags,
+            soft_then: self.soft_then,
+            timeout: self.timeout,
+            retries: self.retries,
+            location: self.location,
 
             given: self.given,
             when: self.when,
@@ -1128,59 +1367,831 @@ where
             __phantom: ::core::default::Default::default(),
         }
     }
-}
 
-impl<World, State: self::scenario::BuilderState> ScenarioBuilder<World, State>
-where
-    State: self::scenario::IsComplete,
-{
-    pub fn build(self) -> Scenario<World> {
-        Scenario {
+    pub fn when<Description, Callback, Output>(
+        mut self,
+        description: Description,
+        callback: Callback,
+    ) -> ScenarioBuilder<World, self::scenario::SetWhen<self::scenario::SetGiven<InnerState>>>
+    where
+        Description: Into<aliases::string::String>,
+        Callback: Fn(&mut World) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+        Output: IntoFallible,
+    {
+        let step = IntoScenarioGivenOrWhenStep::into_step((description, callback), StepLabel::When);
+        self.when.push(step);
+
+        ScenarioBuilder {
             description: self.description,
             ignored: self.ignored,
             tags: self.tags,
+            soft_then: self.soft_then,
+            timeout: self.timeout,
+            retries: self.retries,
+            location: self.location,
 
             given: self.given,
             when: self.when,
             then: self.then,
+
+            __phantom: ::core::default::Default::default(),
         }
     }
-}
 
-mod scenario {
-    pub(super) use super::*;
+    pub fn when_with<Description, Argument, Callback, Output>(
+        mut self,
+        (description, argument): (Description, Argument),
+        callback: Callback,
+    ) -> ScenarioBuilder<World, self::scenario::SetWhen<self::scenario::SetGiven<InnerState>>>
+    where
+        Description: Into<aliases::string::String>,
+        Argument: IntoStepArgument,
+        Callback: Fn(&mut World, &Argument) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+        Output: IntoFallible,
+    {
+        let step = IntoScenarioGivenOrWhenStep::into_step((description, argument, callback), StepLabel::When);
+        self.when.push(step);
 
-    #[sealed]
-    pub trait BuilderState: ::core::marker::Sized {
-        type Description;
-        type Ignored;
-        type Tags;
+        ScenarioBuilder {
+            description: self.description,
+            ignored: self.ignored,
+            tags: self.tags,
+            soft_then: self.soft_then,
+            timeout: self.timeout,
+            retries: self.retries,
+            location: self.location,
 
-        type Given;
-        type When;
-        type Then;
-    }
+            given: self.given,
+            when: self.when,
+            then: self.then,
 
-    #[sealed]
-    pub trait IsComplete:
-        BuilderState<Given: self::marker::IsSet, When: self::marker::IsSet, Then: self::marker::IsSet>
-    {
+            __phantom: ::core::default::Default::default(),
+        }
     }
 
-    #[sealed]
-    impl<State: BuilderState> IsComplete for State
+    #[cfg(feature = "async")]
+    pub fn when_async<Description, Callback, Output>(
+        mut self,
+        description: Description,
+        callback: Callback,
+    ) -> ScenarioBuilder<World, self::scenario::SetWhen<self::scenario::SetGiven<InnerState>>>
     where
-        State::Given: self::marker::IsSet,
-        State::When: self::marker::IsSet,
-        State::Then: self::marker::IsSet,
+        Description: Into<aliases::string::String>,
+        Callback: AsyncFn(&mut World) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+        Output: IntoFallible,
     {
-    }
-
-    pub struct Empty;
+        let step = IntoScenarioGivenOrWhenStep::into_step((description, Asynchronous(callback)), StepLabel::When);
+        self.when.push(step);
 
-    pub struct SetDescription<State: BuilderState = Empty>(aliases::marker::PhantomCovariant<State>);
-    pub struct SetIgnored<State: BuilderState = Empty>(aliases::marker::PhantomCovariant<State>);
-    pub struct SetTags<State: BuilderState = Empty>(aliases::marker::PhantomCovariant<State>);
+        ScenarioBuilder {
+            description: self.description,
+            ignored: self.ignored,
+            tags: self.tags,
+            soft_then: self.soft_then,
+            timeout: self.timeout,
+            retries: self.retries,
+            location: self.location,
+
+            given: self.given,
+            when: self.when,
+            then: self.then,
+
+            __phantom: ::core::default::Default::default(),
+        }
+    }
+
+    #[cfg(feature = "async")]
+    pub fn when_with_async<Description, Argument, Callback, Output>(
+        mut self,
+        (description, argument): (Description, Argument),
+        callback: Callback,
+    ) -> ScenarioBuilder<World, self::scenario::SetWhen<self::scenario::SetGiven<InnerState>>>
+    where
+        Description: Into<aliases::string::String>,
+        Argument: IntoStepArgument,
+        Callback: AsyncFn(&mut World, &Argument) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+        Output: IntoFallible,
+    {
+        let step =
+            IntoScenarioGivenOrWhenStep::into_step((description, argument, Asynchronous(callback)), StepLabel::When);
+        self.when.push(step);
+
+        ScenarioBuilder {
+            description: self.description,
+            ignored: self.ignored,
+            tags: self.tags,
+            soft_then: self.soft_then,
+            timeout: self.timeout,
+            retries: self.retries,
+            location: self.location,
+
+            given: self.given,
+            when: self.when,
+            then: self.then,
+
+            __phantom: ::core::default::Default::default(),
+        }
+    }
+}
+
+impl<World, InnerState: self::scenario::BuilderState> ScenarioBuilder<World, self::scenario::SetWhen<InnerState>>
+where
+    <self::scenario::SetWhen<InnerState> as self::scenario::BuilderState>::Given: self::marker::IsSet,
+    <self::scenario::SetWhen<InnerState> as self::scenario::BuilderState>::When: self::marker::IsSet,
+    <self::scenario::SetWhen<InnerState> as self::scenario::BuilderState>::Then: self::marker::IsUnset,
+{
+    pub fn and<Description, Callback, Output>(
+        mut self,
+        description: Description,
+        callback: Callback,
+    ) -> ScenarioBuilder<World, self::scenario::SetWhen<self::scenario::SetWhen<InnerState>>>
+    where
+        Description: Into<aliases::string::String>,
+        Callback: Fn(&mut World) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+        Output: IntoFallible,
+    {
+        let step = IntoScenarioGivenOrWhenStep::into_step((description, callback), StepLabel::And);
+        self.when.push(step);
+
+        ScenarioBuilder {
+            description: self.description,
+            ignored: self.ignored,
+            tags: self.tags,
+            soft_then: self.soft_then,
+            timeout: self.timeout,
+            retries: self.retries,
+            location: self.location,
+
+            given: self.given,
+            when: self.when,
+            then: self.then,
+
+            __phantom: ::core::default::Default::default(),
+        }
+    }
+
+    pub fn and_with<Description, Argument, Callback, Output>(
+        mut self,
+        (description, argument): (Description, Argument),
+        callback: Callback,
+    ) -> ScenarioBuilder<World, self::scenario::SetWhen<self::scenario::SetWhen<InnerState>>>
+    where
+        Description: Into<aliases::string::String>,
+        Argument: IntoStepArgument,
+        Callback: Fn(&mut World, &Argument) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+        Output: IntoFallible,
+    {
+        let step = IntoScenarioGivenOrWhenStep::into_step((description, argument, callback), StepLabel::And);
+        self.when.push(step);
+
+        ScenarioBuilder {
+            description: self.description,
+            ignored: self.ignored,
+            tags: self.tags,
+            soft_then: self.soft_then,
+            timeout: self.timeout,
+            retries: self.retries,
+            location: self.location,
+
+            given: self.given,
+            when: self.when,
+            then: self.then,
+
+            __phantom: ::core::default::Default::default(),
+        }
+    }
+
+    #[cfg(feature = "async")]
+    pub fn and_async<Description, Callback, Output>(
+        mut self,
+        description: Description,
+        callback: Callback,
+    ) -> ScenarioBuilder<World, self::scenario::SetWhen<self::scenario::SetWhen<InnerState>>>
+    where
+        Description: Into<aliases::string::String>,
+        Callback: AsyncFn(&mut World) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+        Output: IntoFallible,
+    {
+        let step = IntoScenarioGivenOrWhenStep::into_step((description, Asynchronous(callback)), StepLabel::And);
+        self.when.push(step);
+
+        ScenarioBuilder {
+            description: self.description,
+            ignored: self.ignored,
+            tags: self.tags,
+            soft_then: self.soft_then,
+            timeout: self.timeout,
+            retries: self.retries,
+            location: self.location,
+
+            given: self.given,
+            when: self.when,
+            then: self.then,
+
+            __phantom: ::core::default::Default::default(),
+        }
+    }
+
+    #[cfg(feature = "async")]
+    pub fn and_with_async<Description, Argument, Callback, Output>(
+        mut self,
+        (description, argument): (Description, Argument),
+        callback: Callback,
+    ) -> ScenarioBuilder<World, self::scenario::SetWhen<self::scenario::SetWhen<InnerState>>>
+    where
+        Description: Into<aliases::string::String>,
+        Argument: IntoStepArgument,
+        Callback: AsyncFn(&mut World, &Argument) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+        Output: IntoFallible,
+    {
+        let step =
+            IntoScenarioGivenOrWhenStep::into_step((description, argument, Asynchronous(callback)), StepLabel::And);
+        self.when.push(step);
+
+        ScenarioBuilder {
+            description: self.description,
+            ignored: self.ignored,
+            tags: self.tags,
+            soft_then: self.soft_then,
+            timeout: self.timeout,
+            retries: self.retries,
+            location: self.location,
+
+            given: self.given,
+            when: self.when,
+            then: self.then,
+
+            __phantom: ::core::default::Default::default(),
+        }
+    }
+
+    pub fn but<Description, Callback, Output>(
+        mut self,
+        description: Description,
+        callback: Callback,
+    ) -> ScenarioBuilder<World, self::scenario::SetWhen<self::scenario::SetWhen<InnerState>>>
+    where
+        Description: Into<aliases::string::String>,
+        Callback: Fn(&mut World) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+        Output: IntoFallible,
+    {
+        let step = IntoScenarioGivenOrWhenStep::into_step((description, callback), StepLabel::But);
+        self.when.push(step);
+
+        ScenarioBuilder {
+            description: self.description,
+            ignored: self.ignored,
+            tags: self.tags,
+            soft_then: self.soft_then,
+            timeout: self.timeout,
+            retries: self.retries,
+            location: self.location,
+
+            given: self.given,
+            when: self.when,
+            then: self.then,
+
+            __phantom: ::core::default::Default::default(),
+        }
+    }
+
+    pub fn but_with<Description, Argument, Callback, Output>(
+        mut self,
+        (description, argument): (Description, Argument),
+        callback: Callback,
+    ) -> ScenarioBuilder<World, self::scenario::SetWhen<self::scenario::SetWhen<InnerState>>>
+    where
+        Description: Into<aliases::string::String>,
+        Argument: IntoStepArgument,
+        Callback: Fn(&mut World, &Argument) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+        Output: IntoFallible,
+    {
+        let step = IntoScenarioGivenOrWhenStep::into_step((description, argument, callback), StepLabel::But);
+        self.when.push(step);
+
+        ScenarioBuilder {
+            description: self.description,
+            ignored: self.ignored,
+            tags: self.tags,
+            soft_then: self.soft_then,
+            timeout: self.timeout,
+            retries: self.retries,
+            location: self.location,
+
+            given: self.given,
+            when: self.when,
+            then: self.then,
+
+            __phantom: ::core::default::Default::default(),
+        }
+    }
+
+    #[cfg(feature = "async")]
+    pub fn but_async<Description, Callback, Output>(
+        mut self,
+        description: Description,
+        callback: Callback,
+    ) -> ScenarioBuilder<World, self::scenario::SetWhen<self::scenario::SetWhen<InnerState>>>
+    where
+        Description: Into<aliases::string::String>,
+        Callback: AsyncFn(&mut World) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+        Output: IntoFallible,
+    {
+        let step = IntoScenarioGivenOrWhenStep::into_step((description, Asynchronous(callback)), StepLabel::But);
+        self.when.push(step);
+
+        ScenarioBuilder {
+            description: self.description,
+            ignored: self.ignored,
+            tags: self.tags,
+            soft_then: self.soft_then,
+            timeout: self.timeout,
+            retries: self.retries,
+            location: self.location,
+
+            given: self.given,
+            when: self.when,
+            then: self.then,
+
+            __phantom: ::core::default::Default::default(),
+        }
+    }
+
+    #[cfg(feature = "async")]
+    pub fn but_with_async<Description, Argument, Callback, Output>(
+        mut self,
+        (description, argument): (Description, Argument),
+        callback: Callback,
+    ) -> ScenarioBuilder<World, self::scenario::SetWhen<self::scenario::SetWhen<InnerState>>>
+    where
+        Description: Into<aliases::string::String>,
+        Argument: IntoStepArgument,
+        Callback: AsyncFn(&mut World, &Argument) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+        Output: IntoFallible,
+    {
+        let step =
+            IntoScenarioGivenOrWhenStep::into_step((description, argument, Asynchronous(callback)), StepLabel::But);
+        self.when.push(step);
+
+        ScenarioBuilder {
+            description: self.description,
+            ignored: self.ignored,
+            tags: self.tags,
+            soft_then: self.soft_then,
+            timeout: self.timeout,
+            retries: self.retries,
+            location: self.location,
+
+            given: self.given,
+            when: self.when,
+            then: self.then,
+
+            __phantom: ::core::default::Default::default(),
+        }
+    }
+
+    pub fn then<Description, Callback, Output>(
+        mut self,
+        description: Description,
+        callback: Callback,
+    ) -> ScenarioBuilder<World, self::scenario::SetThen<self::scenario::SetWhen<InnerState>>>
+    where
+        Description: Into<aliases::string::String>,
+        Callback: Fn(&World) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+        Output: IntoFallible,
+    {
+        let step = IntoScenarioThenStep::into_step((description, callback), StepLabel::Then);
+        self.then.push(step);
+
+        ScenarioBuilder {
+            description: self.description,
+            ignored: self.ignored,
+            tags: self.tags,
+            soft_then: self.soft_then,
+            timeout: self.timeout,
+            retries: self.retries,
+            location: self.location,
+
+            given: self.given,
+            when: self.when,
+            then: self.then,
+
+            __phantom: ::core::default::Default::default(),
+        }
+    }
+
+    pub fn then_with<Description, Argument, Callback, Output>(
+        mut self,
+        (description, argument): (Description, Argument),
+        callback: Callback,
+    ) -> ScenarioBuilder<World, self::scenario::SetThen<self::scenario::SetWhen<InnerState>>>
+    where
+        Description: Into<aliases::string::String>,
+        Argument: IntoStepArgument,
+        Callback: Fn(&World, &Argument) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+        Output: IntoFallible,
+    {
+        let step = IntoScenarioThenStep::into_step((description, argument, callback), StepLabel::Then);
+        self.then.push(step);
+
+        ScenarioBuilder {
+            description: self.description,
+            ignored: self.ignored,
+            tags: self.tags,
+            soft_then: self.soft_then,
+            timeout: self.timeout,
+            retries: self.retries,
+            location: self.location,
+
+            given: self.given,
+            when: self.when,
+            then: self.then,
+
+            __phantom: ::core::default::Default::default(),
+        }
+    }
+
+    #[cfg(feature = "async")]
+    pub fn then_async<Description, Callback, Output>(
+        mut self,
+        description: Description,
+        callback: Callback,
+    ) -> ScenarioBuilder<World, self::scenario::SetThen<self::scenario::SetWhen<InnerState>>>
+    where
+        Description: Into<aliases::string::String>,
+        Callback: AsyncFn(&World) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+        Output: IntoFallible,
+    {
+        let step = IntoScenarioThenStep::into_step((description, Asynchronous(callback)), StepLabel::Then);
+        self.then.push(step);
+
+        ScenarioBuilder {
+            description: self.description,
+            ignored: self.ignored,
+            tags: self.tags,
+            soft_then: self.soft_then,
+            timeout: self.timeout,
+            retries: self.retries,
+            location: self.location,
+
+            given: self.given,
+            when: self.when,
+            then: self.then,
+
+            __phantom: ::core::default::Default::default(),
+        }
+    }
+
+    #[cfg(feature = "async")]
+    pub fn then_with_async<Description, Argument, Callback, Output>(
+        mut self,
+        (description, argument): (Description, Argument),
+        callback: Callback,
+    ) -> ScenarioBuilder<World, self::scenario::SetThen<self::scenario::SetWhen<InnerState>>>
+    where
+        Description: Into<aliases::string::String>,
+        Argument: IntoStepArgument,
+        Callback: AsyncFn(&World, &Argument) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+        Output: IntoFallible,
+    {
+        let step = IntoScenarioThenStep::into_step((description, argument, Asynchronous(callback)), StepLabel::Then);
+        self.then.push(step);
+
+        ScenarioBuilder {
+            description: self.description,
+            ignored: self.ignored,
+            tags: self.tags,
+            soft_then: self.soft_then,
+            timeout: self.timeout,
+            retries: self.retries,
+            location: self.location,
+
+            given: self.given,
+            when: self.when,
+            then: self.then,
+
+            __phantom: ::core::default::Default::default(),
+        }
+    }
+}
+
+impl<World, InnerState: self::scenario::BuilderState> ScenarioBuilder<World, self::scenario::SetThen<InnerState>>
+where
+    <self::scenario::SetThen<InnerState> as self::scenario::BuilderState>::Given: self::marker::IsSet,
+    <self::scenario::SetThen<InnerState> as self::scenario::BuilderState>::When: self::marker::IsSet,
+    <self::scenario::SetThen<InnerState> as self::scenario::BuilderState>::Then: self::marker::IsSet,
+{
+    pub fn and<Description, Callback, Output>(
+        mut self,
+        description: Description,
+        callback: Callback,
+    ) -> ScenarioBuilder<World, self::scenario::SetThen<self::scenario::SetThen<InnerState>>>
+    where
+        Description: Into<aliases::string::String>,
+        Callback: Fn(&World) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+        Output: IntoFallible,
+    {
+        let step = IntoScenarioThenStep::into_step((description, callback), StepLabel::And);
+        self.then.push(step);
+
+        ScenarioBuilder {
+            description: self.description,
+            ignored: self.ignored,
+            tags: self.tags,
+            soft_then: self.soft_then,
+            timeout: self.timeout,
+            retries: self.retries,
+            location: self.location,
+
+            given: self.given,
+            when: self.when,
+            then: self.then,
+
+            __phantom: ::core::default::Default::default(),
+        }
+    }
+
+    pub fn and_with<Description, Argument, Callback, Output>(
+        mut self,
+        (description, argument): (Description, Argument),
+        callback: Callback,
+    ) -> ScenarioBuilder<World, self::scenario::SetThen<self::scenario::SetThen<InnerState>>>
+    where
+        Description: Into<aliases::string::String>,
+        Argument: IntoStepArgument,
+        Callback: Fn(&World, &Argument) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+        Output: IntoFallible,
+    {
+        let step = IntoScenarioThenStep::into_step((description, argument, callback), StepLabel::And);
+        self.then.push(step);
+
+        ScenarioBuilder {
+            description: self.description,
+            ignored: self.ignored,
+            tags: self.tags,
+            soft_then: self.soft_then,
+            timeout: self.timeout,
+            retries: self.retries,
+            location: self.location,
+
+            given: self.given,
+            when: self.when,
+            then: self.then,
+
+            __phantom: ::core::default::Default::default(),
+        }
+    }
+
+    #[cfg(feature = "async")]
+    pub fn and_async<Description, Callback, Output>(
+        mut self,
+        description: Description,
+        callback: Callback,
+    ) -> ScenarioBuilder<World, self::scenario::SetThen<self::scenario::SetThen<InnerState>>>
+    where
+        Description: Into<aliases::string::String>,
+        Callback: AsyncFn(&World) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+        Output: IntoFallible,
+    {
+        let step = IntoScenarioThenStep::into_step((description, Asynchronous(callback)), StepLabel::And);
+        self.then.push(step);
+
+        ScenarioBuilder {
+            description: self.description,
+            ignored: self.ignored,
+            tags: self.tags,
+            soft_then: self.soft_then,
+            timeout: self.timeout,
+            retries: self.retries,
+            location: self.location,
+
+            given: self.given,
+            when: self.when,
+            then: self.then,
+
+            __phantom: ::core::default::Default::default(),
+        }
+    }
+
+    #[cfg(feature = "async")]
+    pub fn and_with_async<Description, Argument, Callback, Output>(
+        mut self,
+        (description, argument): (Description, Argument),
+        callback: Callback,
+    ) -> ScenarioBuilder<World, self::scenario::SetThen<self::scenario::SetThen<InnerState>>>
+    where
+        Description: Into<aliases::string::String>,
+        Argument: IntoStepArgument,
+        Callback: AsyncFn(&World, &Argument) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+        Output: IntoFallible,
+    {
+        let step = IntoScenarioThenStep::into_step((description, argument, Asynchronous(callback)), StepLabel::And);
+        self.then.push(step);
+
+        ScenarioBuilder {
+            description: self.description,
+            ignored: self.ignored,
+            tags: self.tags,
+            soft_then: self.soft_then,
+            timeout: self.timeout,
+            retries: self.retries,
+            location: self.location,
+
+            given: self.given,
+            when: self.when,
+            then: self.then,
+
+            __phantom: ::core::default::Default::default(),
+        }
+    }
+
+    pub fn but<Description, Callback, Output>(
+        mut self,
+        description: Description,
+        callback: Callback,
+    ) -> ScenarioBuilder<World, self::scenario::SetThen<self::scenario::SetThen<InnerState>>>
+    where
+        Description: Into<aliases::string::String>,
+        Callback: Fn(&World) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+        Output: IntoFallible,
+    {
+        let step = IntoScenarioThenStep::into_step((description, callback), StepLabel::But);
+        self.then.push(step);
+
+        ScenarioBuilder {
+            description: self.description,
+            ignored: self.ignored,
+            tags: self.tags,
+            soft_then: self.soft_then,
+            timeout: self.timeout,
+            retries: self.retries,
+            location: self.location,
+
+            given: self.given,
+            when: self.when,
+            then: self.then,
+
+            __phantom: ::core::default::Default::default(),
+        }
+    }
+
+    pub fn but_with<Description, Argument, Callback, Output>(
+        mut self,
+        (description, argument): (Description, Argument),
+        callback: Callback,
+    ) -> ScenarioBuilder<World, self::scenario::SetThen<self::scenario::SetThen<InnerState>>>
+    where
+        Description: Into<aliases::string::String>,
+        Argument: IntoStepArgument,
+        Callback: Fn(&World, &Argument) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+        Output: IntoFallible,
+    {
+        let step = IntoScenarioThenStep::into_step((description, argument, callback), StepLabel::But);
+        self.then.push(step);
+
+        ScenarioBuilder {
+            description: self.description,
+            ignored: self.ignored,
+            tags: self.tags,
+            soft_then: self.soft_then,
+            timeout: self.timeout,
+            retries: self.retries,
+            location: self.location,
+
+            given: self.given,
+            when: self.when,
+            then: self.then,
+
+            __phantom: ::core::default::Default::default(),
+        }
+    }
+
+    #[cfg(feature = "async")]
+    pub fn but_async<Description, Callback, Output>(
+        mut self,
+        description: Description,
+        callback: Callback,
+    ) -> ScenarioBuilder<World, self::scenario::SetThen<self::scenario::SetThen<InnerState>>>
+    where
+        Description: Into<aliases::string::String>,
+        Callback: AsyncFn(&World) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+        Output: IntoFallible,
+    {
+        let step = IntoScenarioThenStep::into_step((description, Asynchronous(callback)), StepLabel::But);
+        self.then.push(step);
+
+        ScenarioBuilder {
+            description: self.description,
+            ignored: self.ignored,
+            tags: self.tags,
+            soft_then: self.soft_then,
+            timeout: self.timeout,
+            retries: self.retries,
+            location: self.location,
+
+            given: self.given,
+            when: self.when,
+            then: self.then,
+
+            __phantom: ::core::default::Default::default(),
+        }
+    }
+
+    #[cfg(feature = "async")]
+    pub fn but_with_async<Description, Argument, Callback, Output>(
+        mut self,
+        (description, argument): (Description, Argument),
+        callback: Callback,
+    ) -> ScenarioBuilder<World, self::scenario::SetThen<self::scenario::SetThen<InnerState>>>
+    where
+        Description: Into<aliases::string::String>,
+        Argument: IntoStepArgument,
+        Callback: AsyncFn(&World, &Argument) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+        Output: IntoFallible,
+    {
+        let step = IntoScenarioThenStep::into_step((description, argument, Asynchronous(callback)), StepLabel::But);
+        self.then.push(step);
+
+        ScenarioBuilder {
+            description: self.description,
+            ignored: self.ignored,
+            tags: self.tags,
+            soft_then: self.soft_then,
+            timeout: self.timeout,
+            retries: self.retries,
+            location: self.location,
+
+            given: self.given,
+            when: self.when,
+            then: self.then,
+
+            __phantom: ::core::default::Default::default(),
+        }
+    }
+}
+
+impl<World, State: self::scenario::BuilderState> ScenarioBuilder<World, State>
+where
+    State: self::scenario::IsComplete,
+{
+    pub fn build(self) -> Scenario<World> {
+        Scenario {
+            description: self.description,
+            ignored: self.ignored,
+            tags: self.tags,
+            soft_then: self.soft_then.unwrap_or_default(),
+            timeout: self.timeout,
+            retries: self.retries,
+            location: self.location,
+            narrative: None,
+
+            given: self.given,
+            when: self.when,
+            then: self.then,
+        }
+    }
+}
+
+mod scenario {
+    pub(super) use super::*;
+
+    #[sealed]
+    pub trait BuilderState: ::core::marker::Sized {
+        type Description;
+        type Ignored;
+        type Tags;
+        type SoftThen;
+        type Timeout;
+        type Retries;
+
+        type Given;
+        type When;
+        type Then;
+    }
+
+    #[sealed]
+    pub trait IsComplete:
+        BuilderState<Given: self::marker::IsSet, When: self::marker::IsSet, Then: self::marker::IsSet>
+    {
+    }
+
+    #[sealed]
+    impl<State: BuilderState> IsComplete for State
+    where
+        State::Given: self::marker::IsSet,
+        State::When: self::marker::IsSet,
+        State::Then: self::marker::IsSet,
+    {
+    }
+
+    pub struct Empty;
+
+    pub struct SetDescription<State: BuilderState = Empty>(aliases::marker::PhantomCovariant<State>);
+    pub struct SetIgnored<State: BuilderState = Empty>(aliases::marker::PhantomCovariant<State>);
+    pub struct SetTags<State: BuilderState = Empty>(aliases::marker::PhantomCovariant<State>);
+    pub struct SetSoftThen<State: BuilderState = Empty>(aliases::marker::PhantomCovariant<State>);
+    pub struct SetTimeout<State: BuilderState = Empty>(aliases::marker::PhantomCovariant<State>);
+    pub struct SetRetries<State: BuilderState = Empty>(aliases::marker::PhantomCovariant<State>);
 
     pub struct SetGiven<State: BuilderState = Empty>(aliases::marker::PhantomCovariant<State>);
     pub struct SetWhen<State: BuilderState = Empty>(aliases::marker::PhantomCovariant<State>);
@@ -1192,6 +2203,9 @@ mod scenario {
         type Description = self::marker::Unset<self::members::Description>;
         type Ignored = self::marker::Unset<self::members::Ignored>;
         type Tags = self::marker::Unset<self::members::Tags>;
+        type SoftThen = self::marker::Unset<self::members::SoftThen>;
+        type Timeout = self::marker::Unset<self::members::Timeout>;
+        type Retries = self::marker::Unset<self::members::Retries>;
 
         type Given = self::marker::Unset<self::members::Given>;
         type When = self::marker::Unset<self::members::When>;
@@ -1204,6 +2218,9 @@ mod scenario {
         type Description = self::marker::Set<self::members::Description>;
         type Ignored = State::Ignored;
         type Tags = State::Tags;
+        type SoftThen = State::SoftThen;
+        type Timeout = State::Timeout;
+        type Retries = State::Retries;
 
         type Given = State::Given;
         type When = State::When;
@@ -1216,6 +2233,9 @@ mod scenario {
         type Description = State::Description;
         type Ignored = self::marker::Set<self::members::Ignored>;
         type Tags = State::Tags;
+        type SoftThen = State::SoftThen;
+        type Timeout = State::Timeout;
+        type Retries = State::Retries;
 
         type Given = State::Given;
         type When = State::When;
@@ -1228,6 +2248,54 @@ mod scenario {
         type Description = State::Description;
         type Ignored = State::Ignored;
         type Tags = self::marker::Set<self::members::Tags>;
+        type SoftThen = State::SoftThen;
+        type Timeout = State::Timeout;
+        type Retries = State::Retries;
+
+        type Given = State::Given;
+        type When = State::When;
+        type Then = State::Then;
+    }
+
+    #[rustfmt::skip] // `reorder_impl_items`
+    #[sealed]
+    impl<State: BuilderState> BuilderState for SetSoftThen<State> {
+        type Description = State::Description;
+        type Ignored = State::Ignored;
+        type Tags = State::Tags;
+        type SoftThen = self::marker::Set<self::members::SoftThen>;
+        type Timeout = State::Timeout;
+        type Retries = State::Retries;
+
+        type Given = State::Given;
+        type When = State::When;
+        type Then = State::Then;
+    }
+
+    #[rustfmt::skip] // `reorder_impl_items`
+    #[sealed]
+    impl<State: BuilderState> BuilderState for SetTimeout<State> {
+        type Description = State::Description;
+        type Ignored = State::Ignored;
+        type Tags = State::Tags;
+        type SoftThen = State::SoftThen;
+        type Timeout = self::marker::Set<self::members::Timeout>;
+        type Retries = State::Retries;
+
+        type Given = State::Given;
+        type When = State::When;
+        type Then = State::Then;
+    }
+
+    #[rustfmt::skip] // `reorder_impl_items`
+    #[sealed]
+    impl<State: BuilderState> BuilderState for SetRetries<State> {
+        type Description = State::Description;
+        type Ignored = State::Ignored;
+        type Tags = State::Tags;
+        type SoftThen = State::SoftThen;
+        type Timeout = State::Timeout;
+        type Retries = self::marker::Set<self::members::Retries>;
 
         type Given = State::Given;
         type When = State::When;
@@ -1240,6 +2308,9 @@ mod scenario {
         type Description = State::Description;
         type Ignored = State::Ignored;
         type Tags = State::Tags;
+        type SoftThen = State::SoftThen;
+        type Timeout = State::Timeout;
+        type Retries = State::Retries;
 
         type Given = self::marker::Set<self::members::Given>;
         type When = State::When;
@@ -1252,6 +2323,9 @@ mod scenario {
         type Description = State::Description;
         type Ignored = State::Ignored;
         type Tags = State::Tags;
+        type SoftThen = State::SoftThen;
+        type Timeout = State::Timeout;
+        type Retries = State::Retries;
 
         type Given = State::Given;
         type When = self::marker::Set<self::members::When>;
@@ -1264,6 +2338,9 @@ mod scenario {
         type Description = State::Description;
         type Ignored = State::Ignored;
         type Tags = State::Tags;
+        type SoftThen = State::SoftThen;
+        type Timeout = State::Timeout;
+        type Retries = State::Retries;
 
         type Given = State::Given;
         type When = State::When;
@@ -1274,6 +2351,9 @@ mod scenario {
         pub struct Description;
         pub struct Ignored;
         pub struct Tags;
+        pub struct SoftThen;
+        pub struct Timeout;
+        pub struct Retries;
 
         pub struct Given;
         pub struct When;
@@ -1314,7 +2394,7 @@ pub struct ScenarioOutlineBuilder<
     tags: ::core::option::Option<Tags>,
 
     scenario: ::core::option::Option<::std::boxed::Box<dyn Fn(Example) -> Scenario<World>>>,
-    examples: ::std::vec::Vec<Example>,
+    examples: ::std::vec::Vec<Examples<Example>>,
 
     __phantom: aliases::marker::PhantomCovariant<State>,
 }
@@ -1429,6 +2509,8 @@ impl<World, Example, State: self::scenario_outline::BuilderState> ScenarioOutlin
         }
     }
 
+    /// Adds a row to the last examples block if it has no name, description or
+    /// tags, or to a new such block otherwise.
     pub fn example(
         mut self,
         example: Example,
@@ -1436,7 +2518,7 @@ impl<World, Example, State: self::scenario_outline::BuilderState> ScenarioOutlin
     where
         State::Scenario: self::marker::IsSet,
     {
-        self.examples.push(example);
+        self.push_examples(Examples::builder().row(example).build());
 
         ScenarioOutlineBuilder {
             description: self.description,
@@ -1450,14 +2532,17 @@ impl<World, Example, State: self::scenario_outline::BuilderState> ScenarioOutlin
         }
     }
 
+    /// Adds an examples block, either built with [`Examples::new`] to give it a
+    /// name, a description or tags, or given as a plain list of rows, which
+    /// are added as with [`example`](Self::example).
     pub fn examples(
         mut self,
-        examples: impl IntoIterator<Item = Example>,
+        examples: impl IntoExamples<Example>,
     ) -> ScenarioOutlineBuilder<World, Example, self::scenario_outline::SetExamples<State>>
     where
         State::Scenario: self::marker::IsSet,
     {
-        self.examples.extend(examples);
+        self.push_examples(examples.into_examples());
 
         ScenarioOutlineBuilder {
             description: self.description,
@@ -1472,6 +2557,21 @@ impl<World, Example, State: self::scenario_outline::BuilderState> ScenarioOutlin
     }
 }
 
+impl<World, Example, State: self::scenario_outline::BuilderState> ScenarioOutlineBuilder<World, Example, State> {
+    fn push_examples(&mut self, examples: Examples<Example>) {
+        match self.examples.last_mut() {
+            Some(last) if last.is_anonymous() && examples.is_anonymous() => last.rows.extend(examples.rows),
+            _ => self.examples.push(examples),
+        }
+    }
+}
+
+impl<Example> Examples<Example> {
+    fn is_anonymous(&self) -> bool {
+        self.name.is_none() && self.description.is_none() && self.tags.is_none()
+    }
+}
+
 impl<World, Example, State: self::scenario_outline::BuilderState> ScenarioOutlineBuilder<World, Example, State>
 where
     State: self::scenario_outline::IsComplete,
@@ -1609,16 +2709,34 @@ pub trait IntoScenarioOutline<World, Example> {
     {
         let scenario_outline = self.into_scenario_outline();
 
-        scenario_outline.examples.into_iter().map(move |example| {
+        let examples = scenario_outline.examples.into_iter().flat_map(|examples| {
+            let rows = examples.rows.into_iter().enumerate();
+            rows.zip(::core::iter::repeat((examples.name, examples.description, examples.tags)))
+        });
+
+        examples.map(move |((index, example), (name, narrative, tags))| {
             let mut scenario = (scenario_outline.scenario)(example);
+            scenario.narrative = narrative;
+
+            if let Some(description) = scenario_outline.description.as_ref() {
+                let examples = match name {
+                    Some(name) => ::std::format!("{} — Examples: {} #{}", description, name, index + 1),
+                    None => ::std::format!("{} — Examples #{}", description, index + 1),
+                };
+
+                scenario.description =
+                    ::core::option::Option::from(aliases::string::String::from(match scenario.description.take() {
+                        Some(row) => ::std::format!("{} ({})", examples, row),
+                        None => examples,
+                    }));
+            }
 
-            if let Some(ignored) = scenario_outline.ignored.as_ref().cloned() {
-                let scenario_ignored = scenario.ignored.get_or_insert(true);
-                *scenario_ignored = *scenario_ignored && ignored;
+            if let Some(ignored) = scenario_outline.ignored {
+                scenario.ignored = ::core::option::Option::from(scenario.ignored.unwrap_or(false) || ignored);
             }
 
-            if let Some(tags) = scenario_outline.tags.as_ref().cloned() {
-                scenario.tags.get_or_insert_default().extend(tags)
+            for tags in [scenario_outline.tags.as_ref(), tags.as_ref()].into_iter().flatten() {
+                scenario.tags.get_or_insert_default().extend(tags.iter().cloned())
             }
 
             scenario
@@ -1633,15 +2751,273 @@ impl<World, Example> IntoScenarioOutline<World, Example> for ScenarioOutline<Wor
     }
 }
 
-#[cfg(feature = "allow-natural")]
+#[cfg(feature = "allow-natural")]
+#[sealed]
+impl<World, Example, State: self::scenario_outline::BuilderState> IntoScenarioOutline<World, Example>
+    for ScenarioOutlineBuilder<World, Example, State>
+where
+    State: self::scenario_outline::IsComplete,
+{
+    fn into_scenario_outline(self) -> ScenarioOutline<World, Example> {
+        self.build()
+    }
+}
+
+pub struct ExamplesBuilder<Example, State: self::examples::BuilderState = self::examples::Empty> {
+    name: ::core::option::Option<aliases::string::String>,
+    description: ::core::option::Option<aliases::string::String>,
+    tags: ::core::option::Option<Tags>,
+
+    rows: ::std::vec::Vec<Example>,
+
+    __phantom: aliases::marker::PhantomCovariant<State>,
+}
+
+impl<Example> Examples<Example> {
+    #[cfg(feature = "allow-natural")]
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new() -> ExamplesBuilder<Example> {
+        Self::builder()
+    }
+
+    pub fn builder() -> ExamplesBuilder<Example> {
+        ExamplesBuilder {
+            name: ::core::default::Default::default(),
+            description: ::core::default::Default::default(),
+            tags: ::core::default::Default::default(),
+
+            rows: ::core::default::Default::default(),
+
+            __phantom: ::core::default::Default::default(),
+        }
+    }
+}
+
+impl<Example, State: self::examples::BuilderState> ExamplesBuilder<Example, State> {
+    /// Named blocks name the scenarios of their rows after themselves, as in
+    /// `Outline — Examples: edge cases #3`.
+    pub fn name(
+        mut self,
+        name: impl Into<aliases::string::String>,
+    ) -> ExamplesBuilder<Example, self::examples::SetName<State>>
+    where
+        State::Name: self::marker::IsUnset,
+    {
+        self.name = ::core::option::Option::from(name.into());
+
+        ExamplesBuilder {
+            name: self.name,
+            description: self.description,
+            tags: self.tags,
+
+            rows: self.rows,
+
+            __phantom: ::core::default::Default::default(),
+        }
+    }
+
+    /// Printed under the scenarios of the block's rows in plans and reports.
+    pub fn description(
+        mut self,
+        description: impl Into<aliases::string::String>,
+    ) -> ExamplesBuilder<Example, self::examples::SetDescription<State>>
+    where
+        State::Description: self::marker::IsUnset,
+    {
+        self.description = ::core::option::Option::from(description.into());
+
+        ExamplesBuilder {
+            name: self.name,
+            description: self.description,
+            tags: self.tags,
+
+            rows: self.rows,
+
+            __phantom: ::core::default::Default::default(),
+        }
+    }
+
+    /// Tags are added to those of the outline in every scenario of the block.
+    pub fn tags(mut self, tags: impl IntoTags) -> ExamplesBuilder<Example, self::examples::SetTags<State>>
+    where
+        State::Tags: self::marker::IsUnset,
+    {
+        self.tags = ::core::option::Option::from(tags.into_tags());
+
+        ExamplesBuilder {
+            name: self.name,
+            description: self.description,
+            tags: self.tags,
+
+            rows: self.rows,
+
+            __phantom: ::core::default::Default::default(),
+        }
+    }
+
+    pub fn row(mut self, row: Example) -> ExamplesBuilder<Example, self::examples::SetRows<State>> {
+        self.rows.push(row);
+
+        ExamplesBuilder {
+            name: self.name,
+            description: self.description,
+            tags: self.tags,
+
+            rows: self.rows,
+
+            __phantom: ::core::default::Default::default(),
+        }
+    }
+
+    pub fn rows(
+        mut self,
+        rows: impl IntoIterator<Item = Example>,
+    ) -> ExamplesBuilder<Example, self::examples::SetRows<State>> {
+        self.rows.extend(rows);
+
+        ExamplesBuilder {
+            name: self.name,
+            description: self.description,
+            tags: self.tags,
+
+            rows: self.rows,
+
+            __phantom: ::core::default::Default::default(),
+        }
+    }
+}
+
+impl<Example, State: self::examples::BuilderState> ExamplesBuilder<Example, State>
+where
+    State: self::examples::IsComplete,
+{
+    pub fn build(self) -> Examples<Example> {
+        Examples {
+            name: self.name,
+            description: self.description,
+            tags: self.tags,
+
+            rows: self.rows,
+        }
+    }
+}
+
+mod examples {
+    pub(super) use super::*;
+
+    #[sealed]
+    pub trait BuilderState: ::core::marker::Sized {
+        type Name;
+        type Description;
+        type Tags;
+
+        type Rows;
+    }
+
+    #[sealed]
+    pub trait IsComplete: BuilderState<Rows: self::marker::IsSet> {}
+
+    #[sealed]
+    impl<State: BuilderState> IsComplete for State where State::Rows: self::marker::IsSet {}
+
+    pub struct Empty;
+
+    pub struct SetName<State: BuilderState = Empty>(aliases::marker::PhantomCovariant<State>);
+    pub struct SetDescription<State: BuilderState = Empty>(aliases::marker::PhantomCovariant<State>);
+    pub struct SetTags<State: BuilderState = Empty>(aliases::marker::PhantomCovariant<State>);
+
+    pub struct SetRows<State: BuilderState = Empty>(aliases::marker::PhantomCovariant<State>);
+
+    #[rustfmt::skip] // `reorder_impl_items`
+    #[sealed]
+    impl BuilderState for Empty {
+        type Name = self::marker::Unset<self::members::Name>;
+        type Description = self::marker::Unset<self::members::Description>;
+        type Tags = self::marker::Unset<self::members::Tags>;
+
+        type Rows = self::marker::Unset<self::members::Rows>;
+    }
+
+    #[rustfmt::skip] // `reorder_impl_items`
+    #[sealed]
+    impl<State: BuilderState> BuilderState for SetName<State> {
+        type Name = self::marker::Set<self::members::Name>;
+        type Description = State::Description;
+        type Tags = State::Tags;
+
+        type Rows = State::Rows;
+    }
+
+    #[rustfmt::skip] // `reorder_impl_items`
+    #[sealed]
+    impl<State: BuilderState> BuilderState for SetDescription<State> {
+        type Name = State::Name;
+        type Description = self::marker::Set<self::members::Description>;
+        type Tags = State::Tags;
+
+        type Rows = State::Rows;
+    }
+
+    #[rustfmt::skip] // `reorder_impl_items`
+    #[sealed]
+    impl<State: BuilderState> BuilderState for SetTags<State> {
+        type Name = State::Name;
+        type Description = State::Description;
+        type Tags = self::marker::Set<self::members::Tags>;
+
+        type Rows = State::Rows;
+    }
+
+    #[rustfmt::skip] // `reorder_impl_items`
+    #[sealed]
+    impl<State: BuilderState> BuilderState for SetRows<State> {
+        type Name = State::Name;
+        type Description = State::Description;
+        type Tags = State::Tags;
+
+        type Rows = self::marker::Set<self::members::Rows>;
+    }
+
+    mod members {
+        pub struct Name;
+        pub struct Description;
+        pub struct Tags;
+
+        pub struct Rows;
+    }
+}
+
+#[sealed]
+pub trait IntoExamples<Example> {
+    fn into_examples(self) -> Examples<Example>;
+}
+
+#[sealed]
+impl<Example> IntoExamples<Example> for Examples<Example> {
+    fn into_examples(self) -> Examples<Example> {
+        self
+    }
+}
+
+#[cfg(feature = "allow-natural")]
+#[sealed]
+impl<Example, State: self::examples::BuilderState> IntoExamples<Example> for ExamplesBuilder<Example, State>
+where
+    State: self::examples::IsComplete,
+{
+    fn into_examples(self) -> Examples<Example> {
+        self.build()
+    }
+}
+
+/// Rows given as such form a block without a name, description or tags.
 #[sealed]
-impl<World, Example, State: self::scenario_outline::BuilderState> IntoScenarioOutline<World, Example>
-    for ScenarioOutlineBuilder<World, Example, State>
+impl<Example, Rows> IntoExamples<Example> for Rows
 where
-    State: self::scenario_outline::IsComplete,
+    Rows: IntoIterator<Item = Example>,
 {
-    fn into_scenario_outline(self) -> ScenarioOutline<World, Example> {
-        self.build()
+    fn into_examples(self) -> Examples<Example> {
+        Examples::builder().rows(self).build()
     }
 }
 
@@ -1734,6 +3110,80 @@ where
             __phantom: ::core::default::Default::default(),
         }
     }
+
+    pub fn given_with<Description, Argument, Callback, Output>(
+        mut self,
+        (description, argument): (Description, Argument),
+        callback: Callback,
+    ) -> BackgroundBuilder<World, self::background::SetGiven<State>>
+    where
+        Description: Into<aliases::string::String>,
+        Argument: IntoStepArgument,
+        Callback: Fn(&mut World, &Argument) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+        Output: IntoFallible,
+    {
+        let step = IntoBackgroundGivenStep::into_step((description, argument, callback), StepLabel::Given);
+        self.given.push(step);
+
+        BackgroundBuilder {
+            description: self.description,
+            ignored: self.ignored,
+
+            given: self.given,
+
+            __phantom: ::core::default::Default::default(),
+        }
+    }
+
+    #[cfg(feature = "async")]
+    pub fn given_async<Description, Callback, Output>(
+        mut self,
+        description: Description,
+        callback: Callback,
+    ) -> BackgroundBuilder<World, self::background::SetGiven<State>>
+    where
+        Description: Into<aliases::string::String>,
+        Callback: AsyncFn(&mut World) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+        Output: IntoFallible,
+    {
+        let step = IntoBackgroundGivenStep::into_step((description, Asynchronous(callback)), StepLabel::Given);
+        self.given.push(step);
+
+        BackgroundBuilder {
+            description: self.description,
+            ignored: self.ignored,
+
+            given: self.given,
+
+            __phantom: ::core::default::Default::default(),
+        }
+    }
+
+    #[cfg(feature = "async")]
+    pub fn given_with_async<Description, Argument, Callback, Output>(
+        mut self,
+        (description, argument): (Description, Argument),
+        callback: Callback,
+    ) -> BackgroundBuilder<World, self::background::SetGiven<State>>
+    where
+        Description: Into<aliases::string::String>,
+        Argument: IntoStepArgument,
+        Callback: AsyncFn(&mut World, &Argument) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+        Output: IntoFallible,
+    {
+        let step =
+            IntoBackgroundGivenStep::into_step((description, argument, Asynchronous(callback)), StepLabel::Given);
+        self.given.push(step);
+
+        BackgroundBuilder {
+            description: self.description,
+            ignored: self.ignored,
+
+            given: self.given,
+
+            __phantom: ::core::default::Default::default(),
+        }
+    }
 }
 
 impl<World, InnerState: self::background::BuilderState> BackgroundBuilder<World, self::background::SetGiven<InnerState>>
@@ -1763,6 +3213,79 @@ where
         }
     }
 
+    pub fn and_with<Description, Argument, Callback, Output>(
+        mut self,
+        (description, argument): (Description, Argument),
+        callback: Callback,
+    ) -> BackgroundBuilder<World, self::background::SetGiven<self::background::SetGiven<InnerState>>>
+    where
+        Description: Into<aliases::string::String>,
+        Argument: IntoStepArgument,
+        Callback: Fn(&mut World, &Argument) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+        Output: IntoFallible,
+    {
+        let step = IntoBackgroundGivenStep::into_step((description, argument, callback), StepLabel::And);
+        self.given.push(step);
+
+        BackgroundBuilder {
+            description: self.description,
+            ignored: self.ignored,
+
+            given: self.given,
+
+            __phantom: ::core::default::Default::default(),
+        }
+    }
+
+    #[cfg(feature = "async")]
+    pub fn and_async<Description, Callback, Output>(
+        mut self,
+        description: Description,
+        callback: Callback,
+    ) -> BackgroundBuilder<World, self::background::SetGiven<self::background::SetGiven<InnerState>>>
+    where
+        Description: Into<aliases::string::String>,
+        Callback: AsyncFn(&mut World) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+        Output: IntoFallible,
+    {
+        let step = IntoBackgroundGivenStep::into_step((description, Asynchronous(callback)), StepLabel::And);
+        self.given.push(step);
+
+        BackgroundBuilder {
+            description: self.description,
+            ignored: self.ignored,
+
+            given: self.given,
+
+            __phantom: ::core::default::Default::default(),
+        }
+    }
+
+    #[cfg(feature = "async")]
+    pub fn and_with_async<Description, Argument, Callback, Output>(
+        mut self,
+        (description, argument): (Description, Argument),
+        callback: Callback,
+    ) -> BackgroundBuilder<World, self::background::SetGiven<self::background::SetGiven<InnerState>>>
+    where
+        Description: Into<aliases::string::String>,
+        Argument: IntoStepArgument,
+        Callback: AsyncFn(&mut World, &Argument) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+        Output: IntoFallible,
+    {
+        let step = IntoBackgroundGivenStep::into_step((description, argument, Asynchronous(callback)), StepLabel::And);
+        self.given.push(step);
+
+        BackgroundBuilder {
+            description: self.description,
+            ignored: self.ignored,
+
+            given: self.given,
+
+            __phantom: ::core::default::Default::default(),
+        }
+    }
+
     pub fn but<Description, Callback, Output>(
         mut self,
         description: Description,
@@ -1785,6 +3308,79 @@ where
             __phantom: ::core::default::Default::default(),
         }
     }
+
+    pub fn but_with<Description, Argument, Callback, Output>(
+        mut self,
+        (description, argument): (Description, Argument),
+        callback: Callback,
+    ) -> BackgroundBuilder<World, self::background::SetGiven<self::background::SetGiven<InnerState>>>
+    where
+        Description: Into<aliases::string::String>,
+        Argument: IntoStepArgument,
+        Callback: Fn(&mut World, &Argument) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+        Output: IntoFallible,
+    {
+        let step = IntoBackgroundGivenStep::into_step((description, argument, callback), StepLabel::But);
+        self.given.push(step);
+
+        BackgroundBuilder {
+            description: self.description,
+            ignored: self.ignored,
+
+            given: self.given,
+
+            __phantom: ::core::default::Default::default(),
+        }
+    }
+
+    #[cfg(feature = "async")]
+    pub fn but_async<Description, Callback, Output>(
+        mut self,
+        description: Description,
+        callback: Callback,
+    ) -> BackgroundBuilder<World, self::background::SetGiven<self::background::SetGiven<InnerState>>>
+    where
+        Description: Into<aliases::string::String>,
+        Callback: AsyncFn(&mut World) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+        Output: IntoFallible,
+    {
+        let step = IntoBackgroundGivenStep::into_step((description, Asynchronous(callback)), StepLabel::But);
+        self.given.push(step);
+
+        BackgroundBuilder {
+            description: self.description,
+            ignored: self.ignored,
+
+            given: self.given,
+
+            __phantom: ::core::default::Default::default(),
+        }
+    }
+
+    #[cfg(feature = "async")]
+    pub fn but_with_async<Description, Argument, Callback, Output>(
+        mut self,
+        (description, argument): (Description, Argument),
+        callback: Callback,
+    ) -> BackgroundBuilder<World, self::background::SetGiven<self::background::SetGiven<InnerState>>>
+    where
+        Description: Into<aliases::string::String>,
+        Argument: IntoStepArgument,
+        Callback: AsyncFn(&mut World, &Argument) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+        Output: IntoFallible,
+    {
+        let step = IntoBackgroundGivenStep::into_step((description, argument, Asynchronous(callback)), StepLabel::But);
+        self.given.push(step);
+
+        BackgroundBuilder {
+            description: self.description,
+            ignored: self.ignored,
+
+            given: self.given,
+
+            __phantom: ::core::default::Default::default(),
+        }
+    }
 }
 
 impl<World, State: self::background::BuilderState> BackgroundBuilder<World, State>
@@ -1886,7 +3482,7 @@ where
 }
 
 pub struct HookBuilder<Callback, State: self::hook::BuilderState = self::hook::Empty> {
-    tags: ::core::option::Option<Tags>,
+    tags: ::core::option::Option<TagsCondition>,
     callback: ::core::option::Option<Callback>,
 
     __phantom: aliases::marker::PhantomCovariant<State>,
@@ -1904,11 +3500,11 @@ impl<Callback> Hook<Callback> {
 }
 
 impl<Callback, State: self::hook::BuilderState> HookBuilder<Callback, State> {
-    pub(crate) fn tags(mut self, tags: impl IntoTags) -> HookBuilder<Callback, self::hook::SetTags<State>>
+    pub(crate) fn tags(mut self, filter: impl IntoTagsFilter) -> HookBuilder<Callback, self::hook::SetTags<State>>
     where
         State::Tags: self::marker::IsUnset,
     {
-        self.tags = ::core::option::Option::from(tags.into_tags());
+        self.tags = ::core::option::Option::from(aliases::sync::Arc::new(filter.into_filter()) as TagsCondition);
 
         HookBuilder {
             tags: self.tags,
@@ -1986,85 +3582,256 @@ mod hook {
         type Callback = self::marker::Set<self::members::Callback>;
     }
 
-    mod members {
-        pub struct Tags;
-        pub struct Callback;
+    mod members {
+        pub struct Tags;
+        pub struct Callback;
+    }
+}
+
+#[sealed]
+pub trait IntoScenarioOrStepHook<World> {
+    #[allow(private_interfaces)]
+    fn into_hook(self) -> ScenarioOrStepHook<World>;
+}
+
+#[sealed]
+impl<World> IntoScenarioOrStepHook<World> for ScenarioOrStepHook<World> {
+    #[allow(private_interfaces)]
+    fn into_hook(self) -> ScenarioOrStepHook<World> {
+        self
+    }
+}
+
+#[sealed]
+impl<World, Callback, Output> IntoScenarioOrStepHook<World> for Callback
+where
+    Callback: Fn(&mut World) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+    Output: IntoFallible,
+{
+    #[allow(private_interfaces)]
+    fn into_hook(self) -> ScenarioOrStepHook<World> {
+        let callback = aliases::sync::Arc::new(move |world: &mut World| (self)(world).into_fallible())
+            as aliases::sync::Arc<dyn Fn(&mut World) -> Fallible + ::core::marker::Send + ::core::marker::Sync>;
+
+        Hook::builder().callback(callback).build()
+    }
+}
+
+#[cfg(feature = "async")]
+#[sealed]
+impl<World, Callback, Output> IntoScenarioOrStepHook<World> for Asynchronous<Callback>
+where
+    Callback: AsyncFn(&mut World) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+    Output: IntoFallible,
+{
+    #[allow(private_interfaces)]
+    fn into_hook(self) -> ScenarioOrStepHook<World> {
+        let Asynchronous(callback) = self;
+
+        let callback = aliases::sync::Arc::new(move |world: &mut World| {
+            crate::executors::block_on(async { (callback)(world).await.into_fallible() })
+        })
+            as aliases::sync::Arc<dyn Fn(&mut World) -> Fallible + ::core::marker::Send + ::core::marker::Sync>;
+
+        Hook::builder().callback(callback).build()
+    }
+}
+
+/// Makes a hook conditional on the tags of the scenario it would run for, as in
+/// `hook.tags(["db"])` or `hook.tags("@db and not @readonly")`. Step hooks are
+/// selected by the tags of the scenario of the step.
+#[sealed]
+pub trait ScenarioOrStepHookCallbackExt<World> {
+    fn tags(self, filter: impl IntoTagsFilter) -> impl IntoScenarioOrStepHook<World>;
+}
+
+#[sealed]
+impl<World, Callback, Output> ScenarioOrStepHookCallbackExt<World> for Callback
+where
+    Callback: Fn(&mut World) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+    Output: IntoFallible,
+{
+    fn tags(self, filter: impl IntoTagsFilter) -> impl IntoScenarioOrStepHook<World> {
+        let callback = aliases::sync::Arc::new(move |world: &mut World| (self)(world).into_fallible())
+            as aliases::sync::Arc<dyn Fn(&mut World) -> Fallible + ::core::marker::Send + ::core::marker::Sync>;
+
+        Hook::builder().tags(filter).callback(callback).build()
+    }
+}
+
+/// The counterpart of [`ScenarioOrStepHookCallbackExt`] for after-hooks that
+/// take the outcome of the scenario or step they follow.
+#[sealed]
+pub trait AfterScenarioOrStepHookCallbackExt<World> {
+    fn tags(self, filter: impl IntoTagsFilter) -> impl IntoAfterScenarioOrStepHook<World, (Fallible,)>;
+}
+
+#[sealed]
+impl<World, Callback, Output> AfterScenarioOrStepHookCallbackExt<World> for Callback
+where
+    Callback: Fn(&mut World, &Fallible) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+    Output: IntoFallible,
+{
+    fn tags(self, filter: impl IntoTagsFilter) -> impl IntoAfterScenarioOrStepHook<World, (Fallible,)> {
+        let Hook {
+            tags: _,
+            callback,
+        } = self.into_after_hook();
+
+        Hook::builder().tags(filter).callback(callback).build()
     }
 }
 
+/// After-hooks either ignore the outcome of the scenario or step they follow,
+/// like any other hook, or take it as a second argument.
 #[sealed]
-pub trait IntoScenarioOrStepHook<World> {
+pub trait IntoAfterScenarioOrStepHook<World, Arguments> {
     #[allow(private_interfaces)]
-    fn into_hook(self) -> ScenarioOrStepHook<World>;
+    fn into_after_hook(self) -> AfterScenarioOrStepHook<World>;
 }
 
 #[sealed]
-impl<World> IntoScenarioOrStepHook<World> for ScenarioOrStepHook<World> {
+impl<World, Callback> IntoAfterScenarioOrStepHook<World, ()> for Callback
+where
+    Callback: IntoScenarioOrStepHook<World>,
+    World: 'static,
+{
     #[allow(private_interfaces)]
-    fn into_hook(self) -> ScenarioOrStepHook<World> {
+    fn into_after_hook(self) -> AfterScenarioOrStepHook<World> {
+        let Hook {
+            tags,
+            callback,
+        } = self.into_hook();
+
+        let callback = aliases::sync::Arc::new(move |world: &mut World, _: &Fallible| (callback)(world))
+            as aliases::sync::Arc<
+                dyn Fn(&mut World, &Fallible) -> Fallible + ::core::marker::Send + ::core::marker::Sync,
+            >;
+
+        Hook {
+            tags,
+            callback,
+        }
+    }
+}
+
+#[sealed]
+impl<World> IntoAfterScenarioOrStepHook<World, (Fallible,)> for AfterScenarioOrStepHook<World> {
+    #[allow(private_interfaces)]
+    fn into_after_hook(self) -> AfterScenarioOrStepHook<World> {
         self
     }
 }
 
 #[sealed]
-impl<World, Callback, Output> IntoScenarioOrStepHook<World> for Callback
+impl<World, Callback, Output> IntoAfterScenarioOrStepHook<World, (Fallible,)> for Callback
 where
-    Callback: Fn(&mut World) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+    Callback: Fn(&mut World, &Fallible) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
     Output: IntoFallible,
 {
     #[allow(private_interfaces)]
-    fn into_hook(self) -> ScenarioOrStepHook<World> {
-        let callback = aliases::sync::Arc::new(move |world: &mut World| (self)(world).into_fallible())
-            as aliases::sync::Arc<dyn Fn(&mut World) -> Fallible + ::core::marker::Send + ::core::marker::Sync>;
+    fn into_after_hook(self) -> AfterScenarioOrStepHook<World> {
+        let callback = aliases::sync::Arc::new(move |world: &mut World, outcome: &Fallible| {
+            (self)(world, outcome).into_fallible()
+        })
+            as aliases::sync::Arc<
+                dyn Fn(&mut World, &Fallible) -> Fallible + ::core::marker::Send + ::core::marker::Sync,
+            >;
 
         Hook::builder().callback(callback).build()
     }
 }
 
+/// `before_all` hooks may return a fixture, which is handed by reference to
+/// the world factory of every scenario and by value to `after_all` hooks.
 #[sealed]
-pub trait ScenarioOrStepHookCallbackExt<World> {
-    fn tags(self, tags: impl IntoTags) -> impl IntoScenarioOrStepHook<World>;
+pub trait IntoGlobalHook<Fixture = ()> {
+    #[allow(private_interfaces)]
+    fn into_hook(self) -> GlobalHook;
 }
 
 #[sealed]
-impl<World, Callback, Output> ScenarioOrStepHookCallbackExt<World> for Callback
+impl IntoGlobalHook for GlobalHook {
+    #[allow(private_interfaces)]
+    fn into_hook(self) -> GlobalHook {
+        self
+    }
+}
+
+#[sealed]
+impl<Callback, Output, Fixture> IntoGlobalHook<Fixture> for Callback
 where
-    Callback: Fn(&mut World) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
-    Output: IntoFallible,
+    Callback: FnOnce() -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+    Output: IntoFallible<Fixture>,
+    Fixture: ::core::marker::Send + ::core::marker::Sync + 'static,
 {
-    fn tags(self, tags: impl IntoTags) -> impl IntoScenarioOrStepHook<World> {
-        let tags = tags.into_tags();
-        let callback = aliases::sync::Arc::new(move |world: &mut World| (self)(world).into_fallible())
-            as aliases::sync::Arc<dyn Fn(&mut World) -> Fallible + ::core::marker::Send + ::core::marker::Sync>;
+    #[allow(private_interfaces)]
+    fn into_hook(self) -> GlobalHook {
+        let callback = ::std::boxed::Box::new(move |fixtures: &mut Fixtures| {
+            (self)().into_fallible().map(|fixture| fixtures.insert(fixture))
+        })
+            as ::std::boxed::Box<dyn FnOnce(&mut Fixtures) -> Fallible + ::core::marker::Send + ::core::marker::Sync>;
 
-        Hook::builder().tags(tags).callback(callback).build()
+        Hook::builder().callback(callback).build()
     }
 }
 
+#[cfg(feature = "async")]
 #[sealed]
-pub trait IntoGlobalHook {
+impl<Callback, Output, Fixture> IntoGlobalHook<Fixture> for Asynchronous<Callback>
+where
+    Callback: AsyncFnOnce() -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+    Output: IntoFallible<Fixture>,
+    Fixture: ::core::marker::Send + ::core::marker::Sync + 'static,
+{
     #[allow(private_interfaces)]
-    fn into_hook(self) -> GlobalHook;
+    fn into_hook(self) -> GlobalHook {
+        let Asynchronous(callback) = self;
+
+        let callback = ::std::boxed::Box::new(move |fixtures: &mut Fixtures| {
+            crate::executors::block_on(async move {
+                (callback)().await.into_fallible().map(|fixture| fixtures.insert(fixture))
+            })
+        })
+            as ::std::boxed::Box<dyn FnOnce(&mut Fixtures) -> Fallible + ::core::marker::Send + ::core::marker::Sync>;
+
+        Hook::builder().callback(callback).build()
+    }
 }
 
+/// `after_all` hooks either take nothing, like `before_all` hooks, or take the
+/// fixture of their argument's type. Each fixture is given to the first hook
+/// that asks for it.
 #[sealed]
-impl IntoGlobalHook for GlobalHook {
+pub trait IntoAfterGlobalHook<Arguments> {
     #[allow(private_interfaces)]
-    fn into_hook(self) -> GlobalHook {
-        self
+    fn into_after_hook(self) -> GlobalHook;
+}
+
+#[sealed]
+impl<Callback> IntoAfterGlobalHook<()> for Callback
+where
+    Callback: IntoGlobalHook,
+{
+    #[allow(private_interfaces)]
+    fn into_after_hook(self) -> GlobalHook {
+        self.into_hook()
     }
 }
 
 #[sealed]
-impl<Callback, Output> IntoGlobalHook for Callback
+impl<Callback, Output, Fixture> IntoAfterGlobalHook<(Fixture,)> for Callback
 where
-    Callback: FnOnce() -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+    Callback: FnOnce(Fixture) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
     Output: IntoFallible,
+    Fixture: 'static,
 {
     #[allow(private_interfaces)]
-    fn into_hook(self) -> GlobalHook {
-        let callback = ::std::boxed::Box::new(move || (self)().into_fallible())
-            as ::std::boxed::Box<dyn FnOnce() -> Fallible + ::core::marker::Send + ::core::marker::Sync>;
+    fn into_after_hook(self) -> GlobalHook {
+        let callback = ::std::boxed::Box::new(move |fixtures: &mut Fixtures| {
+            fixtures.take().and_then(|fixture| (self)(fixture).into_fallible())
+        })
+            as ::std::boxed::Box<dyn FnOnce(&mut Fixtures) -> Fallible + ::core::marker::Send + ::core::marker::Sync>;
 
         Hook::builder().callback(callback).build()
     }
@@ -2072,7 +3839,7 @@ where
 
 #[sealed]
 pub trait GlobalHookCallbackExt {
-    fn tags(self, tags: impl IntoTags) -> impl IntoGlobalHook;
+    fn tags(self, filter: impl IntoTagsFilter) -> impl IntoGlobalHook;
 }
 
 #[sealed]
@@ -2081,18 +3848,54 @@ where
     Callback: FnOnce() -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
     Output: IntoFallible,
 {
-    fn tags(self, tags: impl IntoTags) -> impl IntoGlobalHook {
-        let tags = tags.into_tags();
-        let callback = ::std::boxed::Box::new(move || (self)().into_fallible())
-            as ::std::boxed::Box<dyn FnOnce() -> Fallible + ::core::marker::Send + ::core::marker::Sync>;
+    fn tags(self, filter: impl IntoTagsFilter) -> impl IntoGlobalHook {
+        let callback = ::std::boxed::Box::new(move |_: &mut Fixtures| (self)().into_fallible())
+            as ::std::boxed::Box<dyn FnOnce(&mut Fixtures) -> Fallible + ::core::marker::Send + ::core::marker::Sync>;
+
+        Hook::builder().tags(filter).callback(callback).build()
+    }
+}
+
+/// Builds the world of each scenario, either from nothing or from a fixture
+/// returned by a `before_all` hook. Factories may fail, in which case none of
+/// the steps of the scenario run.
+#[sealed]
+pub trait IntoWorldFactory<World, Arguments> {
+    #[allow(private_interfaces)]
+    fn into_factory(self) -> WorldFactory<World>;
+}
+
+#[sealed]
+impl<World, Callback, Output> IntoWorldFactory<World, ()> for Callback
+where
+    Callback: Fn() -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+    Output: IntoFallible<World>,
+{
+    #[allow(private_interfaces)]
+    fn into_factory(self) -> WorldFactory<World> {
+        aliases::sync::Arc::new(move |_: &Fixtures| (self)().into_fallible())
+    }
+}
 
-        Hook::builder().tags(tags).callback(callback).build()
+#[sealed]
+impl<World, Callback, Output, Fixture> IntoWorldFactory<World, (Fixture,)> for Callback
+where
+    Callback: Fn(&Fixture) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+    Output: IntoFallible<World>,
+    Fixture: 'static,
+{
+    #[allow(private_interfaces)]
+    fn into_factory(self) -> WorldFactory<World> {
+        aliases::sync::Arc::new(move |fixtures: &Fixtures| {
+            fixtures.get().and_then(|fixture| (self)(fixture).into_fallible())
+        })
     }
 }
 
 struct StepBuilder<Callback, State: self::step::BuilderState = self::step::Empty> {
     label: ::core::option::Option<StepLabel>,
     description: ::core::option::Option<aliases::string::String>,
+    argument: ::core::option::Option<crate::arguments::StepArgument>,
     callback: ::core::option::Option<Callback>,
 
     __phantom: aliases::marker::PhantomCovariant<State>,
@@ -2103,6 +3906,7 @@ impl<Callback> Step<Callback> {
         StepBuilder {
             label: ::core::default::Default::default(),
             description: ::core::default::Default::default(),
+            argument: ::core::default::Default::default(),
             callback: ::core::default::Default::default(),
 
             __phantom: ::core::default::Default::default(),
@@ -2120,6 +3924,7 @@ impl<Callback, State: self::step::BuilderState> StepBuilder<Callback, State> {
         StepBuilder {
             label: self.label,
             description: self.description,
+            argument: self.argument,
             callback: self.callback,
 
             __phantom: ::core::default::Default::default(),
@@ -2138,6 +3943,26 @@ impl<Callback, State: self::step::BuilderState> StepBuilder<Callback, State> {
         StepBuilder {
             label: self.label,
             description: self.description,
+            argument: self.argument,
+            callback: self.callback,
+
+            __phantom: ::core::default::Default::default(),
+        }
+    }
+
+    fn argument(
+        mut self,
+        argument: crate::arguments::StepArgument,
+    ) -> StepBuilder<Callback, self::step::SetArgument<State>>
+    where
+        State::Argument: self::marker::IsUnset,
+    {
+        self.argument = ::core::option::Option::from(argument);
+
+        StepBuilder {
+            label: self.label,
+            description: self.description,
+            argument: self.argument,
             callback: self.callback,
 
             __phantom: ::core::default::Default::default(),
@@ -2153,6 +3978,7 @@ impl<Callback, State: self::step::BuilderState> StepBuilder<Callback, State> {
         StepBuilder {
             label: self.label,
             description: self.description,
+            argument: self.argument,
             callback: self.callback,
 
             __phantom: ::core::default::Default::default(),
@@ -2168,6 +3994,7 @@ where
         Step {
             label: unsafe { self.label.unwrap_unchecked() },
             description: unsafe { self.description.unwrap_unchecked() },
+            argument: self.argument,
             callback: unsafe { self.callback.unwrap_unchecked() },
         }
     }
@@ -2180,6 +4007,7 @@ mod step {
     pub trait BuilderState: ::core::marker::Sized {
         type Label;
         type Description;
+        type Argument;
         type Callback;
     }
 
@@ -2202,6 +4030,7 @@ mod step {
 
     pub struct SetLabel<State: BuilderState = Empty>(aliases::marker::PhantomCovariant<State>);
     pub struct SetDescription<State: BuilderState = Empty>(aliases::marker::PhantomCovariant<State>);
+    pub struct SetArgument<State: BuilderState = Empty>(aliases::marker::PhantomCovariant<State>);
     pub struct SetCallback<State: BuilderState = Empty>(aliases::marker::PhantomCovariant<State>);
 
     #[rustfmt::skip] // `reorder_impl_items`
@@ -2209,6 +4038,7 @@ mod step {
     impl BuilderState for Empty {
         type Label = self::marker::Unset<self::members::Label>;
         type Description = self::marker::Unset<self::members::Description>;
+        type Argument = self::marker::Unset<self::members::Argument>;
         type Callback = self::marker::Unset<self::members::Callback>;
     }
 
@@ -2217,6 +4047,7 @@ mod step {
     impl<State: BuilderState> BuilderState for SetLabel<State> {
         type Label = self::marker::Set<self::members::Label>;
         type Description = State::Description;
+        type Argument = State::Argument;
         type Callback = State::Callback;
     }
 
@@ -2225,6 +4056,16 @@ mod step {
     impl<State: BuilderState> BuilderState for SetDescription<State> {
         type Label = State::Label;
         type Description = self::marker::Set<self::members::Description>;
+        type Argument = State::Argument;
+        type Callback = State::Callback;
+    }
+
+    #[rustfmt::skip] // `reorder_impl_items`
+    #[sealed]
+    impl<State: BuilderState> BuilderState for SetArgument<State> {
+        type Label = State::Label;
+        type Description = State::Description;
+        type Argument = self::marker::Set<self::members::Argument>;
         type Callback = State::Callback;
     }
 
@@ -2233,12 +4074,14 @@ mod step {
     impl<State: BuilderState> BuilderState for SetCallback<State> {
         type Label = State::Label;
         type Description = State::Description;
+        type Argument = State::Argument;
         type Callback = self::marker::Set<self::members::Callback>;
     }
 
     mod members {
         pub struct Label;
         pub struct Description;
+        pub struct Argument;
         pub struct Callback;
     }
 }
@@ -2253,20 +4096,86 @@ pub trait IntoScenarioGivenOrWhenStep<World> {
 impl<World, Description, Callback, Output> IntoScenarioGivenOrWhenStep<World> for (Description, Callback)
 where
     Description: Into<aliases::string::String>,
-    Callback: FnOnce(&mut World) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+    Callback: Fn(&mut World) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
     Output: IntoFallible,
 {
     #[allow(private_interfaces)]
     fn into_step(self, label: StepLabel) -> ScenarioGivenOrWhenStep<World> {
         let (description, callback) = self;
 
-        let callback = ::std::boxed::Box::new(move |world: &mut World| (callback)(world).into_fallible())
-            as ::std::boxed::Box<dyn FnOnce(&mut World) -> Fallible + ::core::marker::Send + ::core::marker::Sync>;
+        let callback = aliases::sync::Arc::new(move |world: &mut World| (callback)(world).into_fallible())
+            as aliases::sync::Arc<dyn Fn(&mut World) -> Fallible + ::core::marker::Send + ::core::marker::Sync>;
+
+        Step::builder().label(label).description(description).callback(callback).build()
+    }
+}
+
+#[cfg(feature = "async")]
+#[sealed]
+impl<World, Description, Callback, Output> IntoScenarioGivenOrWhenStep<World> for (Description, Asynchronous<Callback>)
+where
+    Description: Into<aliases::string::String>,
+    Callback: AsyncFn(&mut World) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+    Output: IntoFallible,
+{
+    #[allow(private_interfaces)]
+    fn into_step(self, label: StepLabel) -> ScenarioGivenOrWhenStep<World> {
+        let (description, Asynchronous(callback)) = self;
+
+        let callback = aliases::sync::Arc::new(move |world: &mut World| {
+            crate::executors::block_on(async { (callback)(world).await.into_fallible() })
+        })
+            as aliases::sync::Arc<dyn Fn(&mut World) -> Fallible + ::core::marker::Send + ::core::marker::Sync>;
 
         Step::builder().label(label).description(description).callback(callback).build()
     }
 }
 
+#[sealed]
+impl<World, Description, Argument, Callback, Output> IntoScenarioGivenOrWhenStep<World>
+    for (Description, Argument, Callback)
+where
+    Description: Into<aliases::string::String>,
+    Argument: IntoStepArgument,
+    Callback: Fn(&mut World, &Argument) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+    Output: IntoFallible,
+{
+    #[allow(private_interfaces)]
+    fn into_step(self, label: StepLabel) -> ScenarioGivenOrWhenStep<World> {
+        let (description, argument, callback) = self;
+        let step_argument = argument.clone().into_step_argument();
+
+        let callback = aliases::sync::Arc::new(move |world: &mut World| (callback)(world, &argument).into_fallible())
+            as aliases::sync::Arc<dyn Fn(&mut World) -> Fallible + ::core::marker::Send + ::core::marker::Sync>;
+
+        Step::builder().label(label).description(description).argument(step_argument).callback(callback).build()
+    }
+}
+
+#[cfg(feature = "async")]
+#[sealed]
+impl<World, Description, Argument, Callback, Output> IntoScenarioGivenOrWhenStep<World>
+    for (Description, Argument, Asynchronous<Callback>)
+where
+    Description: Into<aliases::string::String>,
+    Argument: IntoStepArgument,
+    Callback: AsyncFn(&mut World, &Argument) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+    Output: IntoFallible,
+{
+    #[allow(private_interfaces)]
+    fn into_step(self, label: StepLabel) -> ScenarioGivenOrWhenStep<World> {
+        let (description, argument, Asynchronous(callback)) = self;
+        let step_argument = argument.clone().into_step_argument();
+
+        let callback = aliases::sync::Arc::new(move |world: &mut World| {
+            crate::executors::block_on(async { (callback)(world, &argument).await.into_fallible() })
+        })
+            as aliases::sync::Arc<dyn Fn(&mut World) -> Fallible + ::core::marker::Send + ::core::marker::Sync>;
+
+        Step::builder().label(label).description(description).argument(step_argument).callback(callback).build()
+    }
+}
+
 #[sealed]
 pub trait IntoScenarioThenStep<World> {
     #[allow(private_interfaces)]
@@ -2277,20 +4186,85 @@ pub trait IntoScenarioThenStep<World> {
 impl<World, Description, Callback, Output> IntoScenarioThenStep<World> for (Description, Callback)
 where
     Description: Into<aliases::string::String>,
-    Callback: FnOnce(&World) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+    Callback: Fn(&World) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
     Output: IntoFallible,
 {
     #[allow(private_interfaces)]
     fn into_step(self, label: StepLabel) -> ScenarioThenStep<World> {
         let (description, callback) = self;
 
-        let callback = ::std::boxed::Box::new(move |world: &World| (callback)(world).into_fallible())
-            as ::std::boxed::Box<dyn FnOnce(&World) -> Fallible + ::core::marker::Send + ::core::marker::Sync>;
+        let callback = aliases::sync::Arc::new(move |world: &World| (callback)(world).into_fallible())
+            as aliases::sync::Arc<dyn Fn(&World) -> Fallible + ::core::marker::Send + ::core::marker::Sync>;
+
+        Step::builder().label(label).description(description).callback(callback).build()
+    }
+}
+
+#[cfg(feature = "async")]
+#[sealed]
+impl<World, Description, Callback, Output> IntoScenarioThenStep<World> for (Description, Asynchronous<Callback>)
+where
+    Description: Into<aliases::string::String>,
+    Callback: AsyncFn(&World) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+    Output: IntoFallible,
+{
+    #[allow(private_interfaces)]
+    fn into_step(self, label: StepLabel) -> ScenarioThenStep<World> {
+        let (description, Asynchronous(callback)) = self;
+
+        let callback = aliases::sync::Arc::new(move |world: &World| {
+            crate::executors::block_on(async { (callback)(world).await.into_fallible() })
+        })
+            as aliases::sync::Arc<dyn Fn(&World) -> Fallible + ::core::marker::Send + ::core::marker::Sync>;
 
         Step::builder().label(label).description(description).callback(callback).build()
     }
 }
 
+#[sealed]
+impl<World, Description, Argument, Callback, Output> IntoScenarioThenStep<World> for (Description, Argument, Callback)
+where
+    Description: Into<aliases::string::String>,
+    Argument: IntoStepArgument,
+    Callback: Fn(&World, &Argument) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+    Output: IntoFallible,
+{
+    #[allow(private_interfaces)]
+    fn into_step(self, label: StepLabel) -> ScenarioThenStep<World> {
+        let (description, argument, callback) = self;
+        let step_argument = argument.clone().into_step_argument();
+
+        let callback = aliases::sync::Arc::new(move |world: &World| (callback)(world, &argument).into_fallible())
+            as aliases::sync::Arc<dyn Fn(&World) -> Fallible + ::core::marker::Send + ::core::marker::Sync>;
+
+        Step::builder().label(label).description(description).argument(step_argument).callback(callback).build()
+    }
+}
+
+#[cfg(feature = "async")]
+#[sealed]
+impl<World, Description, Argument, Callback, Output> IntoScenarioThenStep<World>
+    for (Description, Argument, Asynchronous<Callback>)
+where
+    Description: Into<aliases::string::String>,
+    Argument: IntoStepArgument,
+    Callback: AsyncFn(&World, &Argument) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+    Output: IntoFallible,
+{
+    #[allow(private_interfaces)]
+    fn into_step(self, label: StepLabel) -> ScenarioThenStep<World> {
+        let (description, argument, Asynchronous(callback)) = self;
+        let step_argument = argument.clone().into_step_argument();
+
+        let callback = aliases::sync::Arc::new(move |world: &World| {
+            crate::executors::block_on(async { (callback)(world, &argument).await.into_fallible() })
+        })
+            as aliases::sync::Arc<dyn Fn(&World) -> Fallible + ::core::marker::Send + ::core::marker::Sync>;
+
+        Step::builder().label(label).description(description).argument(step_argument).callback(callback).build()
+    }
+}
+
 #[sealed]
 pub trait IntoBackgroundGivenStep<World> {
     #[allow(private_interfaces)]
@@ -2315,6 +4289,98 @@ where
     }
 }
 
+#[cfg(feature = "async")]
+#[sealed]
+impl<World, Description, Callback, Output> IntoBackgroundGivenStep<World> for (Description, Asynchronous<Callback>)
+where
+    Description: Into<aliases::string::String>,
+    Callback: AsyncFn(&mut World) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+    Output: IntoFallible,
+{
+    #[allow(private_interfaces)]
+    fn into_step(self, label: StepLabel) -> BackgroundGivenStep<World> {
+        let (description, Asynchronous(callback)) = self;
+
+        let callback = aliases::sync::Arc::new(move |world: &mut World| {
+            crate::executors::block_on(async { (callback)(world).await.into_fallible() })
+        })
+            as aliases::sync::Arc<dyn Fn(&mut World) -> Fallible + ::core::marker::Send + ::core::marker::Sync>;
+
+        Step::builder().label(label).description(description).callback(callback).build()
+    }
+}
+
+#[sealed]
+impl<World, Description, Argument, Callback, Output> IntoBackgroundGivenStep<World>
+    for (Description, Argument, Callback)
+where
+    Description: Into<aliases::string::String>,
+    Argument: IntoStepArgument,
+    Callback: Fn(&mut World, &Argument) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+    Output: IntoFallible,
+{
+    #[allow(private_interfaces)]
+    fn into_step(self, label: StepLabel) -> BackgroundGivenStep<World> {
+        let (description, argument, callback) = self;
+        let step_argument = argument.clone().into_step_argument();
+
+        let callback = aliases::sync::Arc::new(move |world: &mut World| (callback)(world, &argument).into_fallible())
+            as aliases::sync::Arc<dyn Fn(&mut World) -> Fallible + ::core::marker::Send + ::core::marker::Sync>;
+
+        Step::builder().label(label).description(description).argument(step_argument).callback(callback).build()
+    }
+}
+
+#[cfg(feature = "async")]
+#[sealed]
+impl<World, Description, Argument, Callback, Output> IntoBackgroundGivenStep<World>
+    for (Description, Argument, Asynchronous<Callback>)
+where
+    Description: Into<aliases::string::String>,
+    Argument: IntoStepArgument,
+    Callback: AsyncFn(&mut World, &Argument) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+    Output: IntoFallible,
+{
+    #[allow(private_interfaces)]
+    fn into_step(self, label: StepLabel) -> BackgroundGivenStep<World> {
+        let (description, argument, Asynchronous(callback)) = self;
+        let step_argument = argument.clone().into_step_argument();
+
+        let callback = aliases::sync::Arc::new(move |world: &mut World| {
+            crate::executors::block_on(async { (callback)(world, &argument).await.into_fallible() })
+        })
+            as aliases::sync::Arc<dyn Fn(&mut World) -> Fallible + ::core::marker::Send + ::core::marker::Sync>;
+
+        Step::builder().label(label).description(description).argument(step_argument).callback(callback).build()
+    }
+}
+
+/// Marks a callback as asynchronous so that it is driven by the
+/// [`Executor`](crate::executors::Executor) of the runner running it.
+#[cfg(feature = "async")]
+pub(crate) struct Asynchronous<Callback>(pub(crate) Callback);
+
+/// Data tables and doc strings, which steps pass by reference to their
+/// callback and reports render under the step.
+#[sealed]
+pub trait IntoStepArgument: ::core::clone::Clone + ::core::marker::Send + ::core::marker::Sync + 'static {
+    fn into_step_argument(self) -> crate::arguments::StepArgument;
+}
+
+#[sealed]
+impl IntoStepArgument for crate::arguments::DataTable {
+    fn into_step_argument(self) -> crate::arguments::StepArgument {
+        crate::arguments::StepArgument::DataTable(self)
+    }
+}
+
+#[sealed]
+impl IntoStepArgument for crate::arguments::DocString {
+    fn into_step_argument(self) -> crate::arguments::StepArgument {
+        crate::arguments::StepArgument::DocString(self)
+    }
+}
+
 #[sealed]
 pub trait IntoTags {
     fn into_tags(self) -> Tags;
@@ -2327,7 +4393,7 @@ where
     T: Into<aliases::string::String>,
 {
     fn into_tags(self) -> Tags {
-        self.into_iter().map(Into::into).collect()
+        self.into_iter().map(Into::into).map(untagged).collect()
     }
 }
 
@@ -2352,6 +4418,7 @@ impl IntoFallible for () {
 
 #[sealed]
 pub trait IntoFailed {
+    #[track_caller]
     fn into_failed(self) -> Failed;
 }
 
@@ -2360,9 +4427,58 @@ impl<T> IntoFailed for T
 where
     T: Into<aliases::string::String>,
 {
+    #[track_caller]
     fn into_failed(self) -> Failed {
-        Failed {
-            message: self.into(),
+        Failed::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expanded(
+        outline: ::core::option::Option<bool>,
+        row: ::core::option::Option<bool>,
+    ) -> ::core::option::Option<bool> {
+        let scenario = move |()| match row {
+            Some(ignored) =>
+                Scenario::<()>::new().ignored(ignored).given("a", |_| {}).when("b", |_| {}).then("c", |_| {}).build(),
+            None => Scenario::<()>::new().given("a", |_| {}).when("b", |_| {}).then("c", |_| {}).build(),
+        };
+
+        let scenarios = match outline {
+            Some(ignored) => ScenarioOutline::new().ignored(ignored).scenario(scenario).examples([()]).build(),
+            None => ScenarioOutline::new().scenario(scenario).examples([()]).build(),
+        }
+        .into_scenarios();
+
+        scenarios.into_iter().next().and_then(|scenario| scenario.ignored)
+    }
+
+    #[test]
+    fn tags_are_kept_without_their_at_sign() {
+        let tags = ["@db", "smoke", "@@twice"].into_tags();
+
+        ::core::assert_eq!(tags, ["db", "smoke", "@twice"].map(aliases::string::String::from).into_iter().collect());
+    }
+
+    #[test]
+    fn rows_are_ignored_if_either_they_or_their_outline_are() {
+        let cases = [
+            ((None, None), None),
+            ((None, Some(true)), Some(true)),
+            ((None, Some(false)), Some(false)),
+            ((Some(false), None), Some(false)),
+            ((Some(false), Some(true)), Some(true)),
+            ((Some(false), Some(false)), Some(false)),
+            ((Some(true), None), Some(true)),
+            ((Some(true), Some(false)), Some(true)),
+            ((Some(true), Some(true)), Some(true)),
+        ];
+
+        for ((outline, row), expected) in cases {
+            ::core::assert_eq!(expanded(outline, row), expected, "outline {:?}, row {:?}", outline, row);
         }
     }
 }
@@ -13,11 +13,15 @@ pub struct Runner {
 }
 
 pub struct Suite<World> {
+    pub(crate) world: WorldFactory<World>,
+
     pub(crate) before_scenario_hooks: ::std::vec::Vec<ScenarioOrStepHook<World>>,
-    pub(crate) after_scenario_hooks: ::std::vec::Vec<ScenarioOrStepHook<World>>,
+    pub(crate) after_scenario_hooks: ::std::vec::Vec<AfterScenarioOrStepHook<World>>,
 
     pub(crate) before_step_hooks: ::std::vec::Vec<ScenarioOrStepHook<World>>,
-    pub(crate) after_step_hooks: ::std::vec::Vec<ScenarioOrStepHook<World>>,
+    pub(crate) after_step_hooks: ::std::vec::Vec<AfterScenarioOrStepHook<World>>,
+
+    pub(crate) default_timeout: ::core::option::Option<::core::time::Duration>,
 
     pub(crate) features: ::std::vec::Vec<Feature<World>>,
 }
@@ -30,11 +34,31 @@ pub mod configurations {
         pub(crate) ignore_policy: IgnorePolicy,
         pub(crate) tags_filter: ::core::option::Option<TagsFilter>,
 
-        /* Used by `::libtest_mimic::Arguments` */
+        pub(crate) global_hook_failure_policy: GlobalHookFailurePolicy,
+
+        /// Of scenarios that set none, and whose suite sets none either.
+        pub(crate) timeout: ::core::option::Option<::core::time::Duration>,
+
+        /// How many times scenarios may be retried over the whole run, if
+        /// capped.
+        pub(crate) retry_budget: ::core::option::Option<usize>,
+
+        /// Failures after which scenarios that have yet to start are skipped,
+        /// if the run fails fast.
+        pub(crate) max_failures: ::core::option::Option<usize>,
+
+        /* Passed on to the engine */
         pub(crate) format: Format,
         pub(crate) color: Color,
         pub(crate) threads: ::core::option::Option<ThreadsCount>,
         pub(crate) logfile: ::core::option::Option<aliases::path::Path>,
+
+        pub(crate) reports: ::std::vec::Vec<(ReportFormat, aliases::path::Path)>,
+
+        #[cfg(feature = "async")]
+        pub(crate) executor: ::core::option::Option<aliases::sync::Arc<dyn crate::executors::Executor>>,
+
+        pub(crate) engine: ::core::option::Option<::std::boxed::Box<dyn crate::engines::Engine>>,
     }
 
     #[derive(::core::default::Default, ::core::clone::Clone, ::core::marker::Copy)]
@@ -47,9 +71,28 @@ pub mod configurations {
         None,
     }
 
-    pub(crate) type TagsFilter = ::std::boxed::Box<dyn Fn(&Tags) -> bool>;
+    pub(crate) type TagsFilter = ::std::boxed::Box<dyn Fn(&Tags) -> bool + ::core::marker::Send + ::core::marker::Sync>;
 
-    #[derive(::core::default::Default)]
+    /// What happens to the run when a `before_all` or `after_all` hook fails.
+    /// The failure is printed to stderr whatever the policy.
+    #[derive(::core::default::Default, ::core::clone::Clone, ::core::marker::Copy)]
+    pub enum GlobalHookFailurePolicy {
+        /// Exits with code 2 without running any trial if a `before_all` hook
+        /// fails, or with code 2 once trials have run if an `after_all` hook
+        /// fails. `after_all` hooks still run either way.
+        #[default]
+        Abort,
+
+        /// Fails every trial with the message of the `before_all` hook instead
+        /// of running it. Since trials have already run by the time `after_all`
+        /// hooks do, a failing `after_all` hook fails the run as a whole.
+        FailTrials,
+
+        /// Runs trials as if the hook had passed.
+        Warn,
+    }
+
+    #[derive(::core::default::Default, ::core::clone::Clone, ::core::marker::Copy)]
     pub enum Format {
         #[default]
         Pretty,
@@ -57,7 +100,19 @@ pub mod configurations {
         Json,
     }
 
-    #[derive(::core::default::Default)]
+    /// Formats of reports written to files once all trials have run, alongside
+    /// the console output.
+    pub enum ReportFormat {
+        JUnit,
+
+        /// The JSON report of the Ruby and JVM implementations of Cucumber.
+        CucumberJson,
+
+        /// Newline-delimited [Cucumber Messages](https://github.com/cucumber/messages).
+        CucumberMessages,
+    }
+
+    #[derive(::core::default::Default, ::core::clone::Clone, ::core::marker::Copy)]
     pub enum Color {
         #[default]
         Auto,
@@ -65,6 +120,18 @@ pub mod configurations {
         Never,
     }
 
+    impl Color {
+        /// Whether output is colored, which it is automatically if stdout is a
+        /// terminal.
+        pub fn is_enabled(self) -> bool {
+            match self {
+                Self::Auto => ::std::io::IsTerminal::is_terminal(&::std::io::stdout()),
+                Self::Always => true,
+                Self::Never => false,
+            }
+        }
+    }
+
     pub enum ThreadsCount {
         #[cfg(feature = "num-cpus")]
         LogicalCores,
@@ -84,7 +151,8 @@ pub trait IntoTrialsWithConfigurations: 'static {
     fn into_trials_with_configurations(
         self: ::std::boxed::Box<Self>,
         configurations: &self::configurations::RunnerConfigurations,
-    ) -> ::std::vec::Vec<libtest_mimic::Trial>;
+        shared: &Shared,
+    ) -> ::std::vec::Vec<crate::engines::Trial>;
 }
 
 #[sealed]
@@ -96,9 +164,10 @@ where
     fn into_trials_with_configurations(
         mut self: ::std::boxed::Box<Self>,
         configurations: &self::configurations::RunnerConfigurations,
-    ) -> ::std::vec::Vec<libtest_mimic::Trial> {
+        shared: &Shared,
+    ) -> ::std::vec::Vec<crate::engines::Trial> {
         self.retain(configurations);
-        self.into_trials()
+        self.into_trials(shared)
     }
 }
 
@@ -119,72 +188,38 @@ where
     }
 }
 
+/// Ignored scenarios are kept as ignored trials, which libtest-mimic runs or
+/// skips according to the policy, so that reports can list them as skipped.
+/// Backgrounds have no trial of their own, and are dropped here instead.
 trait RetainByIgnorePolicy {
     fn retain(&mut self, policy: self::configurations::IgnorePolicy);
 }
 
 impl<World> RetainByIgnorePolicy for Suite<World> {
     fn retain(&mut self, policy: self::configurations::IgnorePolicy) {
-        match policy {
-            self::configurations::IgnorePolicy::RetainIgnored =>
-                self.features.retain(|features| features.ignored.as_ref().is_some_and(|ignored| *ignored)),
-            self::configurations::IgnorePolicy::RetainUnignored =>
-                self.features.retain(|features| features.ignored.as_ref().is_none_or(|ignored| !ignored)),
-            _ => {},
-        }
-
         self.features.iter_mut().for_each(|feature| RetainByIgnorePolicy::retain(feature, policy));
     }
 }
 
 impl<World> RetainByIgnorePolicy for Feature<World> {
     fn retain(&mut self, policy: self::configurations::IgnorePolicy) {
-        match policy {
-            self::configurations::IgnorePolicy::RetainIgnored => {
-                self.background = self
-                    .background
-                    .take()
-                    .filter(|background| background.ignored.as_ref().is_some_and(|ignored| *ignored));
-                self.scenarios.retain(|scenario| scenario.ignored.as_ref().is_some_and(|ignored| *ignored));
-                self.rules.retain(|rule| rule.ignored.as_ref().is_some_and(|ignored| *ignored));
-            },
-
-            self::configurations::IgnorePolicy::RetainUnignored => {
-                self.background = self
-                    .background
-                    .take()
-                    .filter(|background| background.ignored.as_ref().is_none_or(|ignored| !ignored));
-                self.scenarios.retain(|scenario| scenario.ignored.as_ref().is_none_or(|ignored| !ignored));
-                self.rules.retain(|rule| rule.ignored.as_ref().is_none_or(|ignored| !ignored));
-            },
-
-            _ => {},
-        }
-
+        self.background = self.background.take().filter(|background| background.is_retained_by(policy));
         self.rules.iter_mut().for_each(|rule| RetainByIgnorePolicy::retain(rule, policy));
     }
 }
 
 impl<World> RetainByIgnorePolicy for Rule<World> {
     fn retain(&mut self, policy: self::configurations::IgnorePolicy) {
-        match policy {
-            self::configurations::IgnorePolicy::RetainIgnored => {
-                self.background = self
-                    .background
-                    .take()
-                    .filter(|background| background.ignored.as_ref().is_some_and(|ignored| *ignored));
-                self.scenarios.retain(|scenario| scenario.ignored.as_ref().is_some_and(|ignored| *ignored));
-            },
-
-            self::configurations::IgnorePolicy::RetainUnignored => {
-                self.background = self
-                    .background
-                    .take()
-                    .filter(|background| background.ignored.as_ref().is_none_or(|ignored| !ignored));
-                self.scenarios.retain(|scenario| scenario.ignored.as_ref().is_none_or(|ignored| !ignored));
-            },
+        self.background = self.background.take().filter(|background| background.is_retained_by(policy));
+    }
+}
 
-            _ => {},
+impl<World> Background<World> {
+    fn is_retained_by(&self, policy: self::configurations::IgnorePolicy) -> bool {
+        match policy {
+            self::configurations::IgnorePolicy::RetainIgnored => self.ignored.as_ref().is_some_and(|ignored| *ignored),
+            self::configurations::IgnorePolicy::RetainUnignored => self.ignored.as_ref().is_none_or(|ignored| !ignored),
+            self::configurations::IgnorePolicy::None => true,
         }
     }
 }
@@ -200,15 +235,8 @@ impl<World> RetainByTagsFilter for Suite<World> {
     where
         Callback: Fn(&Tags) -> bool,
     {
-        self.features.retain(|feature| feature.tags.as_ref().is_some_and(&*filter));
-
         self.features.iter_mut().for_each(|feature| RetainByTagsFilter::retain(feature, filter.clone()));
-
-        self.before_scenario_hooks.retain(|hook| hook.tags.as_ref().is_some_and(&*filter));
-        self.after_scenario_hooks.retain(|hook| hook.tags.as_ref().is_some_and(&*filter));
-
-        self.before_step_hooks.retain(|hook| hook.tags.as_ref().is_some_and(&*filter));
-        self.after_step_hooks.retain(|hook| hook.tags.as_ref().is_some_and(&*filter));
+        self.features.retain(|feature| !feature.scenarios.is_empty() || !feature.rules.is_empty());
     }
 }
 
@@ -217,10 +245,10 @@ impl<World> RetainByTagsFilter for Feature<World> {
     where
         Callback: Fn(&Tags) -> bool,
     {
-        self.scenarios.retain(|scenario| scenario.tags.as_ref().is_some_and(&*filter));
-        self.rules.retain(|rule| rule.tags.as_ref().is_some_and(&*filter));
+        self.scenarios.retain(|scenario| is_matched_by(scenario.tags.as_ref(), &*filter));
 
         self.rules.iter_mut().for_each(|rule| RetainByTagsFilter::retain(rule, filter.clone()));
+        self.rules.retain(|rule| !rule.scenarios.is_empty());
     }
 }
 
@@ -229,47 +257,73 @@ impl<World> RetainByTagsFilter for Rule<World> {
     where
         Callback: Fn(&Tags) -> bool,
     {
-        self.scenarios.retain(|scenario| scenario.tags.as_ref().is_some_and(&*filter));
+        self.scenarios.retain(|scenario| is_matched_by(scenario.tags.as_ref(), &*filter));
+    }
+}
+
+/// Scenarios carry the tags of their feature, rule and examples alongside
+/// their own, so features and rules are kept as long as one of their scenarios
+/// is, whatever their own tags. Untagged scenarios are matched as having no
+/// tags rather than never matched, so that `not @slow` keeps them.
+fn is_matched_by(tags: ::core::option::Option<&Tags>, filter: impl Fn(&Tags) -> bool) -> bool {
+    match tags {
+        Some(tags) => filter(tags),
+        None => filter(&::core::default::Default::default()),
     }
 }
 trait IntoTrials {
-    fn into_trials(self) -> ::std::vec::Vec<::libtest_mimic::Trial>;
+    fn into_trials(self, shared: &Shared) -> ::std::vec::Vec<crate::engines::Trial>;
 }
 
 impl<World> IntoTrials for Suite<World>
 where
-    World: ::core::default::Default + 'static,
+    World: 'static,
 {
-    fn into_trials(self) -> ::std::vec::Vec<::libtest_mimic::Trial> {
+    fn into_trials(mut self, shared: &Shared) -> ::std::vec::Vec<crate::engines::Trial> {
+        let world = self.world;
+
+        let scenarios = self.features.iter_mut().flat_map(|feature| {
+            feature.scenarios.iter_mut().chain(feature.rules.iter_mut().flat_map(|rule| rule.scenarios.iter_mut()))
+        });
+
+        scenarios.for_each(|scenario| scenario.timeout = scenario.timeout.or(self.default_timeout));
+
         self.features
             .into_iter()
-            .zip(::core::iter::repeat([
-                self.before_scenario_hooks.clone(),
-                self.after_scenario_hooks.clone(),
-                self.before_step_hooks.clone(),
-                self.after_step_hooks.clone(),
-            ]))
+            .zip(::core::iter::repeat((world, [
+                (self.before_scenario_hooks.clone(), self.after_scenario_hooks.clone()),
+                (self.before_step_hooks.clone(), self.after_step_hooks.clone()),
+            ])))
             .flat_map(|(feature, hooks)| {
+                let lineage = Lineage::new(shared, &feature);
+
                 ::core::iter::Iterator::chain(
                     feature
                         .scenarios
                         .into_iter()
-                        .zip(::core::iter::repeat((hooks.clone(), [feature
-                            .background
-                            .as_ref()
-                            .map(|background| background.given.clone())])))
+                        .zip(::core::iter::repeat((
+                            hooks.clone(),
+                            [feature.background.as_ref().map(|background| background.given.clone())],
+                            lineage.clone(),
+                        )))
                         .map(|(scenario, context)| scenario.into_trial_with_context(context)),
                     feature
                         .rules
                         .into_iter()
-                        .map(|rule| (rule.scenarios, rule.background))
-                        .map(move |(rule_scenarios, rule_background)| {
+                        .enumerate()
+                        .map(move |(index, rule)| {
+                            let lineage = lineage.rule(index, &rule);
+
                             (
-                                rule_scenarios,
-                                (hooks.clone(), [
-                                    feature.background.as_ref().map(|background| background.given.clone()),
-                                    rule_background.as_ref().map(|background| background.given.clone()),
-                                ]),
+                                rule.scenarios,
+                                (
+                                    hooks.clone(),
+                                    [
+                                        feature.background.as_ref().map(|background| background.given.clone()),
+                                        rule.background.as_ref().map(|background| background.given.clone()),
+                                    ],
+                                    lineage,
+                                ),
                             )
                         })
                         .flat_map(|(rule_scenarios, context)| {
@@ -288,24 +342,36 @@ impl<World> IntoTrials for Feature<World>
 where
     World: ::core::default::Default + 'static,
 {
-    fn into_trials(self) -> ::std::vec::Vec<::libtest_mimic::Trial> {
+    fn into_trials(self, shared: &Shared) -> ::std::vec::Vec<crate::engines::Trial> {
         let feature = self;
+        let lineage = Lineage::new(shared, &feature);
 
         ::core::iter::Iterator::chain(
             feature
                 .scenarios
                 .into_iter()
-                .zip(::core::iter::repeat([feature.background.as_ref().map(|background| background.given.clone())]))
+                .zip(::core::iter::repeat((
+                    [feature.background.as_ref().map(|background| background.given.clone())],
+                    lineage.clone(),
+                )))
                 .map(|(scenario, context)| scenario.into_trial_with_context(context)),
             feature
                 .rules
                 .into_iter()
-                .map(|rule| (rule.scenarios, rule.background))
-                .map(move |(rule_scenarios, rule_background)| {
-                    (rule_scenarios, [
-                        feature.background.as_ref().map(|background| background.given.clone()),
-                        rule_background.as_ref().map(|background| background.given.clone()),
-                    ])
+                .enumerate()
+                .map(move |(index, rule)| {
+                    let lineage = lineage.rule(index, &rule);
+
+                    (
+                        rule.scenarios,
+                        (
+                            [
+                                feature.background.as_ref().map(|background| background.given.clone()),
+                                rule.background.as_ref().map(|background| background.given.clone()),
+                            ],
+                            lineage,
+                        ),
+                    )
                 })
                 .flat_map(|(rule_scenarios, context)| {
                     rule_scenarios
@@ -318,98 +384,436 @@ where
     }
 }
 
+/// What trials share with the runner.
+#[derive(::core::default::Default, ::core::clone::Clone)]
+pub(crate) struct Shared {
+    collector: aliases::sync::Arc<crate::reporters::Collector>,
+
+    /// Filled in by `before_all` hooks after trials are built, but before any
+    /// of them runs.
+    fixtures: aliases::sync::Arc<::std::sync::RwLock<Fixtures>>,
+
+    /// Of scenarios whose suite sets none either.
+    timeout: ::core::option::Option<::core::time::Duration>,
+
+    /// Retries left to the run, if it has a budget.
+    retries: ::core::option::Option<aliases::sync::Arc<::std::sync::atomic::AtomicUsize>>,
+
+    /// Scenarios that failed so far, counted once they are no longer retried.
+    failures: aliases::sync::Arc<::std::sync::atomic::AtomicUsize>,
+    max_failures: ::core::option::Option<usize>,
+
+    /// Of the runner, if it sets one.
+    #[cfg(feature = "async")]
+    executor: ::core::option::Option<crate::executors::SharedExecutor>,
+}
+
+impl Shared {
+    /// Takes a retry out of the budget of the run, unless it is spent.
+    fn take_retry(&self) -> bool {
+        self.retries.as_ref().is_none_or(|retries| {
+            retries
+                .fetch_update(::std::sync::atomic::Ordering::AcqRel, ::std::sync::atomic::Ordering::Acquire, |left| {
+                    left.checked_sub(1)
+                })
+                .is_ok()
+        })
+    }
+
+    /// Whether the run failed fast, after which no scenario starts.
+    fn stopped(&self) -> bool {
+        self.max_failures.is_some_and(|max| self.failures.load(::std::sync::atomic::Ordering::Acquire) >= max)
+    }
+
+    /// Runs `run` with the executor of the runner driving the asynchronous
+    /// steps and hooks it runs on this thread.
+    fn enter<T>(&self, run: impl FnOnce() -> T) -> T {
+        #[cfg(feature = "async")]
+        {
+            crate::executors::enter(self.executor.clone(), run)
+        }
+
+        #[cfg(not(feature = "async"))]
+        {
+            run()
+        }
+    }
+}
+
+/// Where a scenario sits within the run, for reports.
+#[derive(::core::clone::Clone)]
+struct Lineage {
+    shared: Shared,
+
+    feature: crate::reporters::Ancestor,
+    rule: ::core::option::Option<crate::reporters::Ancestor>,
+
+    ignored: bool,
+}
+
+impl Lineage {
+    fn new<World>(shared: &Shared, feature: &Feature<World>) -> Self {
+        Self {
+            shared: shared.clone(),
+
+            feature: shared.collector.feature(feature),
+            rule: None,
+
+            ignored: feature.ignored.is_some_and(|ignored| ignored),
+        }
+    }
+
+    fn rule<World>(&self, index: usize, rule: &Rule<World>) -> Self {
+        Self {
+            rule: Some(crate::reporters::Ancestor {
+                index,
+                description: rule.description.clone(),
+                tags: crate::reporters::sorted(rule.tags.as_ref())
+                    .into_iter()
+                    .filter(|tag| !self.feature.tags.contains(tag))
+                    .collect(),
+                narrative: rule.narrative.clone(),
+            }),
+            ignored: self.ignored || rule.ignored.is_some_and(|ignored| ignored),
+            ..self.clone()
+        }
+    }
+
+    /// `backgrounds` are those of the feature, then of the rule if any.
+    fn report<World>(
+        &self,
+        scenario: &Scenario<World>,
+        backgrounds: &[::core::option::Option<::std::vec::Vec<BackgroundGivenStep<World>>>],
+    ) -> crate::reporters::ScenarioReport {
+        let origins = [crate::reporters::StepOrigin::FeatureBackground, crate::reporters::StepOrigin::RuleBackground];
+
+        let steps = ::core::iter::Iterator::chain(
+            backgrounds.iter().zip(origins).flat_map(|(background, origin)| {
+                background
+                    .iter()
+                    .flatten()
+                    .map(move |step| (step.label, step.description.clone(), step.argument.clone(), origin))
+            }),
+            ::core::iter::Iterator::chain(
+                scenario
+                    .given
+                    .iter()
+                    .chain(&scenario.when)
+                    .map(|step| (step.label, step.description.clone(), step.argument.clone())),
+                scenario.then.iter().map(|step| (step.label, step.description.clone(), step.argument.clone())),
+            )
+            .map(|(label, description, argument)| {
+                (label, description, argument, crate::reporters::StepOrigin::Scenario)
+            }),
+        );
+
+        crate::reporters::ScenarioReport {
+            feature: self.feature.clone(),
+            rule: self.rule.clone(),
+
+            description: scenario.to_description(),
+            tags: crate::reporters::sorted(scenario.tags.as_ref()),
+            ignored: self.ignored || scenario.ignored.is_some_and(|ignored| ignored),
+
+            narrative: scenario.narrative.clone(),
+
+            steps: steps
+                .map(|(label, description, argument, origin)| crate::reporters::StepReport {
+                    label,
+                    description,
+                    argument,
+                    origin,
+
+                    outcome: crate::reporters::StepOutcome::Skipped,
+                    duration: ::core::default::Default::default(),
+                })
+                .collect(),
+            hooks: ::std::vec::Vec::new(),
+
+            outcome: None,
+            started: None,
+            duration: ::core::default::Default::default(),
+
+            attempts: ::std::vec::Vec::new(),
+        }
+    }
+}
+
+/// Hooks that run before and after a scenario or a step.
+type Hooks<World> = (::std::vec::Vec<ScenarioOrStepHook<World>>, ::std::vec::Vec<AfterScenarioOrStepHook<World>>);
+
+/// Keeps the hooks whose tags match those of the scenario, as with the tags
+/// filter of the runner, untagged scenarios are matched as having no tags.
+fn select<World>((mut before, mut after): Hooks<World>, tags: ::core::option::Option<&Tags>) -> Hooks<World> {
+    before.retain(|hook| hook.tags.as_ref().is_none_or(|condition| is_matched_by(tags, &**condition)));
+    after.retain(|hook| hook.tags.as_ref().is_none_or(|condition| is_matched_by(tags, &**condition)));
+
+    (before, after)
+}
+
+/// How the scenarios of a suite build their world, and the hooks that run
+/// around them and their steps.
+type Setup<World> = (WorldFactory<World>, [Hooks<World>; 2]);
+
 trait ScenarioExt<Context> {
-    fn into_trial_with_context(self, context: Context) -> ::libtest_mimic::Trial;
+    fn into_trial_with_context(self, context: Context) -> crate::engines::Trial;
 }
 
 impl<const N: usize, World>
-    ScenarioExt<(
-        [::std::vec::Vec<ScenarioOrStepHook<World>>; 4],
-        [::core::option::Option<::std::vec::Vec<BackgroundGivenStep<World>>>; N],
-    )> for Scenario<World>
+    ScenarioExt<(Setup<World>, [::core::option::Option<::std::vec::Vec<BackgroundGivenStep<World>>>; N], Lineage)>
+    for Scenario<World>
 where
-    World: ::core::default::Default + 'static,
+    World: 'static,
 {
     fn into_trial_with_context(
         self,
-        ([before_scenario_hooks, after_scenario_hooks, before_step_hooks, after_step_hooks], backgrounds): (
-            [::std::vec::Vec<ScenarioOrStepHook<World>>; 4],
+        ((world, [scenario_hooks, step_hooks]), backgrounds, lineage): (
+            Setup<World>,
             [::core::option::Option<::std::vec::Vec<BackgroundGivenStep<World>>>; N],
+            Lineage,
         ),
-    ) -> ::libtest_mimic::Trial {
-        let description = self.to_description();
+    ) -> crate::engines::Trial {
+        let report = lineage.report(&self, &backgrounds);
+        let location = self.location.clone();
+        let timeout = self.timeout;
+        let retries = self.retries.or_else(|| retries_of(self.tags.as_ref())).unwrap_or_default();
 
-        let context = [before_step_hooks.clone(), after_step_hooks.clone()];
+        let (before_scenario_hooks, after_scenario_hooks) = select(scenario_hooks, self.tags.as_ref());
+        let step_hooks = select(step_hooks, self.tags.as_ref());
 
-        let callback = move || {
-            let mut world = ::core::default::Default::default();
+        let fixtures = lineage.shared.fixtures.clone();
 
-            before_scenario_hooks.to_callback()(&mut world)?;
+        let callback = move |trace: &mut crate::reporters::Trace| {
+            let mut world =
+                trace.setup(|| (world)(&fixtures.read().unwrap_or_else(::std::sync::PoisonError::into_inner)))?;
 
-            backgrounds
-                .into_iter()
-                .flatten()
-                .try_for_each(|background| background.to_callback_with_context(context.clone())(&mut world))?;
+            let steps = |world: &mut World, trace: &mut crate::reporters::Trace| {
+                if !before_scenario_hooks.is_empty() {
+                    trace.hook(crate::reporters::HookKind::Before, || before_scenario_hooks.to_callback()(world))?;
+                }
 
-            self.given.into_callback_with_context(context.clone())(&mut world)?;
-            self.when.into_callback_with_context(context.clone())(&mut world)?;
-            self.then.into_callback_with_context(context.clone())(&mut world)?;
+                backgrounds
+                    .iter()
+                    .flatten()
+                    .try_for_each(|background| background.to_callback_with_context(step_hooks.clone())(world, trace))?;
 
-            after_scenario_hooks.to_callback()(&mut world)?;
+                self.given.to_callback_with_context(step_hooks.clone())(world, trace)?;
+                self.when.to_callback_with_context(step_hooks.clone())(world, trace)?;
+                self.then.to_callback_with_context(step_hooks.clone(), self.soft_then)(world, trace)
+            };
 
-            Ok(())
+            let result = steps(&mut world, trace);
+
+            if after_scenario_hooks.is_empty() {
+                return result;
+            }
+
+            let after = trace
+                .hook(crate::reporters::HookKind::After, || after_scenario_hooks.to_callback()(&mut world, &result));
+
+            alongside(result, after)
         };
 
-        into_trial(description, self.tags, callback)
+        into_trial(lineage, (report, location, timeout, retries), callback)
     }
 }
 
-impl<const N: usize, World> ScenarioExt<[::core::option::Option<::std::vec::Vec<BackgroundGivenStep<World>>>; N]>
-    for Scenario<World>
+impl<const N: usize, World>
+    ScenarioExt<([::core::option::Option<::std::vec::Vec<BackgroundGivenStep<World>>>; N], Lineage)> for Scenario<World>
 where
     World: ::core::default::Default + 'static,
 {
     fn into_trial_with_context(
         self,
-        backgrounds: [::core::option::Option<::std::vec::Vec<BackgroundGivenStep<World>>>; N],
-    ) -> ::libtest_mimic::Trial {
-        let description = self.to_description();
-
-        let callback = move || {
+        (backgrounds, lineage): ([::core::option::Option<::std::vec::Vec<BackgroundGivenStep<World>>>; N], Lineage),
+    ) -> crate::engines::Trial {
+        let report = lineage.report(&self, &backgrounds);
+        let location = self.location.clone();
+        let timeout = self.timeout;
+        let retries = self.retries.or_else(|| retries_of(self.tags.as_ref())).unwrap_or_default();
+
+        let callback = move |trace: &mut crate::reporters::Trace| {
             let mut world = ::core::default::Default::default();
 
-            backgrounds.into_iter().flatten().try_for_each(|background| background.to_callback()(&mut world))?;
+            backgrounds.iter().flatten().try_for_each(|background| background.to_callback()(&mut world, trace))?;
 
-            self.given.into_callback()(&mut world)?;
-            self.when.into_callback()(&mut world)?;
-            self.then.into_callback()(&mut world)?;
+            self.given.to_callback()(&mut world, trace)?;
+            self.when.to_callback()(&mut world, trace)?;
+            self.then.to_callback(self.soft_then)(&mut world, trace)?;
 
             Ok(())
         };
 
-        into_trial(description, self.tags, callback)
+        into_trial(lineage, (report, location, timeout, retries), callback)
     }
 }
 
+/// Each run of the scenario builds a world of its own, so that a retried
+/// scenario starts afresh.
 fn into_trial(
-    description: impl Into<::std::borrow::Cow<'static, str>>,
-    tags: ::core::option::Option<impl Into<Tags>>,
-    callback: impl FnOnce() -> Fallible + ::core::marker::Send + ::core::marker::Sync + 'static,
-) -> ::libtest_mimic::Trial {
-    let callback = move || (callback)().map_err(|err| err.message.into());
+    lineage: Lineage,
+    (report, location, timeout, retries): (
+        crate::reporters::ScenarioReport,
+        ::core::option::Option<Location>,
+        ::core::option::Option<::core::time::Duration>,
+        usize,
+    ),
+    callback: impl Fn(&mut crate::reporters::Trace) -> Fallible + ::core::marker::Send + ::core::marker::Sync + 'static,
+) -> crate::engines::Trial {
+    let description = report.description.clone();
+    let timeout = timeout.or(lineage.shared.timeout);
+
+    let tags = report.tags.clone();
+    let ignored = report.ignored;
+
+    let index = lineage.shared.collector.register(report);
+    let collector = lineage.shared.collector.clone();
+
+    let name = description.clone();
+    let shared = lineage.shared.clone();
+    let callback = aliases::sync::Arc::new(move |trace: &mut crate::reporters::Trace| shared.enter(|| callback(trace)));
+
+    let callback = move || {
+        if lineage.shared.stopped() {
+            lineage.shared.collector.record(index, |report| report.outcome = Some(crate::reporters::Outcome::Skipped));
+            return crate::engines::TrialOutcome::Skipped;
+        }
+
+        let mut attempts = 0;
+
+        loop {
+            let mut trace = crate::reporters::Trace::default();
+            let failed_before_all = lineage.shared.collector.failed_before_all();
+
+            let started = ::std::time::SystemTime::now();
+            let start = ::std::time::Instant::now();
+            let (result, abandoned) = match (failed_before_all, timeout) {
+                (Some(failed), _) => (trace.hook(crate::reporters::HookKind::BeforeAll, || Err(failed.clone())), None),
+                (None, Some(timeout)) => {
+                    let callback = callback.clone();
+                    watch((&name, timeout), &mut trace, move |trace| (callback)(trace))
+                },
+                (None, None) => ((callback)(&mut trace), None),
+            };
+
+            let outcome = match result {
+                Ok(()) => crate::reporters::Outcome::Passed,
+                Err(ref err) if ::core::matches!(err.kind, FailedKind::TimedOut) =>
+                    crate::reporters::Outcome::TimedOut(err.clone()),
+                Err(ref err) if trace.failed_setup() => crate::reporters::Outcome::SetupFailed(err.clone()),
+                Err(ref err) => crate::reporters::Outcome::Failed(err.clone()),
+            };
+
+            let duration = start.elapsed();
+
+            attempts += 1;
+
+            // An attempt left running past its timeout still holds its world,
+            // so the scenario is only retried once it is done.
+            let retried = failed_before_all.is_none()
+                && abandoned.as_ref().is_none_or(::std::thread::JoinHandle::is_finished)
+                && attempts <= retries
+                && is_retryable(&outcome)
+                && !lineage.shared.stopped()
+                && lineage.shared.take_retry();
+
+            if !retried && outcome.failed().is_some() {
+                lineage.shared.failures.fetch_add(1, ::std::sync::atomic::Ordering::AcqRel);
+            }
+
+            let rendered = lineage.shared.collector.record(index, |report| {
+                report.finish(trace);
+
+                report.outcome = Some(match outcome {
+                    crate::reporters::Outcome::TimedOut(failed) =>
+                        crate::reporters::Outcome::TimedOut(report.interrupted(failed)),
+                    outcome => outcome,
+                });
+                report.started = Some(started);
+                report.duration = duration;
+
+                match retried {
+                    true => {
+                        report.retry();
+                        None
+                    },
+                    false => Some(report.trace(lineage.shared.collector.colored())),
+                }
+            });
+
+            if !retried {
+                return match result {
+                    Ok(()) => crate::engines::TrialOutcome::Passed,
+                    Err(err) =>
+                        crate::engines::TrialOutcome::Failed(rendered.flatten().unwrap_or_else(|| err.to_string())),
+                };
+            }
+        }
+    };
+
+    crate::engines::Trial {
+        name: description,
+        tags,
+        ignored,
+        location,
 
-    let description = description.into();
+        report: (collector, index),
+
+        callback: ::std::boxed::Box::new(callback),
+    }
+}
 
-    let tags = tags.map(Into::into).map(|tags| tags.to_description());
+/// Whether running the scenario again may change its outcome, which it cannot
+/// if a step is undefined, pending or ambiguous.
+fn is_retryable(outcome: &crate::reporters::Outcome) -> bool {
+    outcome.failed().is_some_and(|failed| ::core::matches!(failed.kind, FailedKind::Failed | FailedKind::TimedOut))
+}
 
-    let trial = ::libtest_mimic::Trial::test(description, callback);
+/// How many times a scenario tagged `@retry(n)` is retried, if it is.
+fn retries_of(tags: ::core::option::Option<&Tags>) -> ::core::option::Option<usize> {
+    tags?.iter().find_map(|tag| tag.strip_prefix("retry(")?.strip_suffix(')')?.trim().parse().ok())
+}
 
-    let trial = match tags {
-        Some(tags) => trial.with_kind(tags),
-        None => trial,
+/// Runs the scenario on a thread of its own, so that it can be given up on once
+/// `timeout` has passed. Threads cannot be stopped, so the thread is left to
+/// finish in the background, and returned alongside the failure, but
+/// asynchronous steps and hooks are cancelled at the deadline.
+fn watch(
+    (name, timeout): (&str, ::core::time::Duration),
+    trace: &mut crate::reporters::Trace,
+    callback: impl FnOnce(&mut crate::reporters::Trace) -> Fallible + ::core::marker::Send + 'static,
+) -> (Fallible, ::core::option::Option<::std::thread::JoinHandle<()>>) {
+    let (sender, receiver) = ::std::sync::mpsc::channel();
+
+    let mut trace_of_thread = trace.clone();
+    let deadline = ::std::time::Instant::now() + timeout;
+
+    let spawned = ::std::thread::Builder::new().name(name.to_owned()).spawn(move || {
+        #[cfg(feature = "async")]
+        crate::executors::set_deadline(deadline, timeout);
+
+        let _ = sender.send(callback(&mut trace_of_thread));
+    });
+
+    let thread = match spawned {
+        Ok(thread) => thread,
+        Err(err) => {
+            let message = ::std::format!("Failed to spawn a thread to run the scenario on: {}", err);
+            return (Err(Failed::untraced(message, FailedKind::Failed)), None);
+        },
     };
 
-    trial
+    match receiver.recv_timeout(deadline.saturating_duration_since(::std::time::Instant::now())) {
+        Ok(result) => (result, None),
+        Err(::std::sync::mpsc::RecvTimeoutError::Timeout) =>
+            (Err(trace.time_out(Failed::timed_out(timeout))), Some(thread)),
+
+        // The sender is only dropped without sending if the scenario panicked.
+        Err(::std::sync::mpsc::RecvTimeoutError::Disconnected) => match thread.join() {
+            Ok(()) => ::core::unreachable!(),
+            Err(payload) => ::std::panic::resume_unwind(payload),
+        },
+    }
 }
 
 trait ToDescription {
@@ -462,102 +866,116 @@ impl ToDescription for StepLabel {
     }
 }
 
-trait ScenarioGivenOrWhenStepsExt<World> {
-    fn into_callback(self) -> impl FnOnce(&mut World) -> Fallible + ::core::marker::Send + ::core::marker::Sync;
+/// Of backgrounds and scenarios alike, as their `Given` and `When` steps share
+/// a type.
+trait GivenOrWhenStepsExt<World> {
+    fn to_callback(
+        &self,
+    ) -> impl Fn(&mut World, &mut crate::reporters::Trace) -> Fallible + ::core::marker::Send + ::core::marker::Sync;
 
-    fn into_callback_with_context(
-        self,
-        context: [::std::vec::Vec<ScenarioOrStepHook<World>>; 2],
-    ) -> impl FnOnce(&mut World) -> Fallible + ::core::marker::Send + ::core::marker::Sync;
+    fn to_callback_with_context(
+        &self,
+        context: Hooks<World>,
+    ) -> impl Fn(&mut World, &mut crate::reporters::Trace) -> Fallible + ::core::marker::Send + ::core::marker::Sync;
 }
 
-impl<World> ScenarioGivenOrWhenStepsExt<World> for ::std::vec::Vec<ScenarioGivenOrWhenStep<World>>
+impl<World> GivenOrWhenStepsExt<World> for ::std::vec::Vec<ScenarioGivenOrWhenStep<World>>
 where
     World: 'static,
 {
-    fn into_callback(self) -> impl FnOnce(&mut World) -> Fallible + ::core::marker::Send + ::core::marker::Sync {
-        move |world: &mut World| self.into_iter().try_for_each(|step| (step.callback)(world))
+    fn to_callback(
+        &self,
+    ) -> impl Fn(&mut World, &mut crate::reporters::Trace) -> Fallible + ::core::marker::Send + ::core::marker::Sync
+    {
+        move |world: &mut World, trace: &mut crate::reporters::Trace| {
+            self.iter().try_for_each(|step| trace.step(|| (step.callback)(world)))
+        }
     }
 
-    fn into_callback_with_context(
-        self,
-        [before_step_hooks, after_step_hooks]: [::std::vec::Vec<ScenarioOrStepHook<World>>; 2],
-    ) -> impl FnOnce(&mut World) -> Fallible + ::core::marker::Send + ::core::marker::Sync {
-        move |world: &mut World| {
-            self.into_iter().try_for_each(|step| {
-                (before_step_hooks.to_callback())(world)?;
-                (step.callback)(world)?;
-                (after_step_hooks.to_callback())(world)?;
-
-                Ok(())
+    fn to_callback_with_context(
+        &self,
+        (before_step_hooks, after_step_hooks): Hooks<World>,
+    ) -> impl Fn(&mut World, &mut crate::reporters::Trace) -> Fallible + ::core::marker::Send + ::core::marker::Sync
+    {
+        move |world: &mut World, trace: &mut crate::reporters::Trace| {
+            self.iter().try_for_each(|step| {
+                trace.step(|| {
+                    let result = (before_step_hooks.to_callback())(world).and_then(|()| (step.callback)(world));
+                    let after = (after_step_hooks.to_callback())(world, &result);
+
+                    alongside(result, after)
+                })
             })
         }
     }
 }
 
+/// With `soft`, every step runs even if one before it failed.
 trait ScenarioThenStepsExt<World> {
-    fn into_callback(self) -> impl FnOnce(&mut World) -> Fallible + ::core::marker::Send + ::core::marker::Sync;
+    fn to_callback(
+        &self,
+        soft: bool,
+    ) -> impl Fn(&mut World, &mut crate::reporters::Trace) -> Fallible + ::core::marker::Send + ::core::marker::Sync;
 
-    fn into_callback_with_context(
-        self,
-        context: [::std::vec::Vec<ScenarioOrStepHook<World>>; 2],
-    ) -> impl FnOnce(&mut World) -> Fallible + ::core::marker::Send + ::core::marker::Sync;
+    fn to_callback_with_context(
+        &self,
+        context: Hooks<World>,
+        soft: bool,
+    ) -> impl Fn(&mut World, &mut crate::reporters::Trace) -> Fallible + ::core::marker::Send + ::core::marker::Sync;
 }
 
 impl<World> ScenarioThenStepsExt<World> for ::std::vec::Vec<ScenarioThenStep<World>>
 where
     World: 'static,
 {
-    fn into_callback(self) -> impl FnOnce(&mut World) -> Fallible + ::core::marker::Send + ::core::marker::Sync {
-        move |world: &mut World| self.into_iter().try_for_each(|step| (step.callback)(world))
-    }
-
-    fn into_callback_with_context(
-        self,
-        [before_step_hooks, after_step_hooks]: [::std::vec::Vec<ScenarioOrStepHook<World>>; 2],
-    ) -> impl FnOnce(&mut World) -> Fallible + ::core::marker::Send + ::core::marker::Sync {
-        move |world: &mut World| {
-            self.into_iter().try_for_each(|step| {
-                (before_step_hooks.to_callback())(world)?;
-                (step.callback)(world)?;
-                (after_step_hooks.to_callback())(world)?;
-
-                Ok(())
-            })
+    fn to_callback(
+        &self,
+        soft: bool,
+    ) -> impl Fn(&mut World, &mut crate::reporters::Trace) -> Fallible + ::core::marker::Send + ::core::marker::Sync
+    {
+        move |world: &mut World, trace: &mut crate::reporters::Trace| {
+            run_then(self.iter().map(|step| trace.step(|| (step.callback)(world))), soft)
         }
     }
-}
-
-trait BackgroundGivenStepsExt<World> {
-    fn to_callback(&self) -> impl FnOnce(&mut World) -> Fallible + ::core::marker::Send + ::core::marker::Sync;
 
     fn to_callback_with_context(
         &self,
-        context: [::std::vec::Vec<ScenarioOrStepHook<World>>; 2],
-    ) -> impl FnOnce(&mut World) -> Fallible + ::core::marker::Send + ::core::marker::Sync;
+        (before_step_hooks, after_step_hooks): Hooks<World>,
+        soft: bool,
+    ) -> impl Fn(&mut World, &mut crate::reporters::Trace) -> Fallible + ::core::marker::Send + ::core::marker::Sync
+    {
+        move |world: &mut World, trace: &mut crate::reporters::Trace| {
+            let results = self.iter().map(|step| {
+                trace.step(|| {
+                    let result = (before_step_hooks.to_callback())(world).and_then(|()| (step.callback)(world));
+                    let after = (after_step_hooks.to_callback())(world, &result);
+
+                    alongside(result, after)
+                })
+            });
+
+            run_then(results, soft)
+        }
+    }
 }
 
-impl<World> BackgroundGivenStepsExt<World> for ::std::vec::Vec<BackgroundGivenStep<World>>
-where
-    World: 'static,
-{
-    fn to_callback(&self) -> impl FnOnce(&mut World) -> Fallible + ::core::marker::Send + ::core::marker::Sync {
-        move |world: &mut World| self.iter().try_for_each(|step| (step.callback)(world))
+/// Results are lazy, so that steps after the first failure never run unless
+/// `soft`, in which case a single failure is kept as is and several are
+/// listed together.
+fn run_then(mut results: impl Iterator<Item = Fallible>, soft: bool) -> Fallible {
+    if !soft {
+        return results.try_for_each(::core::convert::identity);
     }
 
-    fn to_callback_with_context(
-        &self,
-        [before_step_hooks, after_step_hooks]: [::std::vec::Vec<ScenarioOrStepHook<World>>; 2],
-    ) -> impl FnOnce(&mut World) -> Fallible + ::core::marker::Send + ::core::marker::Sync {
-        move |world: &mut World| {
-            self.iter().try_for_each(|step| {
-                (before_step_hooks.to_callback())(world)?;
-                (step.callback)(world)?;
-                (after_step_hooks.to_callback())(world)?;
+    let (count, mut failures) = results.fold((0, ::std::vec::Vec::new()), |(count, mut failures), result| {
+        failures.extend(result.err());
+        (count + 1, failures)
+    });
 
-                Ok(())
-            })
-        }
+    match failures.len() {
+        0 => Ok(()),
+        1 => Err(failures.remove(0)),
+        failed => Err(Failed::listing(::std::format!("{} of {} `Then` steps failed", failed, count), &failures)),
     }
 }
 
@@ -574,63 +992,373 @@ where
     }
 }
 
+trait AfterScenarioOrStepHooksExt<World> {
+    fn to_callback(&self) -> impl Fn(&mut World, &Fallible) -> Fallible + ::core::marker::Send + ::core::marker::Sync;
+}
+
+impl<World> AfterScenarioOrStepHooksExt<World> for ::std::vec::Vec<AfterScenarioOrStepHook<World>>
+where
+    World: 'static,
+{
+    /// Every hook runs, even if one before it failed.
+    fn to_callback(&self) -> impl Fn(&mut World, &Fallible) -> Fallible + ::core::marker::Send + ::core::marker::Sync {
+        move |world: &mut World, outcome: &Fallible| {
+            self.iter().map(|hook| (hook.callback)(world, outcome)).reduce(alongside).unwrap_or(Ok(()))
+        }
+    }
+}
+
+/// Keeps the failure that came first, with the failure of whatever ran after
+/// it appended, so that a failing after-hook never hides why a step failed.
+fn alongside(result: Fallible, after: Fallible) -> Fallible {
+    match (result, after) {
+        (Err(mut failed), Err(after)) => {
+            failed.message = ::std::format!("{}\n\nafter-hook failed: {}", failed.message, after).into();
+            Err(failed)
+        },
+        (Ok(()), after) => after,
+        (result, Ok(())) => result,
+    }
+}
+
 trait GlobalHooksExt {
-    fn to_callback(self) -> impl FnOnce() -> Fallible + ::core::marker::Send + ::core::marker::Sync;
+    fn to_callback(self) -> impl FnOnce(&mut Fixtures) -> Fallible + ::core::marker::Send + ::core::marker::Sync;
 }
 
 impl GlobalHooksExt for ::std::vec::Vec<GlobalHook> {
-    fn to_callback(self) -> impl FnOnce() -> Fallible + ::core::marker::Send + ::core::marker::Sync {
-        move || self.into_iter().try_for_each(|hook| (hook.callback)())
+    fn to_callback(self) -> impl FnOnce(&mut Fixtures) -> Fallible + ::core::marker::Send + ::core::marker::Sync {
+        move |fixtures: &mut Fixtures| self.into_iter().try_for_each(|hook| (hook.callback)(fixtures))
     }
 }
 
 impl Runner {
-    pub fn run(self) -> ::std::process::ExitCode {
+    /// Walks the scenarios that would run, once filtered by tags and by the
+    /// ignore policy, without running any of them or any hook.
+    ///
+    /// `--dry-run` on the command line prints it instead of running, in JSON
+    /// with `--format json`.
+    pub fn plan(self) -> crate::plan::Plan {
+        self.into_plan(|_| true)
+    }
+
+    /// Building trials registers the report of every scenario, which is all
+    /// that the plan needs.
+    fn into_plan(self, keep: impl Fn(&str) -> bool) -> crate::plan::Plan {
+        let shared = Shared::default();
+
+        let trials = self
+            .trials
+            .into_iter()
+            .flat_map(|trials| trials.into_trials_with_configurations(&self.configurations, &shared))
+            .collect::<::std::vec::Vec<_>>();
+
+        let options = self.configurations.into_options(::std::vec::Vec::new());
+        let mut plan = crate::plan::Plan::default();
+
+        for trial in trials.iter().filter(|trial| (options.includes(trial) || trial.ignored) && keep(&trial.name)) {
+            let (ref collector, index) = trial.report;
+            collector.inspect(index, |report| plan.push(report, trial.location.clone()));
+        }
+
+        plan
+    }
+
+    /// Runs the trials, reading the command line as `cargo test` would.
+    ///
+    /// Flags on the command line take precedence over the settings of the
+    /// runner, which only stand in for flags left out, except for `--tags`,
+    /// which narrows the tags filter of the runner rather than replacing it.
+    pub fn run(mut self) -> ::std::process::ExitCode {
+        let mut args = ::std::env::args_os().collect::<::std::vec::Vec<_>>();
+
+        let expression = match take_argument(&mut args, "--tags") {
+            Ok(expression) => expression
+                .or_else(|| ::std::env::var("LITMUS_TAGS").ok())
+                .filter(|expression| !expression.trim().is_empty()),
+            Err(err) => {
+                ::std::eprintln!("error: {}", err);
+                return ::std::process::ExitCode::from(INVALID_ARGUMENTS);
+            },
+        };
+
+        if let Some(expression) = expression {
+            match expression.parse::<crate::tags::Expression>() {
+                Ok(expression) => {
+                    let filter = self.configurations.tags_filter.take();
+
+                    self.configurations.tags_filter =
+                        ::core::option::Option::from(::std::boxed::Box::new(move |tags: &Tags| {
+                            filter.as_ref().is_none_or(|filter| filter(tags)) && expression.matches(tags)
+                        }) as self::configurations::TagsFilter);
+                },
+                Err(err) => {
+                    ::std::eprintln!("error: {}", err);
+                    return ::std::process::ExitCode::from(INVALID_ARGUMENTS);
+                },
+            }
+        }
+
+        let fail_fast = take_fail_fast_argument(&mut args)
+            .or_else(|| ::std::env::var("LITMUS_FAIL_FAST").ok())
+            .filter(|value| !value.trim().is_empty());
+
+        if let Some(value) = fail_fast {
+            match parse_fail_fast(&value) {
+                Ok(max_failures) => self.configurations.max_failures = max_failures,
+                Err(err) => {
+                    ::std::eprintln!("error: {}", err);
+                    return ::std::process::ExitCode::from(INVALID_ARGUMENTS);
+                },
+            }
+        }
+
+        if let Err(err) = self.configurations.take_arguments(&mut args) {
+            ::std::eprintln!("error: {}", err);
+            return ::std::process::ExitCode::from(INVALID_ARGUMENTS);
+        }
+
+        if take_flag(&mut args, "--dry-run") {
+            let filters = crate::engines::Filters::parse(&args);
+            let format = self.configurations.format;
+            let plan = self.into_plan(|name| filters.keep(name));
+
+            match format {
+                self::configurations::Format::Json => ::std::println!("{}", plan.to_json()),
+                _ => ::std::print!("{}", plan),
+            }
+
+            return ::std::process::ExitCode::SUCCESS;
+        }
+
+        let shared = Shared {
+            timeout: self.configurations.timeout,
+            retries: self.configurations.retry_budget.map(|budget| aliases::sync::Arc::new(budget.into())),
+            max_failures: self.configurations.max_failures,
+            #[cfg(feature = "async")]
+            executor: self.configurations.executor.clone(),
+            ..::core::default::Default::default()
+        };
+
         let trials = self
             .trials
             .into_iter()
-            .flat_map(|trials| trials.into_trials_with_configurations(&self.configurations))
+            .flat_map(|trials| trials.into_trials_with_configurations(&self.configurations, &shared))
             .collect();
 
-        let mut args = ::libtest_mimic::Arguments::from_args();
-        self.configurations.update(&mut args);
+        let reports = ::core::mem::take(&mut self.configurations.reports);
 
-        let _ = self.before_global_hooks.to_callback()();
+        let policy = self.configurations.global_hook_failure_policy;
 
-        let conclusion = ::libtest_mimic::run(&args, trials);
-        let exit_code = conclusion.exit_code();
+        let engine = self.configurations.engine.take().unwrap_or_else(default_engine);
+        let options = self.configurations.into_options(args);
 
-        let _ = self.after_global_hooks.to_callback()();
+        shared.collector.set_colored(options.color.is_enabled());
+
+        let before_all = shared.enter(|| {
+            self.before_global_hooks.to_callback()(
+                &mut shared.fixtures.write().unwrap_or_else(::std::sync::PoisonError::into_inner),
+            )
+        });
+
+        if let Err(ref failed) = before_all {
+            print_global_hook_failure("before_all", failed, policy);
+        }
+
+        let exit_code = match (before_all, policy) {
+            (Err(_), self::configurations::GlobalHookFailurePolicy::Abort) => ::std::process::ExitCode::from(ABORTED),
+            (before_all, _) => {
+                if let (Err(failed), self::configurations::GlobalHookFailurePolicy::FailTrials) = (before_all, policy) {
+                    shared.collector.fail_trials(failed);
+                }
+
+                let conclusion = engine.run(trials, &options);
+                shared.collector.write(&reports);
+
+                if !::core::matches!(options.format, self::configurations::Format::Json) {
+                    shared.collector.print_flaky();
+                    shared.collector.print_skipped();
+                }
+
+                conclusion.exit_code()
+            },
+        };
 
-        exit_code
+        let after_all = shared.enter(|| {
+            self.after_global_hooks.to_callback()(
+                &mut shared.fixtures.write().unwrap_or_else(::std::sync::PoisonError::into_inner),
+            )
+        });
+
+        match after_all {
+            Ok(()) => exit_code,
+            Err(failed) => {
+                print_global_hook_failure("after_all", &failed, policy);
+
+                match policy {
+                    self::configurations::GlobalHookFailurePolicy::Abort => ::std::process::ExitCode::from(ABORTED),
+                    self::configurations::GlobalHookFailurePolicy::FailTrials => ::std::process::ExitCode::from(FAILED),
+                    self::configurations::GlobalHookFailurePolicy::Warn => exit_code,
+                }
+            },
+        }
     }
 }
 
-impl self::configurations::RunnerConfigurations {
-    fn update(self, args: &mut ::libtest_mimic::Arguments) {
-        args.format = ::core::option::Option::from(self.format).map(Into::into);
-        args.color = ::core::option::Option::from(self.color).map(Into::into);
-        args.test_threads = self.threads.map(Into::into);
-        args.logfile = self.logfile.map(|path| path.to_string_lossy().into_owned());
+/// Exit code of libtest when a trial failed.
+pub(crate) const FAILED: u8 = 101;
+
+/// When a global hook fails and the run is aborted.
+const ABORTED: u8 = 2;
+
+/// When `--tags`, `--fail-fast`, `--format`, `--color` or `--test-threads`,
+/// or the environment variables standing for them, are given invalid values,
+/// or when a flag that takes a value is given none, as `EX_USAGE` of
+/// `sysexits.h`. It stays apart from the codes of failed and
+/// aborted runs.
+const INVALID_ARGUMENTS: u8 = 64;
+
+/// Takes `<flag> <value>` or `<flag>=<value>` out of the command line, so that
+/// engines are left with arguments they understand. A flag that ends the
+/// command line is missing its value.
+fn take_argument(
+    args: &mut ::std::vec::Vec<::std::ffi::OsString>,
+    flag: &str,
+) -> ::core::result::Result<::core::option::Option<::std::string::String>, ::std::string::String> {
+    let prefix = ::std::format!("{}=", flag);
+
+    let Some(index) = args.iter().position(|arg| arg == flag || arg.to_string_lossy().starts_with(&prefix)) else {
+        return Ok(None);
+    };
+
+    let arg = args.remove(index).to_string_lossy().into_owned();
+
+    match arg.strip_prefix(&prefix) {
+        Some(value) => Ok(Some(value.to_owned())),
+        None if index < args.len() => Ok(Some(args.remove(index).to_string_lossy().into_owned())),
+        None => Err(::std::format!("`{}` requires a value", flag)),
     }
 }
 
-impl From<self::configurations::Format> for ::libtest_mimic::FormatSetting {
-    fn from(format: self::configurations::Format) -> Self {
-        match format {
-            self::configurations::Format::Pretty => Self::Pretty,
-            self::configurations::Format::Terse => Self::Terse,
-            self::configurations::Format::Json => Self::Json,
-        }
+/// Takes `--fail-fast` or `--fail-fast=<value>` out of the command line, the
+/// former standing for `true`.
+fn take_fail_fast_argument(
+    args: &mut ::std::vec::Vec<::std::ffi::OsString>,
+) -> ::core::option::Option<::std::string::String> {
+    let index =
+        args.iter().position(|arg| arg == "--fail-fast" || arg.to_string_lossy().starts_with("--fail-fast="))?;
+    let arg = args.remove(index).to_string_lossy().into_owned();
+
+    match arg.strip_prefix("--fail-fast=") {
+        Some(value) => Some(value.to_owned()),
+        None => Some(::std::string::String::from("true")),
     }
 }
 
-impl From<self::configurations::Color> for ::libtest_mimic::ColorSetting {
-    fn from(color: self::configurations::Color) -> Self {
-        match color {
-            self::configurations::Color::Auto => Self::Auto,
-            self::configurations::Color::Always => Self::Always,
-            self::configurations::Color::Never => Self::Never,
+fn take_flag(args: &mut ::std::vec::Vec<::std::ffi::OsString>, flag: &str) -> bool {
+    let index = args.iter().position(|arg| arg == flag);
+    index.map(|index| args.remove(index)).is_some()
+}
+
+/// `true` stops the run after the first failure and a number after as many,
+/// while `false` and `0` run every scenario whatever the runner says.
+fn parse_fail_fast(value: &str) -> ::core::result::Result<::core::option::Option<usize>, ::std::string::String> {
+    match value.trim() {
+        "true" => Ok(Some(1)),
+        "false" => Ok(None),
+        value => value.parse::<usize>().map(|failures| (failures > 0).then_some(failures)).map_err(|_| {
+            ::std::format!("invalid fail-fast value `{}`, expected `true`, `false` or a number of failures", value)
+        }),
+    }
+}
+
+fn print_global_hook_failure(hook: &str, failed: &Failed, policy: self::configurations::GlobalHookFailurePolicy) {
+    let (severity, consequence) = match policy {
+        self::configurations::GlobalHookFailurePolicy::Abort => ("error", "aborting the run"),
+        self::configurations::GlobalHookFailurePolicy::FailTrials if hook == "before_all" =>
+            ("error", "failing every trial"),
+        self::configurations::GlobalHookFailurePolicy::FailTrials => ("error", "failing the run"),
+        self::configurations::GlobalHookFailurePolicy::Warn => ("warning", "ignoring it"),
+    };
+
+    ::std::eprintln!("\n{}: `{}` hook failed, {}:", severity, hook, consequence);
+    failed.to_string().lines().for_each(|line| ::std::eprintln!("    {}", line));
+    ::std::eprintln!();
+}
+
+#[cfg(feature = "libtest-mimic")]
+fn default_engine() -> ::std::boxed::Box<dyn crate::engines::Engine> {
+    ::std::boxed::Box::new(crate::engines::LibtestEngine::new())
+}
+
+#[cfg(not(feature = "libtest-mimic"))]
+fn default_engine() -> ::std::boxed::Box<dyn crate::engines::Engine> {
+    ::std::boxed::Box::new(crate::engines::NativeEngine::new())
+}
+
+impl self::configurations::RunnerConfigurations {
+    /// Takes the flags of libtest that the runner has settings for out of the
+    /// command line, and lets them override those settings, so that engines
+    /// only have to read their options.
+    fn take_arguments(
+        &mut self,
+        args: &mut ::std::vec::Vec<::std::ffi::OsString>,
+    ) -> ::core::result::Result<(), ::std::string::String> {
+        if let Some(format) = take_argument(args, "--format")? {
+            self.format = match format.as_str() {
+                "pretty" => self::configurations::Format::Pretty,
+                "terse" => self::configurations::Format::Terse,
+                "json" => self::configurations::Format::Json,
+                format =>
+                    return Err(::std::format!("invalid format `{}`, expected `pretty`, `terse` or `json`", format)),
+            };
+        }
+
+        // Not short-circuiting, so that both are taken out.
+        if take_flag(args, "--quiet") | take_flag(args, "-q") {
+            self.format = self::configurations::Format::Terse;
+        }
+
+        if let Some(color) = take_argument(args, "--color")? {
+            self.color = match color.as_str() {
+                "auto" => self::configurations::Color::Auto,
+                "always" => self::configurations::Color::Always,
+                "never" => self::configurations::Color::Never,
+                color => return Err(::std::format!("invalid color `{}`, expected `auto`, `always` or `never`", color)),
+            };
+        }
+
+        if let Some(threads) = take_argument(args, "--test-threads")? {
+            self.threads = match threads.parse::<u64>() {
+                Ok(threads) if threads > 0 => Some(self::configurations::ThreadsCount::Custom(threads)),
+                _ => return Err(::std::format!("invalid number of test threads `{}`", threads)),
+            };
+        }
+
+        if let Some(logfile) = take_argument(args, "--logfile")? {
+            self.logfile = Some(::std::path::PathBuf::from(logfile).into());
+        }
+
+        match (take_flag(args, "--ignored"), take_flag(args, "--include-ignored")) {
+            (_, true) => self.ignore_policy = self::configurations::IgnorePolicy::None,
+            (true, false) => self.ignore_policy = self::configurations::IgnorePolicy::RetainIgnored,
+            (false, false) => {},
+        }
+
+        Ok(())
+    }
+
+    fn into_options(self, args: ::std::vec::Vec<::std::ffi::OsString>) -> crate::engines::Options {
+        crate::engines::Options {
+            args,
+
+            ignore_policy: self.ignore_policy,
+
+            max_failures: self.max_failures,
+
+            format: self.format,
+            color: self.color,
+            threads: self.threads.map(Into::into),
+            logfile: self.logfile,
         }
     }
 }
@@ -648,3 +1376,58 @@ impl From<self::configurations::ThreadsCount> for usize {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arguments_are_taken_with_their_value_or_reported_missing_it() {
+        /// The command line, the value taken out of it, and what is left of it.
+        type Case = (&'static [&'static str], Result<Option<&'static str>, &'static str>, &'static [&'static str]);
+
+        let cases: &[Case] = &[
+            (&["bin", "--tags", "@a", "name"], Ok(Some("@a")), &["bin", "name"]),
+            (&["bin", "--tags=@a or @b", "name"], Ok(Some("@a or @b")), &["bin", "name"]),
+            (&["bin", "--tags="], Ok(Some("")), &["bin"]),
+            (&["bin", "name"], Ok(None), &["bin", "name"]),
+            (&["bin", "--tagsy", "@a"], Ok(None), &["bin", "--tagsy", "@a"]),
+            (&["bin", "name", "--tags"], Err("`--tags` requires a value"), &["bin", "name"]),
+        ];
+
+        for &(args, expected, left) in cases {
+            let mut taken = args.iter().map(::std::ffi::OsString::from).collect::<::std::vec::Vec<_>>();
+            let value = take_argument(&mut taken, "--tags");
+            let taken = taken.iter().map(|arg| arg.to_string_lossy()).collect::<::std::vec::Vec<_>>();
+
+            ::core::assert_eq!(
+                value.as_ref().map(|value| value.as_deref()).map_err(|err| &**err),
+                expected,
+                "{:?}",
+                args
+            );
+            ::core::assert_eq!(taken, left, "{:?}", args);
+        }
+    }
+
+    #[test]
+    fn rules_are_planned_with_only_the_tags_declared_on_them() {
+        let plan = Runner::new()
+            .feature(
+                Feature::new().tags(["@db", "@slow"]).rule(
+                    Rule::new()
+                        .tags(["@rule", "@slow"])
+                        .scenario(Scenario::<()>::new().given("a", |_| {}).when("b", |_| {}).then("c", |_| {})),
+                ),
+            )
+            .build()
+            .plan();
+
+        let feature = &plan.features[0];
+        let rule = &feature.rules[0];
+
+        ::core::assert_eq!(feature.tags, ["db", "slow"]);
+        ::core::assert_eq!(rule.tags, ["rule"]);
+        ::core::assert_eq!(rule.scenarios[0].tags, ["db", "rule", "slow"]);
+    }
+}
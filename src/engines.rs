@@ -0,0 +1,712 @@
+use crate::models::*;
+use crate::utils::aliases;
+
+/// Schedules and runs the trials a runner builds out of its scenarios, and
+/// reports their outcomes on the console.
+///
+/// Reports written to files, global hooks, retries and timeouts are left to
+/// the runner and to the trials themselves, so that an engine only decides
+/// which trials run, when and on which thread.
+pub trait Engine {
+    fn run(&self, trials: ::std::vec::Vec<Trial>, options: &Options) -> Conclusion;
+}
+
+/// A scenario ready to run, with what an engine needs to filter, schedule and
+/// report it.
+pub struct Trial {
+    pub(crate) name: aliases::string::String,
+
+    /// Of the scenario, its feature, its rule and its examples, sorted.
+    pub(crate) tags: ::std::vec::Vec<aliases::string::String>,
+
+    /// Whether the scenario, its rule or its feature is ignored.
+    pub(crate) ignored: bool,
+    pub(crate) location: ::core::option::Option<Location>,
+
+    /// Where the report of the scenario is collected.
+    pub(crate) report: (aliases::sync::Arc<crate::reporters::Collector>, usize),
+
+    pub(crate) callback: ::std::boxed::Box<dyn FnOnce() -> TrialOutcome + ::core::marker::Send>,
+}
+
+impl Trial {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn tags(&self) -> &[aliases::string::String] {
+        &self.tags
+    }
+
+    pub fn is_ignored(&self) -> bool {
+        self.ignored
+    }
+
+    pub fn location(&self) -> ::core::option::Option<&Location> {
+        self.location.as_ref()
+    }
+
+    /// Runs the scenario, recording its outcome for reports. Any thread may run
+    /// it, but a scenario that panics is left unrecorded.
+    pub fn run(self) -> TrialOutcome {
+        (self.callback)()
+    }
+}
+
+pub enum TrialOutcome {
+    Passed,
+
+    /// With the failure rendered for the console, listing the steps of the
+    /// scenario and why the one that failed did.
+    Failed(::std::string::String),
+
+    /// The scenario never started, as the run failed fast before its turn.
+    Skipped,
+}
+
+/// How the runner was configured to run trials, which engines honour as far as
+/// they can. Flags on the command line have already overridden the settings
+/// of the runner here.
+pub struct Options {
+    /// The command line, without the arguments litmus takes for itself.
+    pub(crate) args: ::std::vec::Vec<::std::ffi::OsString>,
+
+    pub(crate) ignore_policy: crate::configurations::IgnorePolicy,
+
+    /// Failures after which trials that have yet to start are skipped, if the
+    /// run fails fast.
+    pub(crate) max_failures: ::core::option::Option<usize>,
+
+    pub(crate) format: crate::configurations::Format,
+    pub(crate) color: crate::configurations::Color,
+    pub(crate) threads: ::core::option::Option<usize>,
+    pub(crate) logfile: ::core::option::Option<aliases::path::Path>,
+}
+
+impl Options {
+    pub fn args(&self) -> &[::std::ffi::OsString] {
+        &self.args
+    }
+
+    /// Whether the trial runs, as far as its being ignored is concerned.
+    pub fn includes(&self, trial: &Trial) -> bool {
+        match self.ignore_policy {
+            crate::configurations::IgnorePolicy::RetainIgnored => trial.ignored,
+            crate::configurations::IgnorePolicy::RetainUnignored => !trial.ignored,
+            crate::configurations::IgnorePolicy::None => true,
+        }
+    }
+
+    /// Failed trials after which those that start end as
+    /// [`TrialOutcome::Skipped`], if the run fails fast.
+    pub fn max_failures(&self) -> ::core::option::Option<usize> {
+        self.max_failures
+    }
+
+    pub fn format(&self) -> crate::configurations::Format {
+        self.format
+    }
+
+    pub fn color(&self) -> crate::configurations::Color {
+        self.color
+    }
+
+    pub fn threads(&self) -> ::core::option::Option<usize> {
+        self.threads
+    }
+
+    pub fn logfile(&self) -> ::core::option::Option<&::std::path::Path> {
+        self.logfile.as_deref()
+    }
+}
+
+/// How many trials ended how, once an engine has run them all.
+#[derive(::core::default::Default, ::core::clone::Clone, ::core::marker::Copy, ::core::fmt::Debug)]
+pub struct Conclusion {
+    pub passed: u64,
+    pub failed: u64,
+    pub skipped: u64,
+    pub ignored: u64,
+    pub filtered_out: u64,
+}
+
+impl Conclusion {
+    pub fn has_failed(&self) -> bool {
+        self.failed > 0
+    }
+
+    /// That of libtest, whichever engine ran the trials.
+    pub fn exit_code(&self) -> ::std::process::ExitCode {
+        match self.has_failed() {
+            true => ::std::process::ExitCode::from(crate::engine::FAILED),
+            false => ::std::process::ExitCode::SUCCESS,
+        }
+    }
+}
+
+/// Runs trials as libtest-mimic tests, which parses the command line as
+/// `cargo test` would.
+///
+/// libtest-mimic has no outcome for skipped trials, and only prints trials as
+/// ignored if told so before it runs them. When the run fails fast, trials
+/// thus all run before libtest-mimic prints any of them, and those that were
+/// skipped are printed as ignored. Output then does not stream, the time
+/// libtest-mimic reports is that of printing the outcomes, and a trial that
+/// hangs shows no progress, so prefer the [`NativeEngine`] to fail fast.
+#[cfg(feature = "libtest-mimic")]
+#[derive(::core::default::Default, ::core::clone::Clone, ::core::marker::Copy)]
+pub struct LibtestEngine;
+
+#[cfg(feature = "libtest-mimic")]
+impl LibtestEngine {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[cfg(feature = "libtest-mimic")]
+impl Engine for LibtestEngine {
+    fn run(&self, trials: ::std::vec::Vec<Trial>, options: &Options) -> Conclusion {
+        let mut args = ::libtest_mimic::Arguments::from_iter(options.args.iter().cloned());
+        options.update(&mut args);
+
+        let (trials, settled) = match options.max_failures.is_some() && !args.list {
+            true => settle(trials, &args),
+            false => (trials, 0),
+        };
+
+        // Trials only end up skipped here if libtest-mimic was told to run
+        // ignored ones, as those that were settled as skipped then are not
+        // handed to it.
+        let ignored = match args.ignored || args.include_ignored {
+            true => 0,
+            false => settled,
+        };
+
+        let skipped = aliases::sync::Arc::new(::std::sync::atomic::AtomicU64::new(0));
+
+        let trials = trials
+            .into_iter()
+            .map(|trial| {
+                let name = trial.name.clone();
+                let tags = (!trial.tags.is_empty()).then(|| trial.tags.join(","));
+                let ignored = trial.ignored;
+
+                let skipped = skipped.clone();
+
+                let trial = ::libtest_mimic::Trial::test(name, move || match trial.run() {
+                    TrialOutcome::Passed => Ok(()),
+                    TrialOutcome::Failed(message) => Err(message.into()),
+                    TrialOutcome::Skipped => {
+                        skipped.fetch_add(1, ::std::sync::atomic::Ordering::Relaxed);
+                        Ok(())
+                    },
+                })
+                .with_ignored_flag(ignored);
+
+                match tags {
+                    Some(tags) => trial.with_kind(tags),
+                    None => trial,
+                }
+            })
+            .collect();
+
+        let conclusion = ::libtest_mimic::run(&args, trials);
+        let skipped = skipped.load(::std::sync::atomic::Ordering::Relaxed);
+
+        Conclusion {
+            passed: conclusion.num_passed - skipped,
+            failed: conclusion.num_failed,
+            skipped: settled + skipped,
+            ignored: conclusion.num_ignored - ignored,
+            filtered_out: conclusion.num_filtered_out,
+        }
+    }
+}
+
+/// Runs the trials that libtest-mimic would run, and has them replay their
+/// outcome when it does. Those that were skipped are flagged as ignored, or
+/// left out if libtest-mimic is to run ignored trials, and counted.
+#[cfg(feature = "libtest-mimic")]
+fn settle(trials: ::std::vec::Vec<Trial>, args: &::libtest_mimic::Arguments) -> (::std::vec::Vec<Trial>, u64) {
+    let mut trials = trials.into_iter().map(|trial| (trial, None)).collect::<::std::vec::Vec<_>>();
+
+    let queue = trials
+        .iter_mut()
+        .enumerate()
+        .filter(|(_, (trial, _))| is_run_by(args, trial))
+        .map(|(position, (trial, _))| {
+            let callback = ::core::mem::replace(&mut trial.callback, ::std::boxed::Box::new(|| TrialOutcome::Skipped));
+            let trial = Trial {
+                name: trial.name.clone(),
+                tags: trial.tags.clone(),
+                ignored: trial.ignored,
+                location: trial.location.clone(),
+
+                report: trial.report.clone(),
+
+                callback,
+            };
+
+            (position, trial)
+        })
+        .collect();
+
+    execute(queue, args.test_threads, |position, outcome| trials[position].1 = Some(outcome));
+
+    let mut skipped = 0;
+
+    let trials = trials
+        .into_iter()
+        .filter_map(|(mut trial, outcome)| match outcome {
+            Some(TrialOutcome::Skipped) => {
+                skipped += 1;
+                trial.ignored = true;
+                (!args.ignored && !args.include_ignored).then_some(trial)
+            },
+            Some(outcome) => {
+                trial.callback = ::std::boxed::Box::new(move || outcome);
+                Some(trial)
+            },
+            None => Some(trial),
+        })
+        .collect();
+
+    (trials, skipped)
+}
+
+/// As libtest-mimic filters trials and decides which are ignored, which it
+/// keeps to itself.
+#[cfg(feature = "libtest-mimic")]
+fn is_run_by(args: &::libtest_mimic::Arguments, trial: &Trial) -> bool {
+    let name = &*trial.name;
+    let name_with_kind = match trial.tags.is_empty() {
+        true => ::std::string::String::from(name),
+        false => ::std::format!("[{}] {}", trial.tags.join(","), name),
+    };
+
+    let matches = |filter: &::std::string::String| match args.exact {
+        true => name == filter || name_with_kind == *filter,
+        false => name_with_kind.contains(filter.as_str()),
+    };
+
+    let filtered_out = args.filter.as_ref().is_some_and(|filter| !matches(filter))
+        || args.skip.iter().any(matches)
+        || args.ignored && !trial.ignored;
+    let ignored = trial.ignored && !args.ignored && !args.include_ignored || args.bench;
+
+    !filtered_out && !ignored
+}
+
+#[cfg(feature = "libtest-mimic")]
+impl Options {
+    /// The runner took the flags these options stand for out of the command
+    /// line, after letting them override its settings.
+    fn update(&self, args: &mut ::libtest_mimic::Arguments) {
+        args.ignored = ::core::matches!(self.ignore_policy, crate::configurations::IgnorePolicy::RetainIgnored);
+        args.include_ignored = ::core::matches!(self.ignore_policy, crate::configurations::IgnorePolicy::None);
+
+        args.format = ::core::option::Option::from(::libtest_mimic::FormatSetting::from(self.format));
+        args.color = ::core::option::Option::from(::libtest_mimic::ColorSetting::from(self.color));
+        args.test_threads = self.threads;
+        args.logfile = self.logfile.as_ref().map(|path| path.to_string_lossy().into_owned());
+    }
+}
+
+#[cfg(feature = "libtest-mimic")]
+impl From<crate::configurations::Format> for ::libtest_mimic::FormatSetting {
+    fn from(format: crate::configurations::Format) -> Self {
+        match format {
+            crate::configurations::Format::Pretty => Self::Pretty,
+            crate::configurations::Format::Terse => Self::Terse,
+            crate::configurations::Format::Json => Self::Json,
+        }
+    }
+}
+
+#[cfg(feature = "libtest-mimic")]
+impl From<crate::configurations::Color> for ::libtest_mimic::ColorSetting {
+    fn from(color: crate::configurations::Color) -> Self {
+        match color {
+            crate::configurations::Color::Auto => Self::Auto,
+            crate::configurations::Color::Always => Self::Always,
+            crate::configurations::Color::Never => Self::Never,
+        }
+    }
+}
+
+/// Runs trials on a pool of threads of its own, telling skipped trials apart.
+///
+/// In the pretty format, scenarios are printed beneath their feature and rule
+/// as Gherkin has them, with their steps, in the order they were defined. The
+/// terse and JSON formats are those of libtest.
+///
+/// Of the command line, it understands `--list`, `--exact`, `--skip` and
+/// filters, which keep the trials whose names contain any of them, besides the
+/// flags that the runner reads into its [`Options`]: `--format`, `--quiet`,
+/// `--color`, `--test-threads`, `--logfile`, `--ignored` and
+/// `--include-ignored`. Other flags are warned about and ignored.
+#[derive(::core::default::Default, ::core::clone::Clone, ::core::marker::Copy)]
+pub struct NativeEngine;
+
+impl NativeEngine {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Engine for NativeEngine {
+    fn run(&self, trials: ::std::vec::Vec<Trial>, options: &Options) -> Conclusion {
+        let start = ::std::time::Instant::now();
+
+        let filters = Filters::parse(&options.args);
+
+        let (trials, filtered_out) =
+            trials.into_iter().partition::<::std::vec::Vec<_>, _>(|trial| filters.keep(&trial.name));
+
+        let mut conclusion = Conclusion {
+            filtered_out: filtered_out.len() as u64,
+            ..::core::default::Default::default()
+        };
+
+        if filters.list {
+            trials.iter().for_each(|trial| ::std::println!("{}: test", trial.name));
+            return conclusion;
+        }
+
+        let mut slots = ::std::vec::Vec::with_capacity(trials.len());
+        let mut queue = ::std::vec::Vec::new();
+
+        for trial in trials {
+            let state = match (options.includes(&trial), trial.ignored) {
+                (true, _) => State::Pending,
+                (false, true) => State::Ignored,
+                (false, false) => {
+                    conclusion.filtered_out += 1;
+                    continue;
+                },
+            };
+
+            slots.push(Slot {
+                name: trial.name.clone(),
+                location: trial.location.clone(),
+                report: trial.report.clone(),
+
+                state,
+            });
+
+            if let State::Pending = slots[slots.len() - 1].state {
+                queue.push((slots.len() - 1, trial));
+            }
+        }
+
+        let mut console = Console::new(options, slots.len());
+
+        // Trials are printed in the order they were defined, as soon as those
+        // before them are.
+        let mut printed = 0;
+        let mut release = |slots: &[Slot]| {
+            while let Some(slot) = slots.get(printed).filter(|slot| !::core::matches!(slot.state, State::Pending)) {
+                console.print(slot);
+
+                match slot.state {
+                    State::Finished(TrialOutcome::Passed) => conclusion.passed += 1,
+                    State::Finished(TrialOutcome::Failed(_)) => conclusion.failed += 1,
+                    State::Finished(TrialOutcome::Skipped) => conclusion.skipped += 1,
+                    State::Ignored => conclusion.ignored += 1,
+                    State::Pending => (),
+                }
+
+                printed += 1;
+            }
+        };
+
+        release(&slots);
+
+        execute(queue, options.threads, |position, outcome| {
+            slots[position].state = State::Finished(outcome);
+            release(&slots);
+        });
+
+        console.conclude(&conclusion, start.elapsed());
+        conclusion
+    }
+}
+
+/// Runs `trials` on a pool of `threads` threads, as many as the machine has
+/// if unset, and hands each outcome to `finished` along with the position
+/// that came with its trial, as soon as it is known.
+fn execute(
+    trials: ::std::vec::Vec<(usize, Trial)>,
+    threads: ::core::option::Option<usize>,
+    mut finished: impl FnMut(usize, TrialOutcome),
+) {
+    let threads = threads
+        .unwrap_or_else(|| ::std::thread::available_parallelism().map_or(1, ::core::num::NonZeroUsize::get))
+        .clamp(1, trials.len().max(1));
+
+    let queue = ::std::sync::Mutex::new(trials.into_iter());
+    let (sender, receiver) = ::std::sync::mpsc::channel();
+
+    ::std::thread::scope(|scope| {
+        for _ in 0..threads {
+            let queue = &queue;
+            let sender = sender.clone();
+
+            scope.spawn(move || loop {
+                let next = queue.lock().unwrap_or_else(::std::sync::PoisonError::into_inner).next();
+
+                let Some((position, trial)) = next else {
+                    break;
+                };
+
+                let outcome = ::std::panic::catch_unwind(::std::panic::AssertUnwindSafe(|| trial.run()))
+                    .unwrap_or_else(|payload| TrialOutcome::Failed(panicked(&*payload)));
+
+                if sender.send((position, outcome)).is_err() {
+                    break;
+                }
+            });
+        }
+
+        drop(sender);
+        receiver.into_iter().for_each(|(position, outcome)| finished(position, outcome));
+    });
+}
+
+/// What the native engine, and the runner on a dry run, make of the command
+/// line.
+#[derive(::core::default::Default)]
+pub(crate) struct Filters {
+    exact: bool,
+    list: bool,
+
+    included: ::std::vec::Vec<::std::string::String>,
+    skipped: ::std::vec::Vec<::std::string::String>,
+}
+
+impl Filters {
+    /// Flags that the runner reads into its options are no longer part of the
+    /// command line by then.
+    pub(crate) fn parse(args: &[::std::ffi::OsString]) -> Self {
+        let mut filters = Self::default();
+        let mut args = args.iter().skip(1).map(|arg| arg.to_string_lossy().into_owned());
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--exact" => filters.exact = true,
+                "--list" => filters.list = true,
+                "--skip" => filters.skipped.extend(args.next()),
+                flag if flag.starts_with('-') => ::std::eprintln!("warning: ignoring unsupported flag `{}`", flag),
+                _ => filters.included.push(arg),
+            }
+        }
+
+        filters
+    }
+
+    pub(crate) fn keep(&self, name: &str) -> bool {
+        let matches = |filter: &::std::string::String| match self.exact {
+            true => name == filter,
+            false => name.contains(filter.as_str()),
+        };
+
+        (self.included.is_empty() || self.included.iter().any(matches)) && !self.skipped.iter().any(matches)
+    }
+}
+
+/// A trial the native engine is to print, once those before it are.
+struct Slot {
+    name: aliases::string::String,
+    location: ::core::option::Option<Location>,
+    report: (aliases::sync::Arc<crate::reporters::Collector>, usize),
+
+    state: State,
+}
+
+enum State {
+    Pending,
+    Ignored,
+    Finished(TrialOutcome),
+}
+
+/// What the native engine prints, scenarios as Gherkin has them in the pretty
+/// format and otherwise as libtest does.
+struct Console {
+    format: crate::configurations::Format,
+    colored: bool,
+    pretty: crate::reporters::pretty::Pretty,
+
+    logfile: ::core::option::Option<::std::fs::File>,
+    failures: ::std::vec::Vec<(::std::string::String, ::std::string::String)>,
+}
+
+impl Console {
+    fn new(options: &Options, count: usize) -> Self {
+        let logfile = options.logfile.as_ref().and_then(|path| match ::std::fs::File::create(path) {
+            Ok(file) => Some(file),
+            Err(err) => {
+                ::std::eprintln!("warning: failed to create logfile `{}`: {}", path.display(), err);
+                None
+            },
+        });
+
+        match options.format {
+            crate::configurations::Format::Json => ::std::println!(
+                "{}",
+                crate::reporters::json::Json::object([
+                    ("type", "suite".into()),
+                    ("event", "started".into()),
+                    ("test_count", count.into()),
+                ])
+            ),
+            _ => ::std::println!("\nrunning {} {}", count, tests(count as u64)),
+        }
+
+        Self {
+            format: options.format,
+            colored: options.color.is_enabled(),
+            pretty: crate::reporters::pretty::Pretty::new(options.color.is_enabled()),
+
+            logfile,
+            failures: ::std::vec::Vec::new(),
+        }
+    }
+
+    /// Ignored trials have no outcome, as they never ran.
+    fn print(&mut self, slot: &Slot) {
+        let name = &*slot.name;
+        let outcome = match slot.state {
+            State::Finished(ref outcome) => Some(outcome),
+            State::Pending | State::Ignored => None,
+        };
+
+        let (status, mark, event, color) = match outcome {
+            None => ("ignored", "i", "ignored", 33),
+            Some(TrialOutcome::Passed) => ("ok", ".", "ok", 32),
+            Some(TrialOutcome::Failed(_)) => ("FAILED", "F", "failed", 31),
+            Some(TrialOutcome::Skipped) => ("skipped", "s", "skipped", 33),
+        };
+
+        if let Some(TrialOutcome::Failed(message)) = outcome {
+            let name = match slot.location {
+                Some(ref location) => ::std::format!("{} ({})", name, location),
+                None => ::std::string::String::from(name),
+            };
+
+            self.failures.push((name, message.clone()));
+        }
+
+        if let Some(ref mut logfile) = self.logfile {
+            let _ = ::std::io::Write::write_fmt(logfile, ::core::format_args!("{} {}\n", event, name));
+        }
+
+        match self.format {
+            crate::configurations::Format::Pretty => {
+                let (ref collector, index) = slot.report;
+                let pretty = &mut self.pretty;
+
+                match collector.inspect(index, |report| pretty.scenario(report)) {
+                    Some(rendered) => ::std::print!("{}", rendered),
+                    None => ::std::println!("test {} ... {}", name, self.paint(status, color)),
+                }
+            },
+            crate::configurations::Format::Terse => {
+                ::std::print!("{}", self.paint(mark, color));
+                let _ = ::std::io::Write::flush(&mut ::std::io::stdout());
+            },
+            crate::configurations::Format::Json => {
+                let event = crate::reporters::json::Json::object([
+                    ("type", "test".into()),
+                    ("name", name.into()),
+                    ("event", event.into()),
+                ]);
+
+                match outcome {
+                    Some(TrialOutcome::Failed(message)) =>
+                        ::std::println!("{}", event.with("stdout", message.as_str())),
+                    _ => ::std::println!("{}", event),
+                }
+            },
+        }
+    }
+
+    fn conclude(&mut self, conclusion: &Conclusion, elapsed: ::core::time::Duration) {
+        let (status, event, color) = match conclusion.has_failed() {
+            true => ("FAILED", "failed", 31),
+            false => ("ok", "ok", 32),
+        };
+
+        if let crate::configurations::Format::Json = self.format {
+            ::std::println!(
+                "{}",
+                crate::reporters::json::Json::object([
+                    ("type", "suite".into()),
+                    ("event", event.into()),
+                    ("passed", (conclusion.passed as u128).into()),
+                    ("failed", (conclusion.failed as u128).into()),
+                    ("skipped", (conclusion.skipped as u128).into()),
+                    ("ignored", (conclusion.ignored as u128).into()),
+                    ("filtered_out", (conclusion.filtered_out as u128).into()),
+                    ("exec_time", elapsed.as_secs_f64().to_string().into()),
+                ])
+            );
+
+            return;
+        }
+
+        match self.format {
+            crate::configurations::Format::Pretty => ::std::print!("{}", self.pretty.finish()),
+            _ => ::std::println!(),
+        }
+
+        // The pretty format has already shown why scenarios failed.
+        if !self.failures.is_empty() && !::core::matches!(self.format, crate::configurations::Format::Pretty) {
+            ::std::println!("\nfailures:\n");
+            self.failures.iter().for_each(|(name, message)| ::std::println!("---- {} ----\n{}\n", name, message));
+        }
+
+        if !self.failures.is_empty() {
+            ::std::println!("\nfailures:");
+            self.failures.iter().for_each(|(name, _)| ::std::println!("    {}", name));
+        }
+
+        ::std::println!(
+            "\ntest result: {}. {} passed; {} failed; {} skipped; {} ignored; {} filtered out; finished in {:.2}s\n",
+            self.paint(status, color),
+            conclusion.passed,
+            conclusion.failed,
+            conclusion.skipped,
+            conclusion.ignored,
+            conclusion.filtered_out,
+            elapsed.as_secs_f64(),
+        );
+    }
+
+    fn paint(&self, text: &str, color: u8) -> ::std::string::String {
+        match self.colored {
+            true => ::std::format!("\x1b[{}m{}\x1b[0m", color, text),
+            false => text.to_owned(),
+        }
+    }
+}
+
+fn tests(count: u64) -> &'static str {
+    match count {
+        1 => "test",
+        _ => "tests",
+    }
+}
+
+/// As libtest words it, with the message of the panic if it has one.
+fn panicked(payload: &(dyn ::core::any::Any + ::core::marker::Send)) -> ::std::string::String {
+    let message = payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<::std::string::String>().map(::std::string::String::as_str));
+
+    match message {
+        Some(message) => ::std::format!("test panicked: {}", message),
+        None => ::std::string::String::from("test panicked"),
+    }
+}
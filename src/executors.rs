@@ -0,0 +1,231 @@
+use crate::models::*;
+use crate::utils::aliases;
+
+pub trait Executor: ::core::marker::Send + ::core::marker::Sync {
+    fn block_on<'a>(
+        &self,
+        future: ::core::pin::Pin<::std::boxed::Box<dyn ::core::future::Future<Output = Fallible> + 'a>>,
+    ) -> Fallible;
+}
+
+/// Drives futures to completion on the calling thread, parking it while
+/// pending.
+#[derive(::core::default::Default, ::core::clone::Clone, ::core::marker::Copy)]
+pub struct LocalExecutor;
+
+impl LocalExecutor {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Executor for LocalExecutor {
+    fn block_on<'a>(
+        &self,
+        mut future: ::core::pin::Pin<::std::boxed::Box<dyn ::core::future::Future<Output = Fallible> + 'a>>,
+    ) -> Fallible {
+        let waker = ::std::task::Waker::from(::std::sync::Arc::new(ThreadWaker(::std::thread::current())));
+        let mut context = ::std::task::Context::from_waker(&waker);
+
+        loop {
+            match future.as_mut().poll(&mut context) {
+                ::std::task::Poll::Ready(output) => break output,
+                ::std::task::Poll::Pending => ::std::thread::park(),
+            }
+        }
+    }
+}
+
+struct ThreadWaker(::std::thread::Thread);
+
+impl ::std::task::Wake for ThreadWaker {
+    fn wake(self: ::std::sync::Arc<Self>) {
+        self.0.unpark();
+    }
+
+    fn wake_by_ref(self: &::std::sync::Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+#[cfg(feature = "tokio")]
+pub struct TokioExecutor {
+    runtime: ::tokio::runtime::Runtime,
+}
+
+#[cfg(feature = "tokio")]
+impl TokioExecutor {
+    /// Panics if the underlying multi-threaded runtime cannot be created, see
+    /// [`TokioExecutor::try_new`] otherwise.
+    pub fn new() -> Self {
+        Self::try_new().unwrap_or_else(|err| ::std::panic!("failed to build tokio runtime: {err}"))
+    }
+
+    pub fn try_new() -> ::std::io::Result<Self> {
+        let runtime = ::tokio::runtime::Builder::new_multi_thread().enable_all().build()?;
+
+        Ok(Self {
+            runtime,
+        })
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl ::core::default::Default for TokioExecutor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl From<::tokio::runtime::Runtime> for TokioExecutor {
+    fn from(runtime: ::tokio::runtime::Runtime) -> Self {
+        Self {
+            runtime,
+        }
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl Executor for TokioExecutor {
+    fn block_on<'a>(
+        &self,
+        future: ::core::pin::Pin<::std::boxed::Box<dyn ::core::future::Future<Output = Fallible> + 'a>>,
+    ) -> Fallible {
+        self.runtime.block_on(future)
+    }
+}
+
+pub(crate) type SharedExecutor = aliases::sync::Arc<dyn Executor>;
+
+::std::thread_local! {
+    /// Of the runner whose scenario or hook runs on this thread, if it has one.
+    static EXECUTOR: ::core::cell::RefCell<::core::option::Option<SharedExecutor>> =
+        const { ::core::cell::RefCell::new(None) };
+}
+
+/// Has `executor` drive the asynchronous steps and hooks that `run` runs on
+/// this thread, so that runners sharing a process do not share an executor.
+pub(crate) fn enter<T>(executor: ::core::option::Option<SharedExecutor>, run: impl FnOnce() -> T) -> T {
+    let _entered = Entered(EXECUTOR.replace(executor));
+    run()
+}
+
+/// Puts back the executor that was entered before, even if `run` panicked.
+struct Entered(::core::option::Option<SharedExecutor>);
+
+impl ::core::ops::Drop for Entered {
+    fn drop(&mut self) {
+        EXECUTOR.set(self.0.take());
+    }
+}
+
+::std::thread_local! {
+    /// When asynchronous steps and hooks running on this thread are cancelled,
+    /// and the timeout it was computed from.
+    static DEADLINE: ::core::cell::Cell<::core::option::Option<(::std::time::Instant, ::core::time::Duration)>> =
+        const { ::core::cell::Cell::new(None) };
+}
+
+/// Set on the thread a scenario with a timeout runs on.
+pub(crate) fn set_deadline(deadline: ::std::time::Instant, timeout: ::core::time::Duration) {
+    DEADLINE.set(Some((deadline, timeout)));
+}
+
+pub(crate) fn block_on<'a>(future: impl ::core::future::Future<Output = Fallible> + 'a) -> Fallible {
+    let executor = EXECUTOR.with_borrow(::core::clone::Clone::clone);
+
+    let future: ::core::pin::Pin<::std::boxed::Box<dyn ::core::future::Future<Output = Fallible> + 'a>> =
+        match DEADLINE.get() {
+            Some(deadline) => ::std::boxed::Box::pin(cancel_at(deadline, future)),
+            None => ::std::boxed::Box::pin(future),
+        };
+
+    match executor {
+        Some(executor) => executor.block_on(future),
+        None => LocalExecutor.block_on(future),
+    }
+}
+
+/// Resolves as timed out once the deadline has passed, dropping `future`,
+/// whatever the executor driving it.
+fn cancel_at<'a>(
+    (deadline, timeout): (::std::time::Instant, ::core::time::Duration),
+    future: impl ::core::future::Future<Output = Fallible> + 'a,
+) -> impl ::core::future::Future<Output = Fallible> + 'a {
+    let mut future = ::std::boxed::Box::pin(future);
+    let mut alarm = None::<Alarm>;
+
+    ::core::future::poll_fn(move |context| {
+        if let ::std::task::Poll::Ready(output) = future.as_mut().poll(context) {
+            return ::std::task::Poll::Ready(output);
+        }
+
+        if ::std::time::Instant::now() >= deadline {
+            return ::std::task::Poll::Ready(Err(Failed::timed_out(timeout)));
+        }
+
+        match alarm {
+            Some(ref alarm) => alarm.update(context.waker()),
+            None => alarm = Alarm::set(deadline, context.waker()),
+        }
+
+        ::std::task::Poll::Pending
+    })
+}
+
+/// Wakes the task polling a future once its deadline has passed, from a
+/// thread of its own, unless dropped before.
+struct Alarm {
+    waker: aliases::sync::Arc<::std::sync::Mutex<::std::task::Waker>>,
+    dropped: aliases::sync::Arc<::std::sync::atomic::AtomicBool>,
+    thread: ::std::thread::Thread,
+}
+
+impl Alarm {
+    /// `None` if no thread could be spawned, in which case the future is only
+    /// cancelled if polled after the deadline.
+    fn set(deadline: ::std::time::Instant, waker: &::std::task::Waker) -> ::core::option::Option<Self> {
+        let waker = aliases::sync::Arc::new(::std::sync::Mutex::new(waker.clone()));
+        let dropped = aliases::sync::Arc::new(::std::sync::atomic::AtomicBool::new(false));
+
+        let spawned = ::std::thread::Builder::new().spawn({
+            let waker = waker.clone();
+            let dropped = dropped.clone();
+
+            move || {
+                while !dropped.load(::std::sync::atomic::Ordering::Acquire) {
+                    let now = ::std::time::Instant::now();
+
+                    if now >= deadline {
+                        waker.lock().unwrap_or_else(::std::sync::PoisonError::into_inner).wake_by_ref();
+                        return;
+                    }
+
+                    ::std::thread::park_timeout(deadline - now);
+                }
+            }
+        });
+
+        Some(Self {
+            waker,
+            dropped,
+            thread: spawned.ok()?.thread().clone(),
+        })
+    }
+
+    fn update(&self, waker: &::std::task::Waker) {
+        let mut current = self.waker.lock().unwrap_or_else(::std::sync::PoisonError::into_inner);
+
+        if !current.will_wake(waker) {
+            current.clone_from(waker);
+        }
+    }
+}
+
+impl ::core::ops::Drop for Alarm {
+    fn drop(&mut self) {
+        self.dropped.store(true, ::std::sync::atomic::Ordering::Release);
+        self.thread.unpark();
+    }
+}
@@ -0,0 +1,79 @@
+pub(crate) struct Feature {
+    pub(crate) name: ::std::string::String,
+    pub(crate) description: ::core::option::Option<::std::string::String>,
+    pub(crate) tags: ::std::vec::Vec<::std::string::String>,
+
+    pub(crate) background: ::core::option::Option<Background>,
+    pub(crate) scenarios: ::std::vec::Vec<Scenario>,
+    pub(crate) rules: ::std::vec::Vec<Rule>,
+}
+
+pub(crate) struct Rule {
+    pub(crate) name: ::std::string::String,
+    pub(crate) description: ::core::option::Option<::std::string::String>,
+    pub(crate) tags: ::std::vec::Vec<::std::string::String>,
+
+    pub(crate) background: ::core::option::Option<Background>,
+    pub(crate) scenarios: ::std::vec::Vec<Scenario>,
+}
+
+pub(crate) struct Background {
+    pub(crate) steps: ::std::vec::Vec<Step>,
+}
+
+/// A `Scenario` is an outline if and only if it has at least one `Examples`
+/// block.
+pub(crate) struct Scenario {
+    pub(crate) name: ::std::string::String,
+    pub(crate) description: ::core::option::Option<::std::string::String>,
+    pub(crate) tags: ::std::vec::Vec<::std::string::String>,
+    pub(crate) line: usize,
+
+    pub(crate) steps: ::std::vec::Vec<Step>,
+    pub(crate) examples: ::std::vec::Vec<Examples>,
+}
+
+pub(crate) struct Examples {
+    pub(crate) name: ::std::string::String,
+    pub(crate) description: ::core::option::Option<::std::string::String>,
+    pub(crate) tags: ::std::vec::Vec<::std::string::String>,
+
+    pub(crate) table: ::core::option::Option<DataTable>,
+}
+
+#[derive(::core::clone::Clone)]
+pub(crate) struct Step {
+    pub(crate) keyword: Keyword,
+    pub(crate) text: ::std::string::String,
+    pub(crate) line: usize,
+
+    pub(crate) argument: ::core::option::Option<StepArgument>,
+}
+
+#[derive(::core::clone::Clone, ::core::marker::Copy, ::core::cmp::PartialEq, ::core::cmp::Eq)]
+pub(crate) enum Keyword {
+    Given,
+    When,
+    Then,
+
+    And,
+    But,
+    Asterisk,
+}
+
+#[derive(::core::clone::Clone)]
+pub(crate) enum StepArgument {
+    DocString(DocString),
+    DataTable(DataTable),
+}
+
+#[derive(::core::clone::Clone)]
+pub(crate) struct DocString {
+    pub(crate) media_type: ::core::option::Option<::std::string::String>,
+    pub(crate) content: ::std::string::String,
+}
+
+#[derive(::core::clone::Clone)]
+pub(crate) struct DataTable {
+    pub(crate) rows: ::std::vec::Vec<::std::vec::Vec<::std::string::String>>,
+}
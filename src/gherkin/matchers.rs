@@ -0,0 +1,641 @@
+use crate::arguments::*;
+use crate::models::*;
+use crate::utils::aliases;
+
+/// The text a step definition is matched against, either a [Cucumber Expression](https://github.com/cucumber/cucumber-expressions)
+/// or a regular expression.
+///
+/// Strings anchored with `^` or `$` are taken to be regular expressions, as in
+/// other Cucumber implementations.
+pub enum StepPattern {
+    Expression(aliases::string::String),
+    Regex(::regex::Regex),
+}
+
+impl From<&'static str> for StepPattern {
+    fn from(pattern: &'static str) -> Self {
+        aliases::string::String::from(pattern).into()
+    }
+}
+
+impl From<::std::string::String> for StepPattern {
+    fn from(pattern: ::std::string::String) -> Self {
+        aliases::string::String::from(pattern).into()
+    }
+}
+
+impl From<aliases::string::String> for StepPattern {
+    fn from(pattern: aliases::string::String) -> Self {
+        if !pattern.starts_with('^') && !pattern.ends_with('$') {
+            return Self::Expression(pattern);
+        }
+
+        match ::regex::Regex::new(&pattern) {
+            Ok(regex) => Self::Regex(regex),
+            // Kept as is, so that the error surfaces from `StepRegistry::load` rather than here.
+            Err(_) => Self::Expression(pattern),
+        }
+    }
+}
+
+impl From<::regex::Regex> for StepPattern {
+    fn from(regex: ::regex::Regex) -> Self {
+        Self::Regex(regex)
+    }
+}
+
+impl ::core::fmt::Display for StepPattern {
+    fn fmt(&self, formatter: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self {
+            Self::Expression(expression) => formatter.write_str(expression),
+            Self::Regex(regex) => formatter.write_str(regex.as_str()),
+        }
+    }
+}
+
+/// Converts the text captured by a step pattern into a typed step definition
+/// argument.
+///
+/// [`DataTable`] and [`DocString`] arguments are rather taken from under the
+/// step, wherever they appear among the arguments of the callback.
+pub trait FromStepArgument: Sized {
+    /// Whether the argument is captured from the text of the step, and so
+    /// counts towards the parameters of its pattern.
+    #[doc(hidden)]
+    const IS_CAPTURED: bool = true;
+
+    fn from_step_argument(argument: &str) -> Fallible<Self>;
+
+    #[doc(hidden)]
+    fn from_step_data(_: ::core::option::Option<&StepArgument>) -> Fallible<Self> {
+        Err(Failed::untraced(
+            ::std::format!("`{}` is not taken from under a step", ::core::any::type_name::<Self>()),
+            FailedKind::Failed,
+        ))
+    }
+}
+
+impl FromStepArgument for DataTable {
+    const IS_CAPTURED: bool = false;
+
+    fn from_step_argument(_: &str) -> Fallible<Self> {
+        Err(Failed::untraced("A data table cannot be captured from the text of a step", FailedKind::Failed))
+    }
+
+    fn from_step_data(argument: ::core::option::Option<&StepArgument>) -> Fallible<Self> {
+        match argument {
+            Some(StepArgument::DataTable(table)) => Ok(table.clone()),
+            Some(StepArgument::DocString(_)) =>
+                Err(Failed::untraced("Expected a data table under the step, found a doc string", FailedKind::Failed)),
+            None => Err(Failed::untraced("Expected a data table under the step", FailedKind::Failed)),
+        }
+    }
+}
+
+impl FromStepArgument for DocString {
+    const IS_CAPTURED: bool = false;
+
+    fn from_step_argument(_: &str) -> Fallible<Self> {
+        Err(Failed::untraced("A doc string cannot be captured from the text of a step", FailedKind::Failed))
+    }
+
+    fn from_step_data(argument: ::core::option::Option<&StepArgument>) -> Fallible<Self> {
+        match argument {
+            Some(StepArgument::DocString(doc_string)) => Ok(doc_string.clone()),
+            Some(StepArgument::DataTable(_)) =>
+                Err(Failed::untraced("Expected a doc string under the step, found a data table", FailedKind::Failed)),
+            None => Err(Failed::untraced("Expected a doc string under the step", FailedKind::Failed)),
+        }
+    }
+}
+
+impl FromStepArgument for ::std::string::String {
+    fn from_step_argument(argument: &str) -> Fallible<Self> {
+        Ok(argument.to_owned())
+    }
+}
+
+impl FromStepArgument for aliases::string::String {
+    fn from_step_argument(argument: &str) -> Fallible<Self> {
+        Ok(argument.to_owned().into())
+    }
+}
+
+macro_rules! impl_from_step_argument_for_from_str {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl FromStepArgument for $ty {
+                fn from_step_argument(argument: &str) -> Fallible<Self> {
+                    argument.parse().map_err(|err| {
+                        Failed::untraced(
+                            ::std::format!(
+                                "Failed to convert `{}` into `{}`: {}",
+                                argument,
+                                ::core::any::type_name::<Self>(),
+                                err
+                            ),
+                            FailedKind::Failed,
+                        )
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_from_step_argument_for_from_str!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64, bool, char,
+);
+
+/// A parameter type that Cucumber Expressions refer to as `{name}`.
+pub(crate) struct ParameterType {
+    pub(crate) name: aliases::string::String,
+    pub(crate) regex: aliases::string::String,
+
+    /// Whether captures are surrounded by quotes that are not part of the
+    /// argument, as with `{string}`.
+    pub(crate) quoted: bool,
+}
+
+impl ParameterType {
+    pub(crate) fn new(name: impl Into<aliases::string::String>, regex: impl Into<aliases::string::String>) -> Self {
+        Self {
+            name: name.into(),
+            regex: regex.into(),
+            quoted: false,
+        }
+    }
+
+    pub(crate) fn defaults() -> ::std::vec::Vec<Self> {
+        const INT: &str = r"-?\d+";
+        const FLOAT: &str = r"[-+]?(?:\d+\.?\d*|\.\d+)(?:[eE][-+]?\d+)?";
+
+        ::std::vec![
+            Self::new("", ".*"),
+            Self::new("int", INT),
+            Self::new("byte", INT),
+            Self::new("short", INT),
+            Self::new("long", INT),
+            Self::new("float", FLOAT),
+            Self::new("double", FLOAT),
+            Self::new("word", r"[^\s]+"),
+            Self {
+                quoted: true,
+                ..Self::new("string", r#""(?:[^"\\]|\\.)*"|'(?:[^'\\]|\\.)*'"#)
+            },
+        ]
+    }
+}
+
+pub(crate) struct StepMatcher {
+    regex: ::regex::Regex,
+    parameters: ::std::vec::Vec<Parameter>,
+}
+
+struct Parameter {
+    group: usize,
+    quoted: bool,
+}
+
+impl StepMatcher {
+    pub(crate) fn compile(
+        pattern: &StepPattern,
+        parameter_types: &[ParameterType],
+    ) -> Result<Self, ::std::string::String> {
+        match pattern {
+            StepPattern::Regex(regex) => Ok(Self {
+                regex: regex.clone(),
+                parameters: (1..regex.captures_len())
+                    .map(|group| Parameter {
+                        group,
+                        quoted: false,
+                    })
+                    .collect(),
+            }),
+
+            StepPattern::Expression(expression) if expression.starts_with('^') || expression.ends_with('$') =>
+                match ::regex::Regex::new(expression) {
+                    Ok(regex) => Self::compile(&StepPattern::Regex(regex), parameter_types),
+                    Err(err) => Err(err.to_string()),
+                },
+
+            StepPattern::Expression(expression) => {
+                let mut compiler = ExpressionCompiler {
+                    parameter_types,
+                    source: ::std::string::String::from("^"),
+                    parameters: ::std::vec::Vec::new(),
+                };
+
+                compiler.compile(expression)?;
+                compiler.source.push('$');
+
+                let regex = ::regex::Regex::new(&compiler.source).map_err(|err| err.to_string())?;
+
+                let parameters = compiler
+                    .parameters
+                    .iter()
+                    .enumerate()
+                    .map(|(index, parameter_type)| Parameter {
+                        group: regex
+                            .capture_names()
+                            .position(|name| name == Some(&ExpressionCompiler::group_name(index)))
+                            .unwrap_or_default(),
+                        quoted: parameter_type.quoted,
+                    })
+                    .collect();
+
+                Ok(Self {
+                    regex,
+                    parameters,
+                })
+            },
+        }
+    }
+
+    pub(crate) fn arity(&self) -> usize {
+        self.parameters.len()
+    }
+
+    pub(crate) fn captures(&self, text: &str) -> ::core::option::Option<Captures> {
+        let captures = self.regex.captures(text)?;
+
+        Some(Captures {
+            text: text.to_owned(),
+            arguments: self
+                .parameters
+                .iter()
+                .map(|parameter| {
+                    captures.get(parameter.group).map(|capture| {
+                        let argument = match parameter.quoted {
+                            true => unquote(capture.as_str()),
+                            false => capture.as_str().to_owned(),
+                        };
+
+                        (capture.range(), argument)
+                    })
+                })
+                .collect(),
+            data: None,
+        })
+    }
+}
+
+/// Translates a Cucumber Expression into an anchored regular expression, one
+/// whitespace-delimited word at a time so that alternation (`cucumber/gherkin`)
+/// binds to the word it appears in.
+struct ExpressionCompiler<'a> {
+    parameter_types: &'a [ParameterType],
+    source: ::std::string::String,
+    parameters: ::std::vec::Vec<&'a ParameterType>,
+}
+
+impl ExpressionCompiler<'_> {
+    fn compile(&mut self, expression: &str) -> Result<(), ::std::string::String> {
+        let mut rest = expression;
+
+        while !rest.is_empty() {
+            let split = rest.find(char::is_whitespace).unwrap_or(rest.len());
+            let (word, tail) = rest.split_at(split);
+
+            let whitespace = tail.find(|char: char| !char.is_whitespace()).unwrap_or(tail.len());
+            let (whitespace, tail) = tail.split_at(whitespace);
+
+            let alternatives = split_alternatives(word);
+
+            match alternatives.as_slice() {
+                [word] => self.compile_text(word, true)?,
+                alternatives => {
+                    self.source.push_str("(?:");
+
+                    for (index, alternative) in alternatives.iter().enumerate() {
+                        if index > 0 {
+                            self.source.push('|');
+                        }
+
+                        self.compile_text(alternative, false)?;
+                    }
+
+                    self.source.push(')');
+                },
+            }
+
+            self.source.push_str(&::regex::escape(whitespace));
+            rest = tail;
+        }
+
+        Ok(())
+    }
+
+    fn compile_text(&mut self, text: &str, allows_parameters: bool) -> Result<(), ::std::string::String> {
+        let mut chars = text.chars();
+
+        while let Some(char) = chars.next() {
+            match char {
+                '\\' => {
+                    let char = chars.next().unwrap_or('\\');
+                    self.source.push_str(&::regex::escape(char.encode_utf8(&mut [0; 4])));
+                },
+
+                '{' => {
+                    let name = take_until(&mut chars, '}').ok_or("`{` is never closed by `}`")?;
+
+                    if !allows_parameters {
+                        return Err(::std::format!("parameter `{{{}}}` cannot be used within an alternation", name));
+                    }
+
+                    let parameter_type = self
+                        .parameter_types
+                        .iter()
+                        .rev()
+                        .find(|parameter_type| parameter_type.name == name)
+                        .ok_or_else(|| ::std::format!("parameter type `{{{}}}` is not registered", name))?;
+
+                    self.source.push_str(&::std::format!(
+                        "(?P<{}>{})",
+                        Self::group_name(self.parameters.len()),
+                        parameter_type.regex
+                    ));
+                    self.parameters.push(parameter_type);
+                },
+
+                '(' => {
+                    let optional = take_until(&mut chars, ')').ok_or("`(` is never closed by `)`")?;
+
+                    if optional.contains('{') {
+                        return Err(::std::format!("parameters cannot be optional, as in `({})`", optional));
+                    }
+
+                    self.source.push_str(&::std::format!("(?:{})?", ::regex::escape(&optional)));
+                },
+
+                char => self.source.push_str(&::regex::escape(char.encode_utf8(&mut [0; 4]))),
+            }
+        }
+
+        Ok(())
+    }
+
+    fn group_name(index: usize) -> ::std::string::String {
+        ::std::format!("parameter{}", index)
+    }
+}
+
+/// Arguments captured from the text of a step, along with the data table or
+/// doc string under it.
+pub(crate) struct Captures {
+    text: ::std::string::String,
+    arguments: ::std::vec::Vec<::core::option::Option<(::core::ops::Range<usize>, ::std::string::String)>>,
+    data: ::core::option::Option<StepArgument>,
+}
+
+impl Captures {
+    pub(crate) fn with_data(self, data: ::core::option::Option<StepArgument>) -> Self {
+        Self {
+            data,
+            ..self
+        }
+    }
+
+    /// Converts the `index`-th captured argument, highlighting it within the
+    /// step text if conversion fails, and moves `index` past it. Arguments
+    /// that are not captured are taken from under the step instead.
+    pub(crate) fn get<T>(&self, index: &mut usize) -> Fallible<T>
+    where
+        T: FromStepArgument,
+    {
+        if !T::IS_CAPTURED {
+            return T::from_step_data(self.data.as_ref());
+        }
+
+        let index = ::core::mem::replace(index, *index + 1);
+
+        let Some(Some((range, argument))) = self.arguments.get(index) else {
+            return Err(Failed::untraced(
+                ::std::format!("Argument #{} was not captured from `{}`", index + 1, self.text),
+                FailedKind::Failed,
+            ));
+        };
+
+        T::from_step_argument(argument).map_err(|failed| {
+            let offset = self.text[..range.start].chars().count();
+            let width = self.text[range.clone()].chars().count().max(1);
+
+            Failed {
+                message: ::std::format!(
+                    "{}\n  {}\n  {}{}",
+                    failed.message,
+                    self.text,
+                    " ".repeat(offset),
+                    "^".repeat(width)
+                )
+                .into(),
+                ..failed
+            }
+        })
+    }
+}
+
+fn split_alternatives(word: &str) -> ::std::vec::Vec<::std::string::String> {
+    let mut alternatives = ::std::vec::Vec::new();
+    let mut alternative = ::std::string::String::new();
+    let mut chars = word.chars();
+
+    while let Some(char) = chars.next() {
+        match char {
+            '\\' => {
+                alternative.push('\\');
+                alternative.extend(chars.next());
+            },
+            '/' => alternatives.push(::core::mem::take(&mut alternative)),
+            char => alternative.push(char),
+        }
+    }
+
+    alternatives.push(alternative);
+    alternatives
+}
+
+fn take_until(chars: &mut ::core::str::Chars<'_>, delimiter: char) -> ::core::option::Option<::std::string::String> {
+    let mut taken = ::std::string::String::new();
+
+    for char in chars.by_ref() {
+        if char == delimiter {
+            return Some(taken);
+        }
+
+        taken.push(char);
+    }
+
+    None
+}
+
+fn unquote(quoted: &str) -> ::std::string::String {
+    let mut chars = quoted.chars();
+    let quote = chars.next();
+    chars.next_back();
+
+    let mut unquoted = ::std::string::String::new();
+
+    while let Some(char) = chars.next() {
+        match (char, chars.clone().next()) {
+            ('\\', Some(next)) if Some(next) == quote => {
+                unquoted.push(next);
+                chars.next();
+            },
+            (char, _) => unquoted.push(char),
+        }
+    }
+
+    unquoted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn compiled(pattern: impl Into<StepPattern>) -> Result<StepMatcher, ::std::string::String> {
+        let mut parameter_types = ParameterType::defaults();
+        parameter_types.push(ParameterType::new("color", "red|green|blue"));
+
+        StepMatcher::compile(&pattern.into(), &parameter_types)
+    }
+
+    fn arguments(pattern: &str, text: &str) -> ::core::option::Option<::std::vec::Vec<::std::string::String>> {
+        let matcher = compiled(pattern.to_owned()).unwrap_or_else(|err| ::core::panic!("`{}`: {}", pattern, err));
+
+        Some(
+            matcher
+                .captures(text)?
+                .arguments
+                .into_iter()
+                .map(|argument| argument.map(|(_, argument)| argument).unwrap_or_default())
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn parameter_types_capture_their_arguments() {
+        let cases: &[(&str, &str, ::core::option::Option<&[&str]>)] = &[
+            ("{int} cucumbers", "12 cucumbers", Some(&["12"])),
+            ("{int} cucumbers", "-3 cucumbers", Some(&["-3"])),
+            ("{int} cucumbers", "many cucumbers", None),
+            ("{float} kg", "1.5 kg", Some(&["1.5"])),
+            ("{float} kg", ".5 kg", Some(&[".5"])),
+            ("{double} kg", "2e3 kg", Some(&["2e3"])),
+            ("{word} is hungry", "Ada is hungry", Some(&["Ada"])),
+            ("{word} is hungry", "Ada Lovelace is hungry", None),
+            ("{string} is said", r#""hi there" is said"#, Some(&["hi there"])),
+            ("{string} is said", r"'it\'s' is said", Some(&["it's"])),
+            ("{string} is said", r#""say \"hi\"" is said"#, Some(&[r#"say "hi""#])),
+            ("{} happens", "anything at all happens", Some(&["anything at all"])),
+            ("a {color} ball", "a green ball", Some(&["green"])),
+            ("a {color} ball", "a purple ball", None),
+            ("{word} has {int} {word}", "Ada has 3 cats", Some(&["Ada", "3", "cats"])),
+        ];
+
+        for &(pattern, text, expected) in cases {
+            let expected = expected.map(|expected| expected.iter().map(|&argument| argument.to_owned()).collect());
+            ::core::assert_eq!(arguments(pattern, text), expected, "`{}` against `{}`", pattern, text);
+        }
+    }
+
+    #[test]
+    fn alternations_optionals_and_escapes_match_literally() {
+        let cases: &[(&str, &str, bool)] = &[
+            ("I have {int} cucumber(s)", "I have 1 cucumber", true),
+            ("I have {int} cucumber(s)", "I have 2 cucumbers", true),
+            ("I have {int} cucumber(s)", "I have 2 cucumberss", false),
+            ("I eat/drink it", "I eat it", true),
+            ("I eat/drink it", "I drink it", true),
+            ("I eat/drink it", "I eat/drink it", false),
+            ("it is red/green/blue", "it is green", true),
+            ("it is red/green/blue", "it is purple", false),
+            ("the color(s)/colour(s) match", "the colours match", true),
+            ("a \\{int} literal", "a {int} literal", true),
+            ("a \\{int} literal", "a 3 literal", false),
+            ("a \\(b) c", "a (b) c", true),
+            ("a \\(b) c", "a  c", false),
+            ("either/or\\/both", "or/both", true),
+            ("1 + 1 = 2.", "1 + 1 = 2.", true),
+            ("1 + 1 = 2.", "1 + 1 = 2!", false),
+            ("^I have (\\d+) cucumbers?$", "I have 4 cucumbers", true),
+        ];
+
+        for &(pattern, text, matches) in cases {
+            ::core::assert_eq!(arguments(pattern, text).is_some(), matches, "`{}` against `{}`", pattern, text);
+        }
+    }
+
+    #[test]
+    fn regexes_capture_each_of_their_groups() {
+        ::core::assert_eq!(
+            arguments("^(\\w+) owes (\\d+)(?: euros)?$", "Ada owes 20 euros"),
+            Some(::std::vec!["Ada".to_owned(), "20".to_owned()])
+        );
+        ::core::assert_eq!(arguments("^(\\w+) owes (\\d+)(?: euros)?$", "Ada owes twenty"), None);
+    }
+
+    #[test]
+    fn malformed_expressions_are_rejected() {
+        let cases = [
+            ("{nope} things", "parameter type `{nope}` is not registered"),
+            ("{int things", "`{` is never closed by `}`"),
+            ("some (thing", "`(` is never closed by `)`"),
+            ("({int}) things", "parameters cannot be optional, as in `({int})`"),
+            ("a/{int} thing", "parameter `{int}` cannot be used within an alternation"),
+        ];
+
+        for (pattern, message) in cases {
+            ::core::assert_eq!(compiled(pattern).err().as_deref(), Some(message), "`{}`", pattern);
+        }
+
+        ::core::assert!(compiled("^unclosed ($").is_err());
+    }
+
+    #[test]
+    fn custom_parameter_types_shadow_the_defaults() {
+        let mut parameter_types = ParameterType::defaults();
+        parameter_types.push(ParameterType::new("int", "[0-9]+|one|two"));
+
+        let matcher = StepMatcher::compile(&"{int} cucumbers".into(), &parameter_types).unwrap();
+
+        ::core::assert!(matcher.captures("two cucumbers").is_some());
+        ::core::assert!(matcher.captures("-3 cucumbers").is_none());
+    }
+
+    #[test]
+    fn failed_conversions_point_at_the_offending_argument() {
+        let cases = [
+            ("{word} {int} byte", "an 300 byte", "  an 300 byte\n     ^^^"),
+            ("é {word} is {int}", "é ça is -1", "  é ça is -1\n          ^^"),
+        ];
+
+        for (pattern, text, highlight) in cases {
+            let captures = compiled(pattern).unwrap().captures(text).unwrap();
+            let mut index = 0;
+
+            let failed = captures
+                .get::<::std::string::String>(&mut index)
+                .and_then(|_| captures.get::<u8>(&mut index))
+                .map(|_| ())
+                .unwrap_err();
+
+            ::core::assert!(failed.message.ends_with(highlight), "`{}`:\n{}", text, failed.message);
+        }
+    }
+
+    #[test]
+    fn step_data_is_taken_from_under_the_step() {
+        let captures = compiled("a table")
+            .unwrap()
+            .captures("a table")
+            .unwrap()
+            .with_data(Some(StepArgument::DataTable(DataTable::new([::std::vec!["a".to_owned()]]))));
+        let mut index = 0;
+
+        ::core::assert!(captures.get::<DataTable>(&mut index).is_ok());
+        ::core::assert!(captures.get::<DocString>(&mut index).is_err());
+        ::core::assert!(captures.get::<::std::string::String>(&mut index).is_err());
+        ::core::assert_eq!(index, 1);
+    }
+}
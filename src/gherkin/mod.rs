@@ -0,0 +1,8 @@
+mod ast;
+mod matchers;
+mod parser;
+mod registry;
+
+pub use self::matchers::*;
+pub use self::parser::Error;
+pub use self::registry::*;
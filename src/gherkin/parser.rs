@@ -0,0 +1,770 @@
+use crate::gherkin::ast;
+
+#[derive(::core::fmt::Debug)]
+pub enum Error {
+    Io {
+        path: ::std::path::PathBuf,
+        source: ::std::io::Error,
+    },
+
+    Syntax {
+        path: ::core::option::Option<::std::path::PathBuf>,
+        line: usize,
+        message: ::std::string::String,
+    },
+
+    Pattern {
+        pattern: ::std::string::String,
+        message: ::std::string::String,
+    },
+}
+
+impl Error {
+    pub(crate) fn with_path(self, path: impl Into<::std::path::PathBuf>) -> Self {
+        match self {
+            Self::Syntax {
+                line,
+                message,
+                ..
+            } => Self::Syntax {
+                path: ::core::option::Option::from(path.into()),
+                line,
+                message,
+            },
+            err => err,
+        }
+    }
+}
+
+impl ::core::fmt::Display for Error {
+    fn fmt(&self, formatter: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self {
+            Self::Io {
+                path,
+                source,
+            } => ::core::write!(formatter, "failed to read `{}`: {}", path.display(), source),
+            Self::Syntax {
+                path: Some(path),
+                line,
+                message,
+            } => ::core::write!(formatter, "{}:{}: {}", path.display(), line, message),
+            Self::Syntax {
+                path: None,
+                line,
+                message,
+            } => ::core::write!(formatter, "line {}: {}", line, message),
+            Self::Pattern {
+                pattern,
+                message,
+            } => ::core::write!(formatter, "invalid step pattern `{}`: {}", pattern, message),
+        }
+    }
+}
+
+impl ::std::error::Error for Error {
+    fn source(&self) -> ::core::option::Option<&(dyn ::std::error::Error + 'static)> {
+        match self {
+            Self::Io {
+                source,
+                ..
+            } => Some(source),
+            Self::Syntax {
+                ..
+            }
+            | Self::Pattern {
+                ..
+            } => None,
+        }
+    }
+}
+
+pub(crate) fn parse(source: &str) -> Result<ast::Feature, Error> {
+    Parser {
+        lines: source.lines().collect(),
+        cursor: 0,
+    }
+    .parse_feature()
+}
+
+enum Line<'a> {
+    Tags(::std::vec::Vec<::std::string::String>),
+
+    Feature(&'a str),
+    Rule(&'a str),
+    Background,
+    Scenario(&'a str),
+    Examples(&'a str),
+
+    Step(ast::Keyword, &'a str),
+    DocStringSeparator(&'static str, &'a str),
+    TableRow(&'a str),
+
+    Text(&'a str),
+}
+
+impl Line<'_> {
+    fn describe(&self) -> ::std::string::String {
+        match self {
+            Self::Tags(_) => "tags".into(),
+
+            Self::Feature(_) => "`Feature:`".into(),
+            Self::Rule(_) => "`Rule:`".into(),
+            Self::Background => "`Background:`".into(),
+            Self::Scenario(_) => "`Scenario:`".into(),
+            Self::Examples(_) => "`Examples:`".into(),
+
+            Self::Step(_, text) => ::std::format!("step `{}`", text),
+            Self::DocStringSeparator(delimiter, _) => ::std::format!("doc string delimiter `{}`", delimiter),
+            Self::TableRow(_) => "table row".into(),
+
+            Self::Text(text) => ::std::format!("text `{}`", text),
+        }
+    }
+}
+
+type IntoLine<'a> = fn(&'a str) -> Line<'a>;
+
+const KEYWORDS: [(&str, ast::Keyword); 6] = [
+    ("Given ", ast::Keyword::Given),
+    ("When ", ast::Keyword::When),
+    ("Then ", ast::Keyword::Then),
+    ("And ", ast::Keyword::And),
+    ("But ", ast::Keyword::But),
+    ("* ", ast::Keyword::Asterisk),
+];
+
+const DOC_STRING_DELIMITERS: [&str; 2] = ["\"\"\"", "```"];
+
+fn classify<'a>(line: &'a str, number: usize) -> Result<::core::option::Option<Line<'a>>, Error> {
+    let headers: [(&str, IntoLine<'a>); 9] = [
+        ("Feature:", Line::Feature),
+        ("Rule:", Line::Rule),
+        ("Background:", |_| Line::Background),
+        ("Scenario Outline:", Line::Scenario),
+        ("Scenario Template:", Line::Scenario),
+        ("Scenario:", Line::Scenario),
+        ("Example:", Line::Scenario),
+        ("Examples:", Line::Examples),
+        ("Scenarios:", Line::Examples),
+    ];
+
+    let line = line.trim();
+
+    if line.is_empty() || line.starts_with('#') {
+        return Ok(None);
+    }
+
+    if line.starts_with('@') {
+        let tags = line
+            .split_whitespace()
+            .take_while(|token| !token.starts_with('#'))
+            .map(|token| match token.starts_with('@') && token.len() > 1 {
+                true => Ok(token.to_owned()),
+                false => Err(syntax(number, ::std::format!("invalid tag `{}`, tags must start with `@`", token))),
+            })
+            .collect::<Result<_, _>>()?;
+
+        return Ok(Some(Line::Tags(tags)));
+    }
+
+    if let Some((into_line, name)) =
+        headers.iter().find_map(|(header, into_line)| line.strip_prefix(header).map(|name| (into_line, name)))
+    {
+        return Ok(Some(into_line(name.trim())));
+    }
+
+    if let Some((keyword, text)) =
+        KEYWORDS.iter().find_map(|(prefix, keyword)| line.strip_prefix(prefix).map(|text| (*keyword, text)))
+    {
+        return Ok(Some(Line::Step(keyword, text.trim())));
+    }
+
+    if let Some((delimiter, media_type)) = DOC_STRING_DELIMITERS
+        .iter()
+        .find_map(|delimiter| line.strip_prefix(delimiter).map(|media_type| (*delimiter, media_type)))
+    {
+        return Ok(Some(Line::DocStringSeparator(delimiter, media_type.trim())));
+    }
+
+    if line.starts_with('|') {
+        return Ok(Some(Line::TableRow(line)));
+    }
+
+    Ok(Some(Line::Text(line)))
+}
+
+fn syntax(line: usize, message: impl Into<::std::string::String>) -> Error {
+    Error::Syntax {
+        path: None,
+        line,
+        message: message.into(),
+    }
+}
+
+struct Parser<'a> {
+    lines: ::std::vec::Vec<&'a str>,
+    cursor: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn parse_feature(mut self) -> Result<ast::Feature, Error> {
+        let tags = self.parse_tags()?;
+
+        let name = match self.peek()? {
+            Some((index, Line::Feature(name))) => {
+                self.bump(index);
+                name.to_owned()
+            },
+            Some((index, line)) =>
+                return Err(syntax(index + 1, ::std::format!("expected `Feature:`, found {}", line.describe()))),
+            None => return Err(syntax(self.lines.len().max(1), "expected `Feature:`, found end of file")),
+        };
+
+        let description = self.parse_description()?;
+
+        let mut feature = ast::Feature {
+            name,
+            description,
+            tags,
+
+            background: self.parse_background()?,
+            scenarios: ::core::default::Default::default(),
+            rules: ::core::default::Default::default(),
+        };
+
+        loop {
+            match self.peek_past_tags()? {
+                Some((_, Line::Scenario(_))) => {
+                    let scenario = self.parse_scenario()?;
+                    feature.scenarios.push(scenario);
+                },
+                Some((_, Line::Rule(_))) => {
+                    let rule = self.parse_rule()?;
+                    feature.rules.push(rule);
+                },
+                Some((index, line)) => return Err(syntax(index + 1, ::std::format!("unexpected {}", line.describe()))),
+                None => break,
+            }
+        }
+
+        match self.peek()? {
+            Some((index, _)) => Err(syntax(index + 1, "tags must be followed by `Scenario:`, `Examples:` or `Rule:`")),
+            None => Ok(feature),
+        }
+    }
+
+    fn parse_rule(&mut self) -> Result<ast::Rule, Error> {
+        let tags = self.parse_tags()?;
+
+        let name = match self.peek()? {
+            Some((index, Line::Rule(name))) => {
+                self.bump(index);
+                name.to_owned()
+            },
+            _ => ::core::unreachable!(),
+        };
+
+        let description = self.parse_description()?;
+
+        let mut rule = ast::Rule {
+            name,
+            description,
+            tags,
+
+            background: self.parse_background()?,
+            scenarios: ::core::default::Default::default(),
+        };
+
+        while let Some((_, Line::Scenario(_))) = self.peek_past_tags()? {
+            let scenario = self.parse_scenario()?;
+            rule.scenarios.push(scenario);
+        }
+
+        Ok(rule)
+    }
+
+    fn parse_background(&mut self) -> Result<::core::option::Option<ast::Background>, Error> {
+        match self.peek()? {
+            Some((index, Line::Background)) => {
+                self.bump(index);
+
+                // Nothing reports the description of a background.
+                self.parse_description()?;
+
+                Ok(Some(ast::Background {
+                    steps: self.parse_steps()?,
+                }))
+            },
+            _ => Ok(None),
+        }
+    }
+
+    fn parse_scenario(&mut self) -> Result<ast::Scenario, Error> {
+        let tags = self.parse_tags()?;
+
+        let (name, line) = match self.peek()? {
+            Some((index, Line::Scenario(name))) => {
+                self.bump(index);
+                (name.to_owned(), index + 1)
+            },
+            _ => ::core::unreachable!(),
+        };
+
+        let description = self.parse_description()?;
+
+        let steps = self.parse_steps()?;
+        let mut examples = ::std::vec::Vec::new();
+
+        while let Some((_, Line::Examples(_))) = self.peek_past_tags()? {
+            let tags = self.parse_tags()?;
+
+            let name = match self.peek()? {
+                Some((index, Line::Examples(name))) => {
+                    self.bump(index);
+                    name.to_owned()
+                },
+                _ => ::core::unreachable!(),
+            };
+
+            examples.push(ast::Examples {
+                name,
+                description: self.parse_description()?,
+                tags,
+
+                table: self.parse_table()?,
+            });
+        }
+
+        Ok(ast::Scenario {
+            name,
+            description,
+            tags,
+            line,
+
+            steps,
+            examples,
+        })
+    }
+
+    fn parse_steps(&mut self) -> Result<::std::vec::Vec<ast::Step>, Error> {
+        let mut steps = ::std::vec::Vec::new();
+
+        while let Some((index, Line::Step(keyword, text))) = self.peek()? {
+            self.bump(index);
+
+            let argument = match self.peek()? {
+                Some((index, Line::DocStringSeparator(delimiter, media_type))) => {
+                    self.bump(index);
+
+                    Some(ast::StepArgument::DocString(ast::DocString {
+                        media_type: (!media_type.is_empty()).then(|| media_type.to_owned()),
+                        content: self.parse_doc_string(index, delimiter)?,
+                    }))
+                },
+                Some((_, Line::TableRow(_))) => self.parse_table()?.map(ast::StepArgument::DataTable),
+                _ => None,
+            };
+
+            steps.push(ast::Step {
+                keyword,
+                text: text.to_owned(),
+                line: index + 1,
+
+                argument,
+            });
+        }
+
+        Ok(steps)
+    }
+
+    fn parse_doc_string(&mut self, opening: usize, delimiter: &str) -> Result<::std::string::String, Error> {
+        let indentation = self.lines[opening].len() - self.lines[opening].trim_start().len();
+        let mut content = ::std::vec::Vec::new();
+
+        loop {
+            let Some(line) = self.lines.get(self.cursor) else {
+                return Err(syntax(opening + 1, "unterminated doc string"));
+            };
+
+            self.cursor += 1;
+
+            if line.trim() == delimiter {
+                break;
+            }
+
+            // Indentation is counted in bytes, and a wide whitespace that
+            // would straddle it is kept whole.
+            let stripped = line
+                .char_indices()
+                .find(|(offset, char)| !char.is_whitespace() || offset + char.len_utf8() > indentation)
+                .map_or(line.len(), |(offset, _)| offset);
+            let escaped = delimiter.chars().flat_map(|char| ['\\', char]).collect::<::std::string::String>();
+
+            content.push(line[stripped..].replace(&escaped, delimiter));
+        }
+
+        Ok(content.join("\n"))
+    }
+
+    fn parse_table(&mut self) -> Result<::core::option::Option<ast::DataTable>, Error> {
+        let mut rows: ::std::vec::Vec<::std::vec::Vec<::std::string::String>> = ::std::vec::Vec::new();
+
+        while let Some((index, Line::TableRow(row))) = self.peek()? {
+            self.bump(index);
+
+            let cells = parse_cells(row).ok_or_else(|| syntax(index + 1, "table rows must end with `|`"))?;
+
+            if rows.first().is_some_and(|first| first.len() != cells.len()) {
+                return Err(syntax(index + 1, "inconsistent cell count within the table"));
+            }
+
+            rows.push(cells);
+        }
+
+        Ok((!rows.is_empty()).then_some(ast::DataTable {
+            rows,
+        }))
+    }
+
+    fn parse_tags(&mut self) -> Result<::std::vec::Vec<::std::string::String>, Error> {
+        let mut tags = ::std::vec::Vec::new();
+
+        while let Some((index, Line::Tags(line))) = self.peek()? {
+            self.bump(index);
+            tags.extend(line);
+        }
+
+        Ok(tags)
+    }
+
+    /// Free-form lines below a header, trimmed and joined, if there are any.
+    fn parse_description(&mut self) -> Result<::core::option::Option<::std::string::String>, Error> {
+        let mut lines = ::std::vec::Vec::new();
+
+        while let Some((index, Line::Text(text))) = self.peek()? {
+            self.bump(index);
+            lines.push(text);
+        }
+
+        Ok((!lines.is_empty()).then(|| lines.join("\n")))
+    }
+
+    /// Returns the next significant line, without consuming it.
+    fn peek(&self) -> Result<::core::option::Option<(usize, Line<'a>)>, Error> {
+        self.peek_from(self.cursor)
+    }
+
+    /// Returns the next significant line that is not a tag line, without
+    /// consuming anything.
+    fn peek_past_tags(&self) -> Result<::core::option::Option<(usize, Line<'a>)>, Error> {
+        let mut cursor = self.cursor;
+
+        loop {
+            match self.peek_from(cursor)? {
+                Some((index, Line::Tags(_))) => cursor = index + 1,
+                line => break Ok(line),
+            }
+        }
+    }
+
+    fn peek_from(&self, mut cursor: usize) -> Result<::core::option::Option<(usize, Line<'a>)>, Error> {
+        while let Some(line) = self.lines.get(cursor) {
+            if let Some(line) = classify(line, cursor + 1)? {
+                return Ok(Some((cursor, line)));
+            }
+
+            cursor += 1;
+        }
+
+        Ok(None)
+    }
+
+    fn bump(&mut self, index: usize) {
+        self.cursor = index + 1;
+    }
+}
+
+fn parse_cells(row: &str) -> ::core::option::Option<::std::vec::Vec<::std::string::String>> {
+    let mut cells = ::std::vec::Vec::new();
+    let mut cell = ::std::string::String::new();
+    let mut chars = row.strip_prefix('|')?.chars();
+
+    while let Some(char) = chars.next() {
+        match char {
+            '|' => cells.push(::core::mem::take(&mut cell).trim().to_owned()),
+            '\\' => match chars.next() {
+                Some('|') => cell.push('|'),
+                Some('n') => cell.push('\n'),
+                Some('\\') => cell.push('\\'),
+                Some(char) => {
+                    cell.push('\\');
+                    cell.push(char);
+                },
+                None => cell.push('\\'),
+            },
+            char => cell.push(char),
+        }
+    }
+
+    cell.trim().is_empty().then_some(cells)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parsed(source: &str) -> ast::Feature {
+        parse(source).unwrap_or_else(|err| ::core::panic!("{}", err))
+    }
+
+    fn texts(steps: &[ast::Step]) -> ::std::vec::Vec<&str> {
+        steps.iter().map(|step| step.text.as_str()).collect()
+    }
+
+    #[test]
+    fn features_keep_their_name_tags_and_description() {
+        let feature = parsed(::core::concat!(
+            "# a comment\n",
+            "@billing @slow\n",
+            "@nightly\n",
+            "Feature: Refunds\n",
+            "  Customers get their money back\n",
+            "  within a week.\n",
+            "\n",
+            "  Scenario: Full refund\n",
+            "    Given a purchase\n",
+        ));
+
+        ::core::assert_eq!(feature.name, "Refunds");
+        ::core::assert_eq!(feature.tags, ["@billing", "@slow", "@nightly"]);
+        ::core::assert_eq!(feature.description.as_deref(), Some("Customers get their money back\nwithin a week."));
+        ::core::assert!(feature.background.is_none());
+        ::core::assert_eq!(feature.scenarios.len(), 1);
+    }
+
+    #[test]
+    fn scenarios_keep_their_tags_description_and_steps() {
+        let feature = parsed(::core::concat!(
+            "Feature: F\n",
+            "  @smoke\n",
+            "  Scenario: S\n",
+            "    Only the happy path.\n",
+            "    Given a\n",
+            "    And b\n",
+            "    When c\n",
+            "    But d\n",
+            "    Then e\n",
+            "    * f\n",
+            "\n",
+            "  Example: T\n",
+            "    Given g\n",
+        ));
+
+        let [ref first, ref second] = feature.scenarios[..] else {
+            ::core::panic!("expected two scenarios");
+        };
+
+        ::core::assert_eq!((first.name.as_str(), first.line), ("S", 3));
+        ::core::assert_eq!(first.tags, ["@smoke"]);
+        ::core::assert_eq!(first.description.as_deref(), Some("Only the happy path."));
+        ::core::assert_eq!(texts(&first.steps), ["a", "b", "c", "d", "e", "f"]);
+        ::core::assert!(first.steps.iter().map(|step| step.keyword).eq([
+            ast::Keyword::Given,
+            ast::Keyword::And,
+            ast::Keyword::When,
+            ast::Keyword::But,
+            ast::Keyword::Then,
+            ast::Keyword::Asterisk,
+        ]));
+        ::core::assert_eq!(first.steps[0].line, 5);
+
+        ::core::assert_eq!(second.name, "T");
+        ::core::assert!(second.tags.is_empty() && second.description.is_none());
+    }
+
+    #[test]
+    fn backgrounds_and_rules_are_nested_under_their_feature() {
+        let feature = parsed(::core::concat!(
+            "Feature: F\n",
+            "  Background:\n",
+            "    Given the feature background\n",
+            "\n",
+            "  Scenario: Outside any rule\n",
+            "    Given a\n",
+            "\n",
+            "  @fast\n",
+            "  Rule: R\n",
+            "    Described.\n",
+            "\n",
+            "    Background:\n",
+            "      Not reported.\n",
+            "      Given the rule background\n",
+            "\n",
+            "    Scenario: Within the rule\n",
+            "      Given b\n",
+            "\n",
+            "  Rule: Q\n",
+            "    Scenario: Within another rule\n",
+            "      Given c\n",
+        ));
+
+        let Some(ref background) = feature.background else {
+            ::core::panic!("expected a background");
+        };
+
+        ::core::assert_eq!(texts(&background.steps), ["the feature background"]);
+        ::core::assert_eq!(feature.scenarios.len(), 1);
+
+        let [ref rule, ref other] = feature.rules[..] else {
+            ::core::panic!("expected two rules");
+        };
+
+        ::core::assert_eq!(rule.name, "R");
+        ::core::assert_eq!(rule.tags, ["@fast"]);
+        ::core::assert_eq!(rule.description.as_deref(), Some("Described."));
+        ::core::assert_eq!(
+            rule.background.as_ref().map(|background| texts(&background.steps)),
+            Some(::std::vec!["the rule background"])
+        );
+        ::core::assert_eq!(rule.scenarios[0].name, "Within the rule");
+
+        ::core::assert_eq!(other.name, "Q");
+        ::core::assert!(other.tags.is_empty() && other.background.is_none());
+        ::core::assert_eq!(other.scenarios[0].name, "Within another rule");
+    }
+
+    #[test]
+    fn outlines_keep_each_examples_block() {
+        let feature = parsed(::core::concat!(
+            "Feature: F\n",
+            "  Scenario Outline: Eating <eaten>\n",
+            "    Given there are <start> cucumbers\n",
+            "\n",
+            "    @small\n",
+            "    Examples: Few\n",
+            "      Nothing left over.\n",
+            "      | start | eaten |\n",
+            "      |     5 |     5 |\n",
+            "\n",
+            "    Scenarios:\n",
+            "      | start | eaten |\n",
+            "      |    12 |     5 |\n",
+            "      |    20 |     5 |\n",
+        ));
+
+        let outline = &feature.scenarios[0];
+
+        ::core::assert_eq!(outline.name, "Eating <eaten>");
+        ::core::assert_eq!(texts(&outline.steps), ["there are <start> cucumbers"]);
+
+        let [ref few, ref many] = outline.examples[..] else {
+            ::core::panic!("expected two examples blocks");
+        };
+
+        ::core::assert_eq!(few.name, "Few");
+        ::core::assert_eq!(few.tags, ["@small"]);
+        ::core::assert_eq!(few.description.as_deref(), Some("Nothing left over."));
+        ::core::assert_eq!(few.table.as_ref().map(|table| table.rows.len()), Some(2));
+
+        ::core::assert!(many.name.is_empty() && many.tags.is_empty() && many.description.is_none());
+        let Some(ref table) = many.table else {
+            ::core::panic!("expected a table");
+        };
+
+        ::core::assert_eq!(table.rows, [["start", "eaten"], ["12", "5"], ["20", "5"]]);
+    }
+
+    #[test]
+    fn data_tables_trim_and_unescape_their_cells() {
+        let feature = parsed(::core::concat!(
+            "Feature: F\n",
+            "  Scenario: S\n",
+            "    Given a table\n",
+            "      | name  | note       |\n",
+            "      | a\\|b  | one\\ntwo   |\n",
+            "      | c\\\\d  | \\x |\n",
+            "      |       |            |\n",
+            "    When the step after it\n",
+        ));
+
+        let steps = &feature.scenarios[0].steps;
+
+        let Some(ast::StepArgument::DataTable(ref table)) = steps[0].argument else {
+            ::core::panic!("expected a data table");
+        };
+
+        ::core::assert_eq!(table.rows, [["name", "note"], ["a|b", "one\ntwo"], ["c\\d", "\\x"], ["", ""],]);
+        ::core::assert_eq!(texts(steps), ["a table", "the step after it"]);
+    }
+
+    #[test]
+    fn doc_strings_keep_their_media_type_and_escaped_delimiters() {
+        let feature = parsed(::core::concat!(
+            "Feature: F\n",
+            "  Scenario: S\n",
+            "    Given a doc string\n",
+            "      ```json\n",
+            "      {\n",
+            "        \"a\": \"\\`\\`\\`\"\n",
+            "      }\n",
+            "      ```\n",
+        ));
+
+        let Some(ast::StepArgument::DocString(ref doc_string)) = feature.scenarios[0].steps[0].argument else {
+            ::core::panic!("expected a doc string");
+        };
+
+        ::core::assert_eq!(doc_string.media_type.as_deref(), Some("json"));
+        ::core::assert_eq!(doc_string.content, "{\n  \"a\": \"```\"\n}");
+    }
+
+    #[test]
+    fn malformed_sources_are_rejected_with_their_line() {
+        let cases = [
+            ("", 1, "expected `Feature:`, found end of file"),
+            ("Scenario: S\n", 1, "expected `Feature:`, found `Scenario:`"),
+            ("Feature: F\n  @ok smoke\n  Scenario: S\n", 2, "invalid tag `smoke`, tags must start with `@`"),
+            ("Feature: F\n  Scenario: S\n    Given a\n      | a | b |\n      | c |\n", 5, "inconsistent cell count"),
+            ("Feature: F\n  Scenario: S\n    Given a\n      | a | b\n", 4, "table rows must end with `|`"),
+            ("Feature: F\n  Scenario: S\n    Given a\n      \"\"\"\n      text\n", 4, "unterminated doc string"),
+            ("Feature: F\n  Scenario: S\n  @dangling\n", 3, "tags must be followed by"),
+            ("Feature: F\n  Scenario: S\n    Given a\n  Feature: G\n", 4, "unexpected `Feature:`"),
+        ];
+
+        for (source, expected_line, expected_message) in cases {
+            match parse(source) {
+                Err(Error::Syntax {
+                    line,
+                    message,
+                    ..
+                }) => {
+                    ::core::assert_eq!(line, expected_line, "{:?}", source);
+                    ::core::assert!(message.starts_with(expected_message), "{:?}: {}", source, message);
+                },
+                Err(err) => ::core::panic!("{:?}: unexpected error {}", source, err),
+                Ok(_) => ::core::panic!("{:?}: parsed", source),
+            }
+        }
+    }
+
+    #[test]
+    fn doc_strings_strip_indentation_without_splitting_chars() {
+        let source = ::core::concat!(
+            "Feature: F\n",
+            "  Scenario: S\n",
+            "    Given a doc string\n",
+            "      \"\"\"\n",
+            "\u{3000}wide\n",
+            "     \u{3000}straddling\n",
+            "      \"\"\"\n",
+        );
+
+        let feature = parse(source).unwrap_or_else(|err| ::core::panic!("{}", err));
+
+        let Some(ast::StepArgument::DocString(ref doc_string)) = feature.scenarios[0].steps[0].argument else {
+            ::core::panic!("expected a doc string");
+        };
+
+        ::core::assert_eq!(doc_string.content, "wide\n\u{3000}straddling");
+    }
+}
@@ -0,0 +1,851 @@
+use ::sealed::sealed;
+
+use crate::arguments::*;
+use crate::builders::*;
+use crate::gherkin::ast;
+use crate::gherkin::matchers::*;
+use crate::gherkin::parser;
+use crate::models::*;
+use crate::utils::aliases;
+
+/// Step definitions that `.feature` files are lowered against.
+///
+/// `Given` and `When` steps are matched against `given` and `when` definitions
+/// respectively, `Then` steps against `then` definitions, and `And`, `But` and
+/// `*` steps against the definitions of the step that precedes them.
+///
+/// Each argument of a definition's callback after the world is converted from
+/// the corresponding parameter of its [`StepPattern`] through
+/// [`FromStepArgument`].
+pub struct StepRegistry<World> {
+    parameter_types: ::std::vec::Vec<ParameterType>,
+
+    given: ::std::vec::Vec<GivenOrWhenStepDefinition<World>>,
+    when: ::std::vec::Vec<GivenOrWhenStepDefinition<World>>,
+    then: ::std::vec::Vec<ThenStepDefinition<World>>,
+}
+
+struct StepDefinition<Callback> {
+    pattern: StepPattern,
+    arity: usize,
+    callback: Callback,
+}
+
+type GivenOrWhenStepDefinition<World> = StepDefinition<GivenOrWhenCallback<World>>;
+type ThenStepDefinition<World> = StepDefinition<ThenCallback<World>>;
+
+pub(crate) type GivenOrWhenCallback<World> =
+    aliases::sync::Arc<dyn Fn(&mut World, &Captures) -> Fallible + ::core::marker::Send + ::core::marker::Sync>;
+pub(crate) type ThenCallback<World> =
+    aliases::sync::Arc<dyn Fn(&World, &Captures) -> Fallible + ::core::marker::Send + ::core::marker::Sync>;
+type BackgroundCallback<World> =
+    aliases::sync::Arc<dyn Fn(&mut World) -> Fallible + ::core::marker::Send + ::core::marker::Sync>;
+
+impl<World> StepRegistry<World> {
+    pub fn new() -> Self {
+        Self {
+            parameter_types: ParameterType::defaults(),
+
+            given: ::core::default::Default::default(),
+            when: ::core::default::Default::default(),
+            then: ::core::default::Default::default(),
+        }
+    }
+
+    /// Registers a custom parameter type, so that Cucumber Expressions may
+    /// refer to text matching `regex` as `{name}`.
+    ///
+    /// Registering a parameter type under an existing name shadows it.
+    pub fn parameter_type(
+        mut self,
+        name: impl Into<aliases::string::String>,
+        regex: impl Into<aliases::string::String>,
+    ) -> Self {
+        self.parameter_types.push(ParameterType::new(name, regex));
+        self
+    }
+
+    pub fn given<Arguments>(
+        mut self,
+        pattern: impl Into<StepPattern>,
+        callback: impl IntoGivenOrWhenStepCallback<World, Arguments>,
+    ) -> Self {
+        self.given.push(StepDefinition {
+            pattern: pattern.into(),
+            arity: callback.arity(),
+            callback: callback.into_callback(),
+        });
+
+        self
+    }
+
+    pub fn when<Arguments>(
+        mut self,
+        pattern: impl Into<StepPattern>,
+        callback: impl IntoGivenOrWhenStepCallback<World, Arguments>,
+    ) -> Self {
+        self.when.push(StepDefinition {
+            pattern: pattern.into(),
+            arity: callback.arity(),
+            callback: callback.into_callback(),
+        });
+
+        self
+    }
+
+    pub fn then<Arguments>(
+        mut self,
+        pattern: impl Into<StepPattern>,
+        callback: impl IntoThenStepCallback<World, Arguments>,
+    ) -> Self {
+        self.then.push(StepDefinition {
+            pattern: pattern.into(),
+            arity: callback.arity(),
+            callback: callback.into_callback(),
+        });
+
+        self
+    }
+}
+
+impl<World> ::core::default::Default for StepRegistry<World> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<World> StepRegistry<World>
+where
+    World: 'static,
+{
+    /// Reads and parses the `.feature` file at `path`.
+    pub fn load(&self, path: impl AsRef<::std::path::Path>) -> Result<Feature<World>, parser::Error> {
+        let path = path.as_ref();
+
+        let source = ::std::fs::read_to_string(path).map_err(|source| parser::Error::Io {
+            path: path.to_owned(),
+            source,
+        })?;
+
+        let feature = parser::parse(&source).map_err(|err| err.with_path(path))?;
+
+        Ok(Lowering::new(self, Some(&path.display().to_string()))?.lower_feature(feature))
+    }
+
+    /// Parses `source` as the contents of a `.feature` file.
+    pub fn parse(&self, source: &str) -> Result<Feature<World>, parser::Error> {
+        let feature = parser::parse(source)?;
+
+        Ok(Lowering::new(self, None)?.lower_feature(feature))
+    }
+}
+
+#[sealed]
+pub trait IntoGivenOrWhenStepCallback<World, Arguments> {
+    #[doc(hidden)]
+    fn arity(&self) -> usize;
+
+    #[allow(private_interfaces)]
+    fn into_callback(self) -> GivenOrWhenCallback<World>;
+}
+
+#[sealed]
+pub trait IntoThenStepCallback<World, Arguments> {
+    #[doc(hidden)]
+    fn arity(&self) -> usize;
+
+    #[allow(private_interfaces)]
+    fn into_callback(self) -> ThenCallback<World>;
+}
+
+macro_rules! impl_into_step_callbacks {
+    ($($argument:ident),*) => {
+        #[sealed]
+        impl<World, Callback, Output, $($argument,)*> IntoGivenOrWhenStepCallback<World, ($($argument,)*)> for Callback
+        where
+            Callback: Fn(&mut World, $($argument),*) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+            Output: IntoFallible,
+            $($argument: FromStepArgument,)*
+        {
+            fn arity(&self) -> usize {
+                0 $(+ <$argument as FromStepArgument>::IS_CAPTURED as usize)*
+            }
+
+            #[allow(private_interfaces)]
+            fn into_callback(self) -> GivenOrWhenCallback<World> {
+                #[allow(unused_variables, unused_mut)]
+                let callback = move |world: &mut World, captures: &Captures| {
+                    let mut index = 0;
+                    (self)(world, $(captures.get::<$argument>(&mut index)?),*).into_fallible()
+                };
+
+                aliases::sync::Arc::new(callback)
+            }
+        }
+
+        #[sealed]
+        impl<World, Callback, Output, $($argument,)*> IntoThenStepCallback<World, ($($argument,)*)> for Callback
+        where
+            Callback: Fn(&World, $($argument),*) -> Output + ::core::marker::Send + ::core::marker::Sync + 'static,
+            Output: IntoFallible,
+            $($argument: FromStepArgument,)*
+        {
+            fn arity(&self) -> usize {
+                0 $(+ <$argument as FromStepArgument>::IS_CAPTURED as usize)*
+            }
+
+            #[allow(private_interfaces)]
+            fn into_callback(self) -> ThenCallback<World> {
+                #[allow(unused_variables, unused_mut)]
+                let callback = move |world: &World, captures: &Captures| {
+                    let mut index = 0;
+                    (self)(world, $(captures.get::<$argument>(&mut index)?),*).into_fallible()
+                };
+
+                aliases::sync::Arc::new(callback)
+            }
+        }
+    };
+}
+
+impl_into_step_callbacks!();
+impl_into_step_callbacks!(A0);
+impl_into_step_callbacks!(A0, A1);
+impl_into_step_callbacks!(A0, A1, A2);
+impl_into_step_callbacks!(A0, A1, A2, A3);
+impl_into_step_callbacks!(A0, A1, A2, A3, A4);
+impl_into_step_callbacks!(A0, A1, A2, A3, A4, A5);
+impl_into_step_callbacks!(A0, A1, A2, A3, A4, A5, A6);
+impl_into_step_callbacks!(A0, A1, A2, A3, A4, A5, A6, A7);
+
+/// Lowers a parsed `.feature` file into models, resolving each step against the
+/// registry.
+struct Lowering<'a, World> {
+    origin: ::core::option::Option<&'a str>,
+
+    given: ::std::vec::Vec<CompiledStepDefinition<'a, GivenOrWhenCallback<World>>>,
+    when: ::std::vec::Vec<CompiledStepDefinition<'a, GivenOrWhenCallback<World>>>,
+    then: ::std::vec::Vec<CompiledStepDefinition<'a, ThenCallback<World>>>,
+}
+
+struct CompiledStepDefinition<'a, Callback> {
+    definition: &'a StepDefinition<Callback>,
+    matcher: StepMatcher,
+}
+
+impl<'a, World> Lowering<'a, World>
+where
+    World: 'static,
+{
+    fn new(registry: &'a StepRegistry<World>, origin: ::core::option::Option<&'a str>) -> Result<Self, parser::Error> {
+        Ok(Self {
+            origin,
+
+            given: compile(&registry.given, &registry.parameter_types)?,
+            when: compile(&registry.when, &registry.parameter_types)?,
+            then: compile(&registry.then, &registry.parameter_types)?,
+        })
+    }
+
+    fn lower_feature(&self, feature: ast::Feature) -> Feature<World> {
+        let tags = feature.tags;
+
+        Feature {
+            description: into_description(feature.name),
+            ignored: None,
+            tags: into_tags([tags.as_slice()]),
+
+            narrative: feature.description.map(Into::into),
+
+            background: feature.background.map(|background| self.lower_background(background)),
+            scenarios: feature
+                .scenarios
+                .into_iter()
+                .flat_map(|scenario| self.lower_scenario(scenario, &[&tags]))
+                .collect(),
+            rules: feature.rules.into_iter().map(|rule| self.lower_rule(rule, &tags)).collect(),
+        }
+    }
+
+    fn lower_rule(&self, rule: ast::Rule, feature_tags: &[::std::string::String]) -> Rule<World> {
+        let tags = rule.tags;
+
+        Rule {
+            description: into_description(rule.name),
+            ignored: None,
+            tags: into_tags([feature_tags, tags.as_slice()]),
+
+            narrative: rule.description.map(Into::into),
+
+            background: rule.background.map(|background| self.lower_background(background)),
+            scenarios: rule
+                .scenarios
+                .into_iter()
+                .flat_map(|scenario| self.lower_scenario(scenario, &[feature_tags, &tags]))
+                .collect(),
+        }
+    }
+
+    fn lower_background(&self, background: ast::Background) -> Background<World> {
+        let given = StepKind::resolve(&background.steps)
+            .enumerate()
+            .map(|(index, (kind, step))| {
+                let callback = match kind {
+                    StepKind::Given => self.resolve(&self.given, (kind, step)).map(|(callback, captures)| {
+                        aliases::sync::Arc::new(move |world: &mut World| (callback)(world, &captures))
+                            as BackgroundCallback<World>
+                    }),
+                    StepKind::When => self.resolve(&self.when, (kind, step)).map(|(callback, captures)| {
+                        aliases::sync::Arc::new(move |world: &mut World| (callback)(world, &captures))
+                            as BackgroundCallback<World>
+                    }),
+                    StepKind::Then => self.resolve(&self.then, (kind, step)).map(|(callback, captures)| {
+                        aliases::sync::Arc::new(move |world: &mut World| (callback)(world, &captures))
+                            as BackgroundCallback<World>
+                    }),
+                }
+                .unwrap_or_else(|failed| aliases::sync::Arc::new(move |_: &mut World| Err(failed.clone())));
+
+                Step {
+                    label: StepLabel::of(step, StepKind::Given, index == 0),
+                    description: step.text.clone().into(),
+                    argument: step.argument.as_ref().map(into_step_argument),
+
+                    callback,
+                }
+            })
+            .collect();
+
+        Background {
+            description: None,
+            ignored: None,
+
+            given,
+        }
+    }
+
+    fn lower_scenario(
+        &self,
+        scenario: ast::Scenario,
+        inherited_tags: &[&[::std::string::String]],
+    ) -> ::std::vec::Vec<Scenario<World>> {
+        if scenario.examples.is_empty() {
+            let tags = into_tags(inherited_tags.iter().copied().chain([scenario.tags.as_slice()]));
+            return ::std::vec![self.lower_steps(
+                into_scenario((scenario.name, scenario.description), tags, self.locate_scenario(scenario.line)),
+                &scenario.steps
+            )];
+        }
+
+        scenario
+            .examples
+            .iter()
+            .filter_map(|examples| Some((examples, examples.table.as_ref()?.rows.split_first()?)))
+            .flat_map(|(examples, (header, rows))| {
+                rows.iter().enumerate().map(|(index, row)| {
+                    let substitute = |text: &str| {
+                        header.iter().zip(row).fold(text.to_owned(), |text, (name, value)| {
+                            text.replace(&::std::format!("<{}>", name), value)
+                        })
+                    };
+
+                    let name = match examples.name.is_empty() {
+                        true => ::std::format!("{} — Examples #{}", substitute(&scenario.name), index + 1),
+                        false => ::std::format!(
+                            "{} — Examples: {} #{}",
+                            substitute(&scenario.name),
+                            examples.name,
+                            index + 1
+                        ),
+                    };
+
+                    let steps = scenario
+                        .steps
+                        .iter()
+                        .map(|step| ast::Step {
+                            text: substitute(&step.text),
+                            argument: step.argument.as_ref().map(|argument| match argument {
+                                ast::StepArgument::DocString(doc_string) =>
+                                    ast::StepArgument::DocString(ast::DocString {
+                                        media_type: doc_string.media_type.clone(),
+                                        content: substitute(&doc_string.content),
+                                    }),
+                                ast::StepArgument::DataTable(data_table) =>
+                                    ast::StepArgument::DataTable(ast::DataTable {
+                                        rows: data_table
+                                            .rows
+                                            .iter()
+                                            .map(|row| row.iter().map(|cell| substitute(cell)).collect())
+                                            .collect(),
+                                    }),
+                            }),
+                            ..step.clone()
+                        })
+                        .collect::<::std::vec::Vec<_>>();
+
+                    let tags = into_tags(
+                        inherited_tags.iter().copied().chain([scenario.tags.as_slice(), examples.tags.as_slice()]),
+                    );
+
+                    let narrative = match (&scenario.description, &examples.description) {
+                        (Some(scenario), Some(examples)) => Some(::std::format!("{}\n{}", scenario, examples)),
+                        (scenario, examples) => scenario.as_ref().or(examples.as_ref()).cloned(),
+                    };
+
+                    self.lower_steps(
+                        into_scenario((name, narrative), tags, self.locate_scenario(scenario.line)),
+                        &steps,
+                    )
+                })
+            })
+            .collect()
+    }
+
+    fn lower_steps(&self, mut scenario: Scenario<World>, steps: &[ast::Step]) -> Scenario<World> {
+        for (kind, step) in StepKind::resolve(steps) {
+            let current = match (scenario.then.is_empty(), scenario.when.is_empty()) {
+                (false, _) => StepKind::Then,
+                (true, false) => StepKind::When,
+                (true, true) => StepKind::Given,
+            };
+
+            if kind < current {
+                let message = ::std::format!(
+                    "`{} {}` ({}) cannot follow a `{}` step, as scenarios run their `Given`, `When` and `Then` steps \
+                     in that order",
+                    kind,
+                    step.text,
+                    self.locate(step),
+                    current
+                );
+
+                scenario.given = ::std::vec![Step {
+                    label: StepLabel::Given,
+                    description: step.text.clone().into(),
+                    argument: step.argument.as_ref().map(into_step_argument),
+
+                    callback: aliases::sync::Arc::new(move |_: &mut World| Err(Failed::untraced(
+                        message.clone(),
+                        FailedKind::Failed
+                    ))),
+                }];
+                scenario.when.clear();
+                scenario.then.clear();
+
+                break;
+            }
+
+            match kind {
+                StepKind::Given | StepKind::When => {
+                    let definitions = match kind {
+                        StepKind::Given => &self.given,
+                        _ => &self.when,
+                    };
+
+                    let callback = match self.resolve(definitions, (kind, step)) {
+                        Ok((callback, captures)) =>
+                            aliases::sync::Arc::new(move |world: &mut World| (callback)(world, &captures))
+                                as aliases::sync::Arc<
+                                    dyn Fn(&mut World) -> Fallible + ::core::marker::Send + ::core::marker::Sync,
+                                >,
+                        Err(failed) => aliases::sync::Arc::new(move |_: &mut World| Err(failed.clone())),
+                    };
+
+                    let steps = match kind {
+                        StepKind::Given => &mut scenario.given,
+                        _ => &mut scenario.when,
+                    };
+
+                    steps.push(Step {
+                        label: StepLabel::of(step, kind, steps.is_empty()),
+                        description: step.text.clone().into(),
+                        argument: step.argument.as_ref().map(into_step_argument),
+
+                        callback,
+                    });
+                },
+
+                StepKind::Then => {
+                    let callback = match self.resolve(&self.then, (kind, step)) {
+                        Ok((callback, captures)) =>
+                            aliases::sync::Arc::new(move |world: &World| (callback)(world, &captures))
+                                as aliases::sync::Arc<
+                                    dyn Fn(&World) -> Fallible + ::core::marker::Send + ::core::marker::Sync,
+                                >,
+                        Err(failed) => aliases::sync::Arc::new(move |_: &World| Err(failed.clone())),
+                    };
+
+                    scenario.then.push(Step {
+                        label: StepLabel::of(step, kind, scenario.then.is_empty()),
+                        description: step.text.clone().into(),
+                        argument: step.argument.as_ref().map(into_step_argument),
+
+                        callback,
+                    });
+                },
+            }
+        }
+
+        scenario
+    }
+
+    fn resolve<Callback>(
+        &self,
+        definitions: &[CompiledStepDefinition<'_, Callback>],
+        (kind, step): (StepKind, &ast::Step),
+    ) -> Result<(Callback, Captures), Failed>
+    where
+        Callback: ::core::clone::Clone,
+    {
+        let mut candidates = definitions
+            .iter()
+            .filter_map(|definition| Some((definition, definition.matcher.captures(&step.text)?)))
+            .collect::<::std::vec::Vec<_>>();
+
+        match candidates.len() {
+            1 => {
+                let (definition, captures) = candidates.remove(0);
+                let captures = captures.with_data(step.argument.as_ref().map(into_step_argument));
+
+                Ok((definition.definition.callback.clone(), captures))
+            },
+
+            0 => Err(Failed::untraced(
+                match definitions.is_empty() {
+                    true => ::std::format!(
+                        "Step is undefined: `{} {}` ({})\nNo `{}` definitions are registered",
+                        kind,
+                        step.text,
+                        self.locate(step),
+                        kind
+                    ),
+                    false => ::std::format!(
+                        "Step is undefined: `{} {}` ({})\nCandidate `{}` definitions:\n{}",
+                        kind,
+                        step.text,
+                        self.locate(step),
+                        kind,
+                        list(definitions.iter())
+                    ),
+                },
+                FailedKind::Undefined,
+            )),
+
+            _ => Err(Failed::untraced(
+                ::std::format!(
+                    "Step is ambiguous: `{} {}` ({})\nMatching `{}` definitions:\n{}",
+                    kind,
+                    step.text,
+                    self.locate(step),
+                    kind,
+                    list(candidates.iter().map(|(definition, _)| *definition))
+                ),
+                FailedKind::Ambiguous,
+            )),
+        }
+    }
+
+    /// Scenarios parsed from a string rather than read from a file have no
+    /// location.
+    fn locate_scenario(&self, line: usize) -> ::core::option::Option<Location> {
+        Some(Location {
+            file: self.origin?.to_owned().into(),
+            line,
+        })
+    }
+
+    fn locate(&self, step: &ast::Step) -> ::std::string::String {
+        match self.origin {
+            Some(path) => ::std::format!("{}:{}", path, step.line),
+            None => ::std::format!("line {}", step.line),
+        }
+    }
+}
+
+#[derive(
+    ::core::clone::Clone,
+    ::core::marker::Copy,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+    ::core::cmp::PartialOrd,
+    ::core::cmp::Ord
+)]
+enum StepKind {
+    Given,
+    When,
+    Then,
+}
+
+impl StepKind {
+    /// `And`, `But` and `*` steps inherit the kind of the step before them.
+    fn resolve(steps: &[ast::Step]) -> impl Iterator<Item = (Self, &ast::Step)> {
+        steps.iter().scan(Self::Given, |kind, step| {
+            *kind = match step.keyword {
+                ast::Keyword::Given => Self::Given,
+                ast::Keyword::When => Self::When,
+                ast::Keyword::Then => Self::Then,
+                _ => *kind,
+            };
+
+            Some((*kind, step))
+        })
+    }
+}
+
+impl ::core::fmt::Display for StepKind {
+    fn fmt(&self, formatter: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self {
+            Self::Given => formatter.write_str("Given"),
+            Self::When => formatter.write_str("When"),
+            Self::Then => formatter.write_str("Then"),
+        }
+    }
+}
+
+impl From<StepKind> for StepLabel {
+    fn from(kind: StepKind) -> Self {
+        match kind {
+            StepKind::Given => Self::Given,
+            StepKind::When => Self::When,
+            StepKind::Then => Self::Then,
+        }
+    }
+}
+
+impl StepLabel {
+    /// The first step of each group is labelled by its kind, so that e.g. a
+    /// leading `*` still reads `Given`.
+    fn of(step: &ast::Step, kind: StepKind, is_first: bool) -> Self {
+        match (is_first, step.keyword) {
+            (true, _) => kind.into(),
+
+            (false, ast::Keyword::Given) => Self::Given,
+            (false, ast::Keyword::When) => Self::When,
+            (false, ast::Keyword::Then) => Self::Then,
+            (false, ast::Keyword::And | ast::Keyword::Asterisk) => Self::And,
+            (false, ast::Keyword::But) => Self::But,
+        }
+    }
+}
+
+fn compile<'a, Callback>(
+    definitions: &'a [StepDefinition<Callback>],
+    parameter_types: &[ParameterType],
+) -> Result<::std::vec::Vec<CompiledStepDefinition<'a, Callback>>, parser::Error> {
+    definitions
+        .iter()
+        .map(|definition| {
+            let into_error = |message| parser::Error::Pattern {
+                pattern: definition.pattern.to_string(),
+                message,
+            };
+
+            let matcher = StepMatcher::compile(&definition.pattern, parameter_types).map_err(into_error)?;
+
+            if matcher.arity() != definition.arity {
+                return Err(into_error(::std::format!(
+                    "the pattern has {} parameter(s), but its callback takes {} argument(s) besides the world",
+                    matcher.arity(),
+                    definition.arity
+                )));
+            }
+
+            Ok(CompiledStepDefinition {
+                definition,
+                matcher,
+            })
+        })
+        .collect()
+}
+
+fn list<'a, 'b: 'a, Callback: 'b>(
+    definitions: impl Iterator<Item = &'a CompiledStepDefinition<'b, Callback>>,
+) -> ::std::string::String {
+    definitions
+        .map(|definition| ::std::format!("  - `{}`", definition.definition.pattern))
+        .collect::<::std::vec::Vec<_>>()
+        .join("\n")
+}
+
+fn into_scenario<World>(
+    (name, narrative): (::std::string::String, ::core::option::Option<::std::string::String>),
+    tags: ::core::option::Option<Tags>,
+    location: ::core::option::Option<Location>,
+) -> Scenario<World> {
+    Scenario {
+        description: into_description(name),
+        ignored: None,
+        tags,
+        soft_then: false,
+        timeout: None,
+        retries: None,
+        location,
+        narrative: narrative.map(Into::into),
+
+        given: ::core::default::Default::default(),
+        when: ::core::default::Default::default(),
+        then: ::core::default::Default::default(),
+    }
+}
+
+fn into_description(name: ::std::string::String) -> ::core::option::Option<aliases::string::String> {
+    (!name.is_empty()).then(|| name.into())
+}
+
+fn into_tags<'a>(tags: impl IntoIterator<Item = &'a [::std::string::String]>) -> ::core::option::Option<Tags> {
+    let tags = tags.into_iter().flatten().cloned().map(Into::into).map(untagged).collect::<Tags>();
+    (!tags.is_empty()).then_some(tags)
+}
+
+fn into_step_argument(argument: &ast::StepArgument) -> StepArgument {
+    match argument {
+        ast::StepArgument::DataTable(table) => StepArgument::DataTable(DataTable::new(table.rows.iter().cloned())),
+        ast::StepArgument::DocString(doc_string) => {
+            let content = DocString::new(doc_string.content.clone());
+
+            StepArgument::DocString(match &doc_string.media_type {
+                Some(media_type) => content.media_type(media_type.clone()),
+                None => content,
+            })
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(::core::default::Default)]
+    struct World {
+        log: ::std::vec::Vec<::std::string::String>,
+    }
+
+    fn scenario(registry: &StepRegistry<World>, steps: &str) -> Scenario<World> {
+        let source = ::std::format!("Feature: Lunch\n  Scenario: Eating\n{}", steps);
+        let mut feature = registry.parse(&source).unwrap_or_else(|err| ::core::panic!("{}", err));
+
+        feature.scenarios.remove(0)
+    }
+
+    fn run(scenario: &Scenario<World>) -> Result<World, Failed> {
+        let mut world = World::default();
+
+        for step in scenario.given.iter().chain(&scenario.when) {
+            (step.callback)(&mut world)?;
+        }
+
+        for step in &scenario.then {
+            (step.callback)(&world)?;
+        }
+
+        Ok(world)
+    }
+
+    fn registry() -> StepRegistry<World> {
+        StepRegistry::new()
+            .parameter_type("meal", "breakfast|lunch|dinner")
+            .given("{word} has {int} cucumber(s)", |world: &mut World, name: ::std::string::String, count: u8| {
+                world.log.push(::std::format!("{} has {}", name, count));
+            })
+            .given("{word} is hungry/starving", |world: &mut World, name: ::std::string::String| {
+                world.log.push(::std::format!("{} is hungry", name));
+            })
+            .when(
+                "{word} eats {meal}",
+                |world: &mut World, name: ::std::string::String, meal: ::std::string::String| {
+                    world.log.push(::std::format!("{} eats {}", name, meal));
+                },
+            )
+            .when("someone eats {}", |_: &mut World, _: ::std::string::String| {})
+            .then("^(\\w+) is full$", |world: &World, name: ::std::string::String| {
+                ::core::assert!(!world.log.is_empty(), "{} ate nothing", name);
+            })
+    }
+
+    #[test]
+    fn steps_run_the_definition_they_match_with_typed_arguments() {
+        let scenario = scenario(
+            &registry(),
+            ::core::concat!(
+                "    Given Ada has 1 cucumber\n",
+                "    And Bob is starving\n",
+                "    * Cy has 12 cucumbers\n",
+                "    When Ada eats lunch\n",
+                "    Then Ada is full\n",
+            ),
+        );
+
+        ::core::assert_eq!(
+            run(&scenario).map(|world| world.log).unwrap_or_else(|failed| ::core::panic!("{}", failed.message)),
+            ["Ada has 1", "Bob is hungry", "Cy has 12", "Ada eats lunch"]
+        );
+    }
+
+    #[test]
+    fn steps_fail_as_undefined_ambiguous_or_unconvertible() {
+        type Case = (&'static str, fn(FailedKind) -> bool, &'static [&'static str]);
+
+        let cases: &[Case] = &[
+            ("    Given Ada has some cucumbers\n", |kind| ::core::matches!(kind, FailedKind::Undefined), &[
+                "Step is undefined: `Given Ada has some cucumbers` (line 3)",
+                "  - `{word} has {int} cucumber(s)`",
+            ]),
+            ("    Then Ada is hungry\n", |kind| ::core::matches!(kind, FailedKind::Undefined), &[
+                "Step is undefined: `Then Ada is hungry` (line 3)",
+                "  - `^(\\w+) is full$`",
+            ]),
+            (
+                "    Given Ada has 1 cucumber\n    When someone eats lunch\n",
+                |kind| ::core::matches!(kind, FailedKind::Ambiguous),
+                &[
+                    "Step is ambiguous: `When someone eats lunch` (line 4)",
+                    "  - `{word} eats {meal}`",
+                    "  - `someone eats {}`",
+                ],
+            ),
+            ("    Given Ada has 300 cucumbers\n", |kind| ::core::matches!(kind, FailedKind::Failed), &[
+                "Failed to convert `300` into `u8`",
+                "  Ada has 300 cucumbers\n          ^^^",
+            ]),
+            (
+                "    When Ada eats lunch\n    Given Ada is hungry\n",
+                |kind| ::core::matches!(kind, FailedKind::Failed),
+                &["`Given Ada is hungry` (line 4) cannot follow a `When` step"],
+            ),
+        ];
+
+        for &(steps, is_kind, messages) in cases {
+            let Err(failed) = run(&scenario(&registry(), steps)) else {
+                ::core::panic!("{} did not fail", steps);
+            };
+
+            ::core::assert!(is_kind(failed.kind), "{}: {}", steps, failed.message);
+
+            for message in messages {
+                ::core::assert!(failed.message.contains(message), "{}: {}", steps, failed.message);
+            }
+        }
+    }
+
+    #[test]
+    fn malformed_definitions_are_rejected_when_lowering() {
+        let source = "Feature: Lunch\n";
+
+        let cases = [
+            (
+                StepRegistry::new().given("{word} eats", |_: &mut World| {}).parse(source).err(),
+                "invalid step pattern `{word} eats`: the pattern has 1 parameter(s), but its callback takes 0 \
+                 argument(s) besides the world",
+            ),
+            (
+                StepRegistry::new().when("{word} eats {meal}", |_: &mut World, _: u8, _: u8| {}).parse(source).err(),
+                "invalid step pattern `{word} eats {meal}`: parameter type `{meal}` is not registered",
+            ),
+            (StepRegistry::new().then("a table", |_: &World, _: DataTable| {}).parse(source).err(), ""),
+        ];
+
+        for (err, message) in cases {
+            ::core::assert_eq!(err.map(|err| err.to_string()).unwrap_or_default(), message);
+        }
+    }
+}
@@ -6,16 +6,32 @@
 ))]
 ::core::compile_error!("The following feature flags are mutually exclusive: `ahash`, `fxhash`, `metrohash`, `seahash`");
 
+pub mod arguments;
 pub mod assertions;
 pub mod builders;
-#[cfg(feature = "libtest-mimic")]
 pub mod engine;
+pub mod engines;
+#[cfg(feature = "async")]
+pub mod executors;
+#[cfg(feature = "gherkin")]
+pub mod gherkin;
 pub mod models;
+pub mod plan;
 pub mod prelude;
+pub mod tags;
 
+mod reporters;
 mod utils;
 
+pub use self::arguments::*;
+pub use self::assertions::expect_that;
+pub use self::assertions::soft;
+pub use self::assertions::Matcher;
+pub use self::assertions::Soft;
 pub use self::builders::*;
-#[cfg(feature = "libtest-mimic")]
 pub use self::engine::*;
+pub use self::engines::Engine;
+#[cfg(feature = "async")]
+pub use self::executors::*;
 pub use self::models::*;
+pub use self::plan::Plan;
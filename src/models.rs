@@ -6,6 +6,9 @@ pub struct Feature<World> {
     pub(crate) ignored: ::core::option::Option<bool>,
     pub(crate) tags: ::core::option::Option<Tags>,
 
+    /// Free-form lines below the `Feature:` line of a `.feature` file.
+    pub(crate) narrative: ::core::option::Option<aliases::string::String>,
+
     pub(crate) background: ::core::option::Option<Background<World>>,
     pub(crate) scenarios: ::std::vec::Vec<Scenario<World>>,
     pub(crate) rules: ::std::vec::Vec<Rule<World>>,
@@ -17,6 +20,9 @@ pub struct Rule<World> {
     pub(crate) ignored: ::core::option::Option<bool>,
     pub(crate) tags: ::core::option::Option<Tags>,
 
+    /// Free-form lines below the `Rule:` line of a `.feature` file.
+    pub(crate) narrative: ::core::option::Option<aliases::string::String>,
+
     pub(crate) background: ::core::option::Option<Background<World>>,
     pub(crate) scenarios: ::std::vec::Vec<Scenario<World>>,
 }
@@ -26,19 +32,44 @@ pub struct Scenario<World> {
     pub(crate) ignored: ::core::option::Option<bool>,
     pub(crate) tags: ::core::option::Option<Tags>,
 
+    /// Whether `Then` steps all run, rather than stopping at the first that
+    /// fails.
+    pub(crate) soft_then: bool,
+
+    /// How long the scenario may run for, if not the default of its suite or
+    /// runner.
+    pub(crate) timeout: ::core::option::Option<::core::time::Duration>,
+
+    /// How many times the scenario is run again while it fails, if not as
+    /// many as its `@retry(n)` tag says.
+    pub(crate) retries: ::core::option::Option<usize>,
+
+    pub(crate) location: ::core::option::Option<Location>,
+
+    /// Free-form lines below the `Scenario:` line of a `.feature` file, and
+    /// the description of the examples block the scenario was expanded from.
+    pub(crate) narrative: ::core::option::Option<aliases::string::String>,
+
     pub(crate) given: ::std::vec::Vec<ScenarioGivenOrWhenStep<World>>,
     pub(crate) when: ::std::vec::Vec<ScenarioGivenOrWhenStep<World>>,
     pub(crate) then: ::std::vec::Vec<ScenarioThenStep<World>>,
 }
 
 pub struct ScenarioOutline<World, Example> {
-    #[allow(dead_code)]
     pub(crate) description: ::core::option::Option<aliases::string::String>,
     pub(crate) ignored: ::core::option::Option<bool>,
     pub(crate) tags: ::core::option::Option<Tags>,
 
     pub(crate) scenario: ::std::boxed::Box<dyn Fn(Example) -> Scenario<World>>,
-    pub(crate) examples: ::std::vec::Vec<Example>,
+    pub(crate) examples: ::std::vec::Vec<Examples<Example>>,
+}
+
+pub struct Examples<Example> {
+    pub(crate) name: ::core::option::Option<aliases::string::String>,
+    pub(crate) description: ::core::option::Option<aliases::string::String>,
+    pub(crate) tags: ::core::option::Option<Tags>,
+
+    pub(crate) rows: ::std::vec::Vec<Example>,
 }
 
 pub struct Background<World> {
@@ -51,32 +82,101 @@ pub struct Background<World> {
 
 #[derive(::core::clone::Clone)]
 pub(crate) struct Hook<Callback> {
-    pub(crate) tags: ::core::option::Option<Tags>,
+    /// Untagged hooks run for every scenario.
+    pub(crate) tags: ::core::option::Option<TagsCondition>,
     pub(crate) callback: Callback,
 }
 
+/// Which scenarios, by their tags, a hook runs for.
+pub(crate) type TagsCondition = aliases::sync::Arc<dyn Fn(&Tags) -> bool + ::core::marker::Send + ::core::marker::Sync>;
+
 pub(crate) type ScenarioOrStepHook<World> =
     Hook<aliases::sync::Arc<dyn Fn(&mut World) -> Fallible + ::core::marker::Send + ::core::marker::Sync>>;
+/// After-hooks are given the outcome of the scenario or step they follow.
+pub(crate) type AfterScenarioOrStepHook<World> =
+    Hook<aliases::sync::Arc<dyn Fn(&mut World, &Fallible) -> Fallible + ::core::marker::Send + ::core::marker::Sync>>;
+/// `before_all` hooks store the fixture they return, and `after_all` hooks
+/// take the one they ask for.
 pub(crate) type GlobalHook =
-    Hook<::std::boxed::Box<dyn FnOnce() -> Fallible + ::core::marker::Send + ::core::marker::Sync>>;
+    Hook<::std::boxed::Box<dyn FnOnce(&mut Fixtures) -> Fallible + ::core::marker::Send + ::core::marker::Sync>>;
+
+/// Values returned by `before_all` hooks, at most one per type, shared by
+/// every scenario of the run.
+#[derive(::core::default::Default)]
+pub(crate) struct Fixtures(
+    ::std::collections::HashMap<
+        ::core::any::TypeId,
+        ::std::boxed::Box<dyn ::core::any::Any + ::core::marker::Send + ::core::marker::Sync>,
+    >,
+);
+
+pub(crate) type WorldFactory<World> =
+    aliases::sync::Arc<dyn Fn(&Fixtures) -> Fallible<World> + ::core::marker::Send + ::core::marker::Sync>;
 
 #[derive(::core::clone::Clone)]
 pub(crate) struct Step<Callback> {
     pub(crate) label: StepLabel,
     pub(crate) description: aliases::string::String,
+    pub(crate) argument: ::core::option::Option<crate::arguments::StepArgument>,
 
     pub(crate) callback: Callback,
 }
 
 pub(crate) type ScenarioGivenOrWhenStep<World> =
-    Step<::std::boxed::Box<dyn FnOnce(&mut World) -> Fallible + ::core::marker::Send + ::core::marker::Sync>>;
+    Step<aliases::sync::Arc<dyn Fn(&mut World) -> Fallible + ::core::marker::Send + ::core::marker::Sync>>;
 pub(crate) type ScenarioThenStep<World> =
-    Step<::std::boxed::Box<dyn FnOnce(&World) -> Fallible + ::core::marker::Send + ::core::marker::Sync>>;
+    Step<aliases::sync::Arc<dyn Fn(&World) -> Fallible + ::core::marker::Send + ::core::marker::Sync>>;
 pub(crate) type BackgroundGivenStep<World> =
     Step<aliases::sync::Arc<dyn Fn(&mut World) -> Fallible + ::core::marker::Send + ::core::marker::Sync>>;
 
+/// Tags are kept without their leading `@`, whether they were set with a
+/// builder or read from a `.feature` file.
 pub type Tags = ::std::collections::HashSet<aliases::string::String, aliases::hash::BuildHasher>;
 
+/// Strips the leading `@` of a tag, if it has one.
+pub(crate) fn untagged(tag: aliases::string::String) -> aliases::string::String {
+    match tag {
+        ::std::borrow::Cow::Borrowed(tag) => ::std::borrow::Cow::Borrowed(tag.strip_prefix('@').unwrap_or(tag)),
+        ::std::borrow::Cow::Owned(tag) => match tag.strip_prefix('@') {
+            Some(stripped) => ::std::borrow::Cow::Owned(stripped.to_owned()),
+            None => ::std::borrow::Cow::Owned(tag),
+        },
+    }
+}
+
+/// Where a scenario was defined: the call that built it, or the line of the
+/// `.feature` file it was read from.
+#[derive(::core::clone::Clone, ::core::fmt::Debug)]
+pub struct Location {
+    pub(crate) file: aliases::string::String,
+    pub(crate) line: usize,
+}
+
+impl Location {
+    pub fn file(&self) -> &str {
+        &self.file
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+}
+
+impl From<&'static ::core::panic::Location<'static>> for Location {
+    fn from(location: &'static ::core::panic::Location<'static>) -> Self {
+        Self {
+            file: location.file().into(),
+            line: location.line() as usize,
+        }
+    }
+}
+
+impl ::core::fmt::Display for Location {
+    fn fmt(&self, formatter: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        ::core::write!(formatter, "{}:{}", self.file, self.line)
+    }
+}
+
 #[derive(::core::clone::Clone, ::core::marker::Copy)]
 pub(crate) enum StepLabel {
     Given,
@@ -89,6 +189,263 @@ pub(crate) enum StepLabel {
 
 pub type Fallible<T = ()> = ::core::result::Result<T, Failed>;
 
+/// Why a step, hook or world setup failed.
+///
+/// Besides its message, a failure may carry where it was raised, the error
+/// that caused it, the expected and actual values it compared and any context
+/// worth reporting, all of which are rendered after the message. Errors are
+/// turned into failures with `?`, their sources becoming the cause chain.
+#[derive(::core::clone::Clone)]
 pub struct Failed {
     pub(crate) message: aliases::string::String,
+    pub(crate) kind: FailedKind,
+
+    /// `None` for failures raised by litmus itself, such as undefined steps,
+    /// rather than by a step definition.
+    pub(crate) location: ::core::option::Option<&'static ::core::panic::Location<'static>>,
+    pub(crate) source: ::core::option::Option<FailedSource>,
+
+    /// Boxed, as most failures have none and `Fallible` is returned by every
+    /// step.
+    pub(crate) details: ::core::option::Option<::std::boxed::Box<FailedDetails>>,
+}
+
+#[derive(::core::default::Default, ::core::clone::Clone)]
+pub(crate) struct FailedDetails {
+    /// Rendered with `Debug`.
+    pub(crate) expected: ::core::option::Option<::std::string::String>,
+    pub(crate) actual: ::core::option::Option<::std::string::String>,
+    pub(crate) context: ::std::vec::Vec<(aliases::string::String, ::std::string::String)>,
+
+    /// Shown instead of the expected and actual values, which are either
+    /// long or span several lines.
+    pub(crate) diff: ::std::vec::Vec<(crate::utils::Change, ::std::string::String)>,
+}
+
+pub(crate) type FailedSource =
+    aliases::sync::Arc<dyn ::std::error::Error + ::core::marker::Send + ::core::marker::Sync + 'static>;
+
+impl Fixtures {
+    /// Replaces any fixture of the same type.
+    pub(crate) fn insert<Fixture>(&mut self, fixture: Fixture)
+    where
+        Fixture: ::core::marker::Send + ::core::marker::Sync + 'static,
+    {
+        self.0.insert(::core::any::TypeId::of::<Fixture>(), ::std::boxed::Box::new(fixture));
+    }
+
+    pub(crate) fn get<Fixture: 'static>(&self) -> Fallible<&Fixture> {
+        self.0
+            .get(&::core::any::TypeId::of::<Fixture>())
+            .and_then(|fixture| fixture.downcast_ref())
+            .ok_or_else(missing::<Fixture>)
+    }
+
+    pub(crate) fn take<Fixture: 'static>(&mut self) -> Fallible<Fixture> {
+        self.0
+            .remove(&::core::any::TypeId::of::<Fixture>())
+            .and_then(|fixture| fixture.downcast().ok())
+            .map(|fixture| *fixture)
+            .ok_or_else(missing::<Fixture>)
+    }
+}
+
+fn missing<Fixture>() -> Failed {
+    Failed::untraced(
+        ::std::format!("no `before_all` hook returned a fixture of type `{}`", ::core::any::type_name::<Fixture>()),
+        ::core::default::Default::default(),
+    )
+}
+
+impl Failed {
+    #[track_caller]
+    pub fn new(message: impl Into<aliases::string::String>) -> Self {
+        Self {
+            location: Some(::core::panic::Location::caller()),
+            ..Self::untraced(message, ::core::default::Default::default())
+        }
+    }
+
+    pub(crate) fn untraced(message: impl Into<aliases::string::String>, kind: FailedKind) -> Self {
+        Self {
+            message: message.into(),
+            kind,
+
+            location: None,
+            source: None,
+
+            details: None,
+        }
+    }
+
+    pub(crate) fn timed_out(timeout: ::core::time::Duration) -> Self {
+        Self::untraced(::std::format!("Timed out after {:?}", timeout), FailedKind::TimedOut)
+    }
+
+    pub fn with_source<Error>(self, source: Error) -> Self
+    where
+        Error: ::std::error::Error + ::core::marker::Send + ::core::marker::Sync + 'static,
+    {
+        Self {
+            source: Some(aliases::sync::Arc::new(source)),
+            ..self
+        }
+    }
+
+    pub fn with_expected(mut self, expected: impl ::core::fmt::Debug) -> Self {
+        self.details.get_or_insert_default().expected = Some(::std::format!("{:?}", expected));
+        self
+    }
+
+    pub fn with_actual(mut self, actual: impl ::core::fmt::Debug) -> Self {
+        self.details.get_or_insert_default().actual = Some(::std::format!("{:?}", actual));
+        self
+    }
+
+    /// Adds a line of context, rendered as `key: value`, after any added
+    /// before.
+    pub fn with_context(mut self, key: impl Into<aliases::string::String>, value: impl ::core::fmt::Display) -> Self {
+        self.details.get_or_insert_default().context.push((key.into(), value.to_string()));
+        self
+    }
+
+    pub(crate) fn with_diff(mut self, diff: ::std::vec::Vec<(crate::utils::Change, ::std::string::String)>) -> Self {
+        self.details.get_or_insert_default().diff = diff;
+        self
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn location(&self) -> ::core::option::Option<&'static ::core::panic::Location<'static>> {
+        self.location
+    }
+
+    pub fn source(&self) -> ::core::option::Option<&(dyn ::std::error::Error + 'static)> {
+        self.source.as_deref().map(|source| source as _)
+    }
+
+    pub fn expected(&self) -> ::core::option::Option<&str> {
+        self.details.as_ref()?.expected.as_deref()
+    }
+
+    pub fn actual(&self) -> ::core::option::Option<&str> {
+        self.details.as_ref()?.actual.as_deref()
+    }
+
+    pub fn context(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.details.iter().flat_map(|details| &details.context).map(|(key, value)| (&**key, value.as_str()))
+    }
+
+    /// The sources of the failure, outermost first. A source that reads as
+    /// the message, as the error a failure was converted from does, is left
+    /// out.
+    fn causes(&self) -> impl Iterator<Item = &(dyn ::std::error::Error + 'static)> {
+        ::core::iter::successors(self.source(), |source| source.source())
+            .enumerate()
+            .filter(|(index, source)| *index > 0 || source.to_string() != *self.message)
+            .map(|(_, source)| source)
+    }
+}
+
+impl<Error> From<Error> for Failed
+where
+    Error: ::std::error::Error + ::core::marker::Send + ::core::marker::Sync + 'static,
+{
+    #[track_caller]
+    fn from(source: Error) -> Self {
+        Self::new(source.to_string()).with_source(source)
+    }
+}
+
+impl Failed {
+    /// The message, followed by whatever else the failure carries, one item
+    /// per line. Lines of the diff, if any, are coloured for terminals if
+    /// `colored`.
+    pub(crate) fn render(&self, colored: bool) -> ::std::string::String {
+        let mut rendered = self.message.to_string();
+
+        let diff = self.details.iter().flat_map(|details| &details.diff).collect::<::std::vec::Vec<_>>();
+
+        let values = [("expected", self.expected()), ("actual", self.actual())];
+        let values = values.into_iter().filter(|_| diff.is_empty()).filter_map(|(key, value)| Some((key, value?)));
+
+        for (key, value) in values.chain(self.context()) {
+            match value.contains('\n') {
+                true => rendered.push_str(&::std::format!("\n{}:\n  {}", key, value.replace('\n', "\n  "))),
+                false => rendered.push_str(&::std::format!("\n{}: {}", key, value)),
+            }
+        }
+
+        if !diff.is_empty() {
+            rendered.push_str("\ndiff (- expected, + actual):");
+        }
+
+        for (change, line) in diff {
+            let (marker, color) = match change {
+                crate::utils::Change::Same => (' ', None),
+                crate::utils::Change::Expected => ('-', Some(31)),
+                crate::utils::Change::Actual => ('+', Some(32)),
+            };
+
+            match color.filter(|_| colored) {
+                Some(color) => rendered.push_str(&::std::format!("\n\x1b[{}m  {} {}\x1b[0m", color, marker, line)),
+                None => rendered.push_str(&::std::format!("\n  {} {}", marker, line)),
+            }
+        }
+
+        for cause in self.causes() {
+            rendered.push_str(&::std::format!("\ncaused by: {}", cause));
+        }
+
+        if let Some(location) = self.location {
+            rendered.push_str(&::std::format!("\nat {}", location));
+        }
+
+        rendered
+    }
+}
+
+impl Failed {
+    /// One failure listing several, each rendered in full and numbered.
+    pub(crate) fn listing(summary: ::std::string::String, failures: &[Self]) -> Self {
+        let items = failures
+            .iter()
+            .enumerate()
+            .map(|(index, failed)| ::std::format!("\n  {}. {}", index + 1, failed.to_string().replace('\n', "\n     ")))
+            .collect::<::std::string::String>();
+
+        Self::untraced(::std::format!("{}:{}", summary, items), FailedKind::Failed)
+    }
+}
+
+impl ::core::fmt::Display for Failed {
+    fn fmt(&self, formatter: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        formatter.write_str(&self.render(false))
+    }
+}
+
+impl ::core::fmt::Debug for Failed {
+    fn fmt(&self, formatter: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        ::core::fmt::Display::fmt(self, formatter)
+    }
+}
+
+/// Lets reports tell steps that failed apart from steps that are yet to be
+/// implemented or could not be run at all.
+#[derive(::core::default::Default, ::core::clone::Clone, ::core::marker::Copy)]
+pub(crate) enum FailedKind {
+    #[default]
+    Failed,
+    Pending,
+
+    /// Only step definitions registered with Gherkin can fail to match.
+    #[cfg_attr(not(feature = "gherkin"), allow(dead_code))]
+    Undefined,
+    #[cfg_attr(not(feature = "gherkin"), allow(dead_code))]
+    Ambiguous,
+
+    /// The scenario ran past its timeout while the step or hook was running.
+    TimedOut,
 }
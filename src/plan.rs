@@ -0,0 +1,345 @@
+use crate::arguments::*;
+use crate::models::*;
+use crate::reporters::json::Json;
+use crate::utils::aliases;
+
+/// The scenarios a runner would run, after filtering, beneath their feature
+/// and rule, with their backgrounds and steps. None of their callbacks ran to
+/// make it.
+///
+/// It prints as Gherkin would have it, or as JSON with [`Plan::to_json`].
+#[derive(::core::default::Default)]
+pub struct Plan {
+    pub(crate) features: ::std::vec::Vec<FeaturePlan>,
+}
+
+pub(crate) struct FeaturePlan {
+    pub(crate) index: usize,
+    pub(crate) name: aliases::string::String,
+    pub(crate) tags: ::std::vec::Vec<aliases::string::String>,
+    pub(crate) narrative: ::core::option::Option<aliases::string::String>,
+
+    pub(crate) background: ::std::vec::Vec<StepPlan>,
+    pub(crate) scenarios: ::std::vec::Vec<ScenarioPlan>,
+    pub(crate) rules: ::std::vec::Vec<RulePlan>,
+}
+
+pub(crate) struct RulePlan {
+    pub(crate) index: usize,
+    pub(crate) name: aliases::string::String,
+    pub(crate) tags: ::std::vec::Vec<aliases::string::String>,
+    pub(crate) narrative: ::core::option::Option<aliases::string::String>,
+
+    pub(crate) background: ::std::vec::Vec<StepPlan>,
+    pub(crate) scenarios: ::std::vec::Vec<ScenarioPlan>,
+}
+
+/// Outlines are planned as one scenario per example.
+pub(crate) struct ScenarioPlan {
+    pub(crate) name: aliases::string::String,
+
+    /// Of the scenario, its feature, its rule and its examples, sorted.
+    pub(crate) tags: ::std::vec::Vec<aliases::string::String>,
+
+    /// Whether the scenario, its rule or its feature is ignored.
+    pub(crate) ignored: bool,
+    pub(crate) location: ::core::option::Option<Location>,
+    pub(crate) narrative: ::core::option::Option<aliases::string::String>,
+
+    pub(crate) steps: ::std::vec::Vec<StepPlan>,
+}
+
+pub(crate) struct StepPlan {
+    pub(crate) label: StepLabel,
+    pub(crate) description: aliases::string::String,
+    pub(crate) argument: ::core::option::Option<StepArgument>,
+}
+
+impl Plan {
+    /// Scenarios are expected in the order they were defined, so that those of
+    /// a feature or rule follow one another.
+    pub(crate) fn push(
+        &mut self,
+        report: &crate::reporters::ScenarioReport,
+        location: ::core::option::Option<Location>,
+    ) {
+        let steps = |origin| {
+            report
+                .steps
+                .iter()
+                .filter(|step| step.origin == origin)
+                .map(|step| StepPlan {
+                    label: step.label,
+                    description: step.description.clone(),
+                    argument: step.argument.clone(),
+                })
+                .collect::<::std::vec::Vec<_>>()
+        };
+
+        if self.features.last().is_none_or(|feature| feature.index != report.feature.index) {
+            self.features.push(FeaturePlan {
+                index: report.feature.index,
+                name: report.feature.name("Feature"),
+                tags: report.feature.tags.clone(),
+                narrative: report.feature.narrative.clone(),
+
+                background: steps(crate::reporters::StepOrigin::FeatureBackground),
+                scenarios: ::std::vec::Vec::new(),
+                rules: ::std::vec::Vec::new(),
+            });
+        }
+
+        let Some(feature) = self.features.last_mut() else {
+            return;
+        };
+
+        let scenario = ScenarioPlan {
+            name: report.description.clone(),
+            tags: report.tags.clone(),
+            ignored: report.ignored,
+            location,
+            narrative: report.narrative.clone(),
+
+            steps: steps(crate::reporters::StepOrigin::Scenario),
+        };
+
+        let Some(ref rule) = report.rule else {
+            feature.scenarios.push(scenario);
+            return;
+        };
+
+        if feature.rules.last().is_none_or(|planned| planned.index != rule.index) {
+            feature.rules.push(RulePlan {
+                index: rule.index,
+                name: rule.name("Rule"),
+                tags: rule.tags.clone(),
+                narrative: rule.narrative.clone(),
+
+                background: steps(crate::reporters::StepOrigin::RuleBackground),
+                scenarios: ::std::vec::Vec::new(),
+            });
+        }
+
+        if let Some(rule) = feature.rules.last_mut() {
+            rule.scenarios.push(scenario);
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.features.is_empty()
+    }
+
+    /// `{"features":[{"name":…,"tags":[…],"background":[…],"scenarios":[…],
+    /// "rules":[…]}]}`, where features, rules and scenarios also have a
+    /// `description` if they have one, scenarios have a `name`, `tags`,
+    /// `ignored`, `steps` and, if it is known, a `location`, and steps have a
+    /// `keyword`, a `text` and, if they have one, `rows` or a `doc_string`.
+    pub fn to_json(&self) -> ::std::string::String {
+        let features = self.features.iter().map(|feature| {
+            described(
+                feature.narrative.as_deref(),
+                Json::object([
+                    ("name", (*feature.name).into()),
+                    ("tags", tags(&feature.tags)),
+                    ("background", feature.background.iter().map(StepPlan::to_json).collect()),
+                    ("scenarios", feature.scenarios.iter().map(ScenarioPlan::to_json).collect()),
+                    (
+                        "rules",
+                        feature
+                            .rules
+                            .iter()
+                            .map(|rule| {
+                                described(
+                                    rule.narrative.as_deref(),
+                                    Json::object([
+                                        ("name", (*rule.name).into()),
+                                        ("tags", tags(&rule.tags)),
+                                        ("background", rule.background.iter().map(StepPlan::to_json).collect()),
+                                        ("scenarios", rule.scenarios.iter().map(ScenarioPlan::to_json).collect()),
+                                    ]),
+                                )
+                            })
+                            .collect(),
+                    ),
+                ]),
+            )
+        });
+
+        Json::object([("features", features.collect())]).to_string()
+    }
+}
+
+impl ScenarioPlan {
+    fn to_json(&self) -> Json {
+        let json = Json::object([
+            ("name", (*self.name).into()),
+            ("tags", tags(&self.tags)),
+            ("ignored", self.ignored.into()),
+            ("steps", self.steps.iter().map(StepPlan::to_json).collect()),
+        ]);
+
+        let json = described(self.narrative.as_deref(), json);
+
+        match self.location {
+            Some(ref location) => json
+                .with("location", Json::object([("file", location.file().into()), ("line", location.line().into())])),
+            None => json,
+        }
+    }
+}
+
+impl StepPlan {
+    fn to_json(&self) -> Json {
+        let json = Json::object([("keyword", self.label.keyword().into()), ("text", (*self.description).into())]);
+
+        match self.argument {
+            Some(StepArgument::DataTable(ref table)) => json.with(
+                "rows",
+                table.rows().iter().map(|cells| cells.iter().map(|cell| &**cell).collect::<Json>()).collect::<Json>(),
+            ),
+            Some(StepArgument::DocString(ref doc_string)) => {
+                let json_doc_string = Json::object([("content", doc_string.content().into())]);
+
+                json.with("doc_string", match doc_string.content_type() {
+                    Some(content_type) => json_doc_string.with("content_type", content_type),
+                    None => json_doc_string,
+                })
+            },
+            None => json,
+        }
+    }
+}
+
+fn described(narrative: ::core::option::Option<&str>, json: Json) -> Json {
+    match narrative {
+        Some(narrative) => json.with("description", narrative),
+        None => json,
+    }
+}
+
+fn tags(tags: &[aliases::string::String]) -> Json {
+    tags.iter().map(|tag| &**tag).collect()
+}
+
+impl ::core::fmt::Display for Plan {
+    fn fmt(&self, formatter: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let mut scenarios = 0;
+        let mut ignored = 0;
+
+        for feature in &self.features {
+            header(formatter, ("Feature", &feature.name, &feature.tags), 0)?;
+            narrative(formatter, feature.narrative.as_deref(), 2)?;
+            background(formatter, &feature.background, 2)?;
+
+            for scenario in &feature.scenarios {
+                scenario.write(formatter, 2)?;
+            }
+
+            for rule in &feature.rules {
+                header(formatter, ("Rule", &rule.name, &rule.tags), 2)?;
+                narrative(formatter, rule.narrative.as_deref(), 4)?;
+                background(formatter, &rule.background, 4)?;
+
+                for scenario in &rule.scenarios {
+                    scenario.write(formatter, 4)?;
+                }
+            }
+
+            let planned = feature.scenarios.iter().chain(feature.rules.iter().flat_map(|rule| &rule.scenarios));
+
+            for scenario in planned {
+                scenarios += 1;
+                ignored += scenario.ignored as usize;
+            }
+        }
+
+        let noun = |count: usize, (singular, plural): (&'static str, &'static str)| match count {
+            1 => singular,
+            _ => plural,
+        };
+
+        ::core::write!(
+            formatter,
+            "\n{} {}, {} {}",
+            self.features.len(),
+            noun(self.features.len(), ("feature", "features")),
+            scenarios,
+            noun(scenarios, ("scenario", "scenarios")),
+        )?;
+
+        match ignored {
+            0 => ::core::writeln!(formatter),
+            ignored => ::core::writeln!(formatter, " ({} ignored)", ignored),
+        }
+    }
+}
+
+impl ScenarioPlan {
+    fn write(&self, formatter: &mut ::core::fmt::Formatter<'_>, indent: usize) -> ::core::fmt::Result {
+        ::core::writeln!(formatter)?;
+
+        if !self.tags.is_empty() {
+            ::core::writeln!(formatter, "{:indent$}{}", "", crate::reporters::tag_line(&self.tags))?;
+        }
+
+        ::core::write!(formatter, "{:indent$}Scenario: {}", "", self.name)?;
+
+        if self.ignored {
+            formatter.write_str(" (ignored)")?;
+        }
+
+        match self.location {
+            Some(ref location) => ::core::writeln!(formatter, "  # {}", location)?,
+            None => ::core::writeln!(formatter)?,
+        }
+
+        narrative(formatter, self.narrative.as_deref(), indent + 2)?;
+
+        self.steps.iter().try_for_each(|step| step.write(formatter, indent + 2))
+    }
+}
+
+impl StepPlan {
+    fn write(&self, formatter: &mut ::core::fmt::Formatter<'_>, indent: usize) -> ::core::fmt::Result {
+        ::core::writeln!(formatter, "{:indent$}{} {}", "", self.label.keyword(), self.description)?;
+
+        self.argument
+            .iter()
+            .flat_map(StepArgument::lines)
+            .try_for_each(|line| ::core::writeln!(formatter, "{:indent$}{}", "", line, indent = indent + 2))
+    }
+}
+
+fn header(
+    formatter: &mut ::core::fmt::Formatter<'_>,
+    (kind, name, tags): (&str, &str, &[aliases::string::String]),
+    indent: usize,
+) -> ::core::fmt::Result {
+    ::core::writeln!(formatter)?;
+
+    if !tags.is_empty() {
+        ::core::writeln!(formatter, "{:indent$}{}", "", crate::reporters::tag_line(tags))?;
+    }
+
+    ::core::writeln!(formatter, "{:indent$}{}: {}", "", kind, name)
+}
+
+fn narrative(
+    formatter: &mut ::core::fmt::Formatter<'_>,
+    narrative: ::core::option::Option<&str>,
+    indent: usize,
+) -> ::core::fmt::Result {
+    narrative
+        .into_iter()
+        .flat_map(str::lines)
+        .try_for_each(|line| ::core::writeln!(formatter, "{:indent$}{}", "", line))
+}
+
+fn background(formatter: &mut ::core::fmt::Formatter<'_>, steps: &[StepPlan], indent: usize) -> ::core::fmt::Result {
+    if steps.is_empty() {
+        return Ok(());
+    }
+
+    ::core::writeln!(formatter, "\n{:indent$}Background:", "")?;
+    steps.iter().try_for_each(|step| step.write(formatter, indent + 2))
+}
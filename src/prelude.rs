@@ -1 +1,2 @@
+pub use crate::builders::models::AfterScenarioOrStepHookCallbackExt as _;
 pub use crate::builders::models::ScenarioOrStepHookCallbackExt as _;
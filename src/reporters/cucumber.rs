@@ -0,0 +1,169 @@
+use crate::reporters::document::*;
+use crate::reporters::json::*;
+use crate::reporters::*;
+
+/// Renders the JSON report of the Ruby and JVM implementations of Cucumber,
+/// which `cucumber-html-reporter` and most CI plugins understand.
+///
+/// The format has no notion of rules, so scenarios within rules are listed
+/// among those of their feature, and the steps of every background that applies
+/// to a scenario are gathered in a single background element before it.
+pub(crate) fn render(scenarios: &[&ScenarioReport]) -> ::std::string::String {
+    let features = features(scenarios)
+        .iter()
+        .map(|scenarios| Document::new(scenarios))
+        .map(|document| render_feature(&document))
+        .collect::<Json>();
+
+    ::std::format!("{}\n", features)
+}
+
+fn render_feature(document: &Document<'_>) -> Json {
+    let id = slug(&document.feature.name("Feature"));
+
+    let elements = document
+        .scenarios()
+        .flat_map(|scenario| {
+            let background = scenario.steps.iter().filter(|step| step.step.origin != StepOrigin::Scenario);
+
+            let background = background.clone().next().map(|first| {
+                Json::object([
+                    ("keyword", "Background".into()),
+                    ("type", "background".into()),
+                    ("name", "".into()),
+                    ("description", "".into()),
+                    ("line", (first.line - 1).into()),
+                    ("steps", background.map(render_step).collect()),
+                ])
+            });
+
+            let attempts = render_attempts(scenario.scenario);
+
+            let scenario = Json::object([
+                ("id", ::std::format!("{};{}", id, slug(&scenario.scenario.description)).into()),
+                ("keyword", "Scenario".into()),
+                ("type", "scenario".into()),
+                ("name", (*scenario.scenario.description).into()),
+                ("description", scenario.scenario.narrative.as_deref().unwrap_or_default().into()),
+                ("line", scenario.line.into()),
+                ("tags", render_tags(&scenario.scenario.tags, scenario.line)),
+                ("before", render_hooks(scenario.scenario, &[HookKind::BeforeAll, HookKind::Setup, HookKind::Before])),
+                (
+                    "steps",
+                    scenario
+                        .steps
+                        .iter()
+                        .filter(|step| step.step.origin == StepOrigin::Scenario)
+                        .map(render_step)
+                        .collect(),
+                ),
+                ("after", render_hooks(scenario.scenario, &[HookKind::After])),
+            ]);
+
+            let scenario = match attempts {
+                Some(attempts) => scenario.with("attempts", attempts),
+                None => scenario,
+            };
+
+            background.into_iter().chain([scenario])
+        })
+        .collect();
+
+    Json::object([
+        ("uri", (*document.uri).into()),
+        ("id", id.into()),
+        ("keyword", "Feature".into()),
+        ("name", (*document.feature.name("Feature")).into()),
+        ("description", document.feature.narrative.as_deref().unwrap_or_default().into()),
+        ("line", document.line.into()),
+        ("tags", render_tags(&document.feature.tags, document.line)),
+        ("elements", elements),
+    ])
+}
+
+fn render_step(step: &StepNode<'_>) -> Json {
+    let json = Json::object([
+        ("keyword", ::std::format!("{} ", step.step.label.keyword()).into()),
+        ("name", (*step.step.description).into()),
+        ("line", step.line.into()),
+        ("result", render_result(&step.step.outcome, step.step.duration)),
+    ]);
+
+    match &step.step.argument {
+        Some(StepArgument::DataTable(table)) => json.with(
+            "rows",
+            table
+                .rows()
+                .iter()
+                .map(|cells| Json::object([("cells", cells.iter().map(|cell| &**cell).collect())]))
+                .collect::<Json>(),
+        ),
+        Some(StepArgument::DocString(doc_string)) => {
+            let json_doc_string =
+                Json::object([("value", doc_string.content().into()), ("line", (step.line + 1).into())]);
+
+            json.with("doc_string", match doc_string.content_type() {
+                Some(content_type) => json_doc_string.with("content_type", content_type),
+                None => json_doc_string,
+            })
+        },
+        None => json,
+    }
+}
+
+fn render_hooks(scenario: &ScenarioReport, kinds: &[HookKind]) -> Json {
+    scenario
+        .hooks
+        .iter()
+        .filter(|hook| kinds.contains(&hook.kind))
+        .map(|hook| {
+            Json::object([
+                ("match", Json::object([("location", hook.kind.name().into())])),
+                ("result", render_result(&hook.outcome, hook.duration)),
+            ])
+        })
+        .collect()
+}
+
+/// Durations are in nanoseconds.
+fn render_result(outcome: &StepOutcome, duration: ::core::time::Duration) -> Json {
+    let result = Json::object([("status", outcome.status().name().into())]);
+
+    let result = match outcome {
+        StepOutcome::Skipped => result,
+        _ => result.with("duration", duration.as_nanos()),
+    };
+
+    let Some(failed) = outcome.failed() else {
+        return result;
+    };
+
+    // Not part of the format, but kept apart for tools that compare them.
+    let values = [("expected", failed.expected()), ("actual", failed.actual())];
+
+    values
+        .into_iter()
+        .filter_map(|(key, value)| Some((key, value?)))
+        .fold(result.with("error_message", failed.to_string()), |result, (key, value)| result.with(key, value))
+}
+
+/// Earlier attempts of a retried scenario, oldest first, which the format has
+/// no notion of either.
+fn render_attempts(scenario: &ScenarioReport) -> ::core::option::Option<Json> {
+    let attempts = scenario.attempts.iter().filter_map(|attempt| {
+        let failed = attempt.outcome.failed()?;
+
+        Some(Json::object([
+            ("status", Status::Failed.name().into()),
+            ("duration", attempt.duration.as_nanos().into()),
+            ("error_message", failed.to_string().into()),
+        ]))
+    });
+
+    (!scenario.attempts.is_empty()).then(|| attempts.collect())
+}
+
+/// Tags are written on the line before the keyword they apply to.
+fn render_tags(tags: &[aliases::string::String], line: usize) -> Json {
+    tags.iter().map(|tag| Json::object([("name", tagged(tag).into()), ("line", (line - 1).into())])).collect()
+}
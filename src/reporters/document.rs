@@ -0,0 +1,268 @@
+use crate::reporters::*;
+
+/// A Gherkin rendition of a feature, for reports that refer to features by
+/// their source text and to scenarios and steps by line.
+///
+/// Features built in code have no `.feature` file, so one is written out from
+/// the reports of their scenarios, with tags that scenarios inherit from their
+/// feature or rule written only once.
+pub(crate) struct Document<'a> {
+    pub(crate) uri: ::std::string::String,
+    pub(crate) source: ::std::string::String,
+
+    pub(crate) feature: &'a Ancestor,
+    pub(crate) line: usize,
+    pub(crate) children: ::std::vec::Vec<Child<'a>>,
+}
+
+pub(crate) enum Child<'a> {
+    Background(BackgroundNode<'a>),
+    Scenario(ScenarioNode<'a>),
+    Rule(RuleNode<'a>),
+}
+
+pub(crate) struct RuleNode<'a> {
+    pub(crate) rule: &'a Ancestor,
+    pub(crate) line: usize,
+    pub(crate) children: ::std::vec::Vec<Child<'a>>,
+}
+
+#[derive(::core::clone::Clone)]
+pub(crate) struct BackgroundNode<'a> {
+    pub(crate) line: usize,
+    pub(crate) steps: ::std::vec::Vec<StepNode<'a>>,
+}
+
+pub(crate) struct ScenarioNode<'a> {
+    pub(crate) scenario: &'a ScenarioReport,
+    pub(crate) line: usize,
+
+    /// Tags written on the scenario itself, as opposed to inherited ones.
+    pub(crate) tags: ::std::vec::Vec<&'a str>,
+
+    /// Where each step of the scenario is written, including those of its
+    /// backgrounds.
+    pub(crate) steps: ::std::vec::Vec<StepNode<'a>>,
+}
+
+#[derive(::core::clone::Clone, ::core::marker::Copy)]
+pub(crate) struct StepNode<'a> {
+    pub(crate) step: &'a StepReport,
+    pub(crate) line: usize,
+}
+
+/// Lines are numbered from 1, and tags sit on the line before the keyword they
+/// apply to.
+struct Writer {
+    source: ::std::string::String,
+    line: usize,
+}
+
+impl<'a> Document<'a> {
+    /// `scenarios` must all belong to the same feature.
+    pub(crate) fn new(scenarios: &[&'a ScenarioReport]) -> Self {
+        let feature = &scenarios[0].feature;
+        let name = feature.name("Feature");
+
+        let mut writer = Writer {
+            source: ::std::string::String::new(),
+            line: 0,
+        };
+
+        let line = writer.keyword(0, (&feature.tags, ::std::format!("Feature: {}", name)));
+
+        let mut children = ::std::vec::Vec::new();
+
+        let outside = scenarios.iter().copied().filter(|scenario| scenario.rule.is_none());
+
+        let background = writer.background(1, (scenarios, StepOrigin::FeatureBackground));
+        let mut rules = ::std::vec::Vec::<(&Ancestor, ::std::vec::Vec<&ScenarioReport>)>::new();
+
+        scenarios.iter().filter_map(|scenario| Some((scenario.rule.as_ref()?, *scenario))).for_each(
+            |(rule, scenario)| match rules.iter_mut().find(|(other, _)| other.index == rule.index) {
+                Some((_, scenarios)) => scenarios.push(scenario),
+                None => rules.push((rule, ::std::vec![scenario])),
+            },
+        );
+
+        children.extend(background.iter().map(|background| Child::Background(background.clone())));
+
+        children.extend(outside.map(|scenario| {
+            Child::Scenario(writer.scenario(1, (scenario, &feature.tags, [background.as_ref(), None])))
+        }));
+
+        children.extend(rules.into_iter().map(|(rule, scenarios)| {
+            writer.blank();
+            let line = writer.keyword(1, (&rule.tags, ::std::format!("Rule: {}", rule.name("Rule"))));
+
+            let rule_background = writer.background(2, (&scenarios, StepOrigin::RuleBackground));
+
+            let inherited = feature.tags.iter().chain(&rule.tags).cloned().collect::<::std::vec::Vec<_>>();
+
+            let mut children = ::std::vec::Vec::new();
+            children.extend(rule_background.iter().map(|background| Child::Background(background.clone())));
+            children.extend(scenarios.into_iter().map(|scenario| {
+                let backgrounds = [background.as_ref(), rule_background.as_ref()];
+                Child::Scenario(writer.scenario(2, (scenario, &inherited, backgrounds)))
+            }));
+
+            Child::Rule(RuleNode {
+                rule,
+                line,
+                children,
+            })
+        }));
+
+        Self {
+            uri: ::std::format!("{}.feature", slug(&name)),
+            source: writer.source,
+
+            feature,
+            line,
+            children,
+        }
+    }
+
+    /// Backgrounds and scenarios in the order they are written, with those
+    /// within rules following the rule itself.
+    pub(crate) fn scenarios(&self) -> impl Iterator<Item = &ScenarioNode<'a>> {
+        self.children
+            .iter()
+            .flat_map(|child| match child {
+                Child::Rule(rule) => rule.children.iter().collect(),
+                child => ::std::vec![child],
+            })
+            .filter_map(|child| match child {
+                Child::Scenario(scenario) => Some(scenario),
+                _ => None,
+            })
+    }
+}
+
+impl Writer {
+    fn push(&mut self, indentation: usize, text: &str) -> usize {
+        self.source.push_str(&"  ".repeat(indentation));
+        self.source.push_str(&text.replace(['\r', '\n'], " "));
+        self.source.push('\n');
+
+        self.line += 1;
+        self.line
+    }
+
+    fn blank(&mut self) {
+        self.source.push('\n');
+        self.line += 1;
+    }
+
+    /// Returns the line of the keyword.
+    fn keyword(&mut self, indentation: usize, (tags, text): (&[impl AsRef<str>], ::std::string::String)) -> usize {
+        if !tags.is_empty() {
+            self.push(indentation, &tag_line(tags));
+        }
+
+        self.push(indentation, &text)
+    }
+
+    /// Returns the line of the step, which its data table or doc string, if
+    /// any, follows.
+    fn step(&mut self, indentation: usize, step: &StepReport) -> usize {
+        let line = self.push(indentation, &::std::format!("{} {}", step.label.keyword(), step.description));
+
+        for argument in step.argument.iter().flat_map(StepArgument::lines) {
+            self.push(indentation + 1, &argument);
+        }
+
+        line
+    }
+
+    /// Backgrounds are written once, from the first scenario that has steps
+    /// with the given origin.
+    fn background<'a>(
+        &mut self,
+        indentation: usize,
+        (scenarios, origin): (&[&'a ScenarioReport], StepOrigin),
+    ) -> ::core::option::Option<BackgroundNode<'a>> {
+        let steps = scenarios
+            .iter()
+            .map(|scenario| &scenario.steps)
+            .find(|steps| steps.iter().any(|step| step.origin == origin))?;
+
+        self.blank();
+        let line = self.push(indentation, "Background:");
+
+        let steps = steps
+            .iter()
+            .filter(|step| step.origin == origin)
+            .map(|step| StepNode {
+                step,
+                line: self.step(indentation + 1, step),
+            })
+            .collect();
+
+        Some(BackgroundNode {
+            line,
+            steps,
+        })
+    }
+
+    fn scenario<'a>(
+        &mut self,
+        indentation: usize,
+        (scenario, inherited, [feature_background, rule_background]): (
+            &'a ScenarioReport,
+            &[aliases::string::String],
+            [::core::option::Option<&BackgroundNode<'a>>; 2],
+        ),
+    ) -> ScenarioNode<'a> {
+        let tags = scenario
+            .tags
+            .iter()
+            .filter(|tag| !inherited.contains(tag))
+            .map(|tag| &**tag)
+            .collect::<::std::vec::Vec<_>>();
+
+        self.blank();
+        let line = self.keyword(indentation, (&tags, ::std::format!("Scenario: {}", scenario.description)));
+
+        // Steps of backgrounds point at the background they were written under,
+        // whichever scenario they were reported by.
+        let mut feature_background = feature_background.into_iter().flat_map(|background| &background.steps);
+        let mut rule_background = rule_background.into_iter().flat_map(|background| &background.steps);
+
+        let steps = scenario
+            .steps
+            .iter()
+            .map(|step| match step.origin {
+                StepOrigin::Scenario => StepNode {
+                    step,
+                    line: self.step(indentation + 1, step),
+                },
+                StepOrigin::FeatureBackground => StepNode {
+                    step,
+                    line: feature_background.next().map_or(0, |background| background.line),
+                },
+                StepOrigin::RuleBackground => StepNode {
+                    step,
+                    line: rule_background.next().map_or(0, |background| background.line),
+                },
+            })
+            .collect();
+
+        ScenarioNode {
+            scenario,
+            line,
+            tags,
+            steps,
+        }
+    }
+}
+
+/// Turns a name into an identifier made of lowercase letters, digits and
+/// dashes, as Cucumber does for `id`s.
+pub(crate) fn slug(name: &str) -> ::std::string::String {
+    name.split(|char: char| !char.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect::<::std::vec::Vec<_>>()
+        .join("-")
+}
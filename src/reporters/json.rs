@@ -0,0 +1,119 @@
+/// Just enough of JSON to write reports, printed without whitespace.
+pub(crate) enum Json {
+    Bool(bool),
+    Number(u128),
+    String(::std::string::String),
+    Array(::std::vec::Vec<Json>),
+    Object(::std::vec::Vec<(&'static str, Json)>),
+}
+
+impl Json {
+    pub(crate) fn object<const N: usize>(members: [(&'static str, Json); N]) -> Self {
+        Self::Object(members.into())
+    }
+
+    /// Adds a member to an object, which is left as is if it is anything else.
+    pub(crate) fn with(mut self, key: &'static str, value: impl Into<Json>) -> Self {
+        if let Self::Object(ref mut members) = self {
+            members.push((key, value.into()));
+        }
+
+        self
+    }
+}
+
+impl From<bool> for Json {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<usize> for Json {
+    fn from(value: usize) -> Self {
+        Self::Number(value as u128)
+    }
+}
+
+impl From<u128> for Json {
+    fn from(value: u128) -> Self {
+        Self::Number(value)
+    }
+}
+
+impl From<&str> for Json {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_owned())
+    }
+}
+
+impl From<::std::string::String> for Json {
+    fn from(value: ::std::string::String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl<T> FromIterator<T> for Json
+where
+    T: Into<Json>,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(values: I) -> Self {
+        Self::Array(values.into_iter().map(Into::into).collect())
+    }
+}
+
+impl ::core::fmt::Display for Json {
+    fn fmt(&self, formatter: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self {
+            Self::Bool(value) => ::core::write!(formatter, "{}", value),
+            Self::Number(value) => ::core::write!(formatter, "{}", value),
+            Self::String(value) => escape(formatter, value),
+
+            Self::Array(values) => {
+                formatter.write_str("[")?;
+
+                for (index, value) in values.iter().enumerate() {
+                    if index > 0 {
+                        formatter.write_str(",")?;
+                    }
+
+                    ::core::write!(formatter, "{}", value)?;
+                }
+
+                formatter.write_str("]")
+            },
+
+            Self::Object(members) => {
+                formatter.write_str("{")?;
+
+                for (index, (key, value)) in members.iter().enumerate() {
+                    if index > 0 {
+                        formatter.write_str(",")?;
+                    }
+
+                    escape(formatter, key)?;
+                    ::core::write!(formatter, ":{}", value)?;
+                }
+
+                formatter.write_str("}")
+            },
+        }
+    }
+}
+
+fn escape(formatter: &mut ::core::fmt::Formatter<'_>, text: &str) -> ::core::fmt::Result {
+    formatter.write_str("\"")?;
+
+    for char in text.chars() {
+        match char {
+            '"' => formatter.write_str("\\\"")?,
+            '\\' => formatter.write_str("\\\\")?,
+            '\n' => formatter.write_str("\\n")?,
+            '\r' => formatter.write_str("\\r")?,
+            '\t' => formatter.write_str("\\t")?,
+            char if char.is_control() => ::core::write!(formatter, "\\u{:04x}", char as u32)?,
+            char => ::core::write!(formatter, "{}", char)?,
+        }
+    }
+
+    formatter.write_str("\"")
+}
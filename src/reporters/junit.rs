@@ -0,0 +1,167 @@
+use crate::reporters::*;
+
+/// Renders one `<testsuite>` per feature and one `<testcase>` per scenario,
+/// following the schema understood by most CI servers. Failures carry the
+/// trace of the steps of their scenario, and scenarios whose world could not be
+/// built are reported as errors rather than failures. Earlier attempts of
+/// retried scenarios follow the outcome of their last run.
+pub(crate) fn render(scenarios: &[&ScenarioReport]) -> ::std::string::String {
+    let mut xml = ::std::string::String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+
+    xml.push_str(&::std::format!(
+        "<testsuites name=\"litmus\" tests=\"{}\" failures=\"{}\" errors=\"{}\" skipped=\"{}\" time=\"{:.3}\">\n",
+        scenarios.len(),
+        count_failures(scenarios.iter().copied()),
+        count_errors(scenarios.iter().copied()),
+        count_skipped(scenarios.iter().copied()),
+        sum_durations(scenarios.iter().copied()).as_secs_f64(),
+    ));
+
+    for scenarios in features(scenarios) {
+        let feature = scenarios[0].feature.name("Feature");
+
+        xml.push_str(&::std::format!(
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"{}\" skipped=\"{}\" time=\"{:.3}\">\n",
+            escape(&feature),
+            scenarios.len(),
+            count_failures(scenarios.iter().copied()),
+            count_errors(scenarios.iter().copied()),
+            count_skipped(scenarios.iter().copied()),
+            sum_durations(scenarios.iter().copied()).as_secs_f64(),
+        ));
+
+        for scenario in scenarios {
+            let classname = match scenario.rule {
+                Some(ref rule) => ::std::format!("{}.{}", feature, rule.name("Rule")),
+                None => feature.to_string(),
+            };
+
+            xml.push_str(&::std::format!(
+                "    <testcase name=\"{}\" classname=\"{}\" time=\"{:.3}\"",
+                escape(&scenario.description),
+                escape(&classname),
+                scenario.elapsed().as_secs_f64(),
+            ));
+
+            let outcome = match scenario.outcome {
+                Some(Outcome::Passed) => ::std::string::String::new(),
+
+                Some(Outcome::Failed(ref failed)) => ::std::format!(
+                    "{}      <failure message=\"{}\" type=\"failure\">{}</failure>\n",
+                    properties(failed),
+                    escape(failed.message().lines().next().unwrap_or_default()),
+                    escape(&scenario.trace(false)),
+                ),
+
+                Some(Outcome::TimedOut(ref failed)) => ::std::format!(
+                    "      <failure message=\"{}\" type=\"timeout\">{}</failure>\n",
+                    escape(&failed.message),
+                    escape(&scenario.trace(false)),
+                ),
+
+                Some(Outcome::SetupFailed(ref failed)) => ::std::format!(
+                    "      <error message=\"{}\" type=\"setup\">{}</error>\n",
+                    escape(failed.message().lines().next().unwrap_or_default()),
+                    escape(&scenario.trace(false)),
+                ),
+
+                Some(Outcome::Skipped) => ::std::string::String::from("      <skipped message=\"fail-fast\"/>\n"),
+
+                None => ::std::string::String::from("      <skipped message=\"ignored\"/>\n"),
+            };
+
+            let attempts = scenario
+                .attempts
+                .iter()
+                .map(|attempt| attempt_element(attempt, scenario.flaky()))
+                .collect::<::std::string::String>();
+
+            match (outcome + &attempts).as_str() {
+                "" => xml.push_str("/>\n"),
+                elements => xml.push_str(&::std::format!(">\n{}    </testcase>\n", elements)),
+            }
+        }
+
+        xml.push_str("  </testsuite>\n");
+    }
+
+    xml.push_str("</testsuites>\n");
+    xml
+}
+
+/// A failed attempt of a retried scenario, as reported by Maven Surefire: as a
+/// flaky failure if the scenario went on to pass, or as a rerun failure if it
+/// did not.
+fn attempt_element(attempt: &Attempt, flaky: bool) -> ::std::string::String {
+    let Some(failed) = attempt.outcome.failed() else {
+        return ::std::string::String::new();
+    };
+
+    let (element, kind) = match (flaky, &attempt.outcome) {
+        (true, Outcome::SetupFailed(_)) => ("flakyError", "setup"),
+        (true, Outcome::TimedOut(_)) => ("flakyFailure", "timeout"),
+        (true, _) => ("flakyFailure", "failure"),
+        (false, Outcome::SetupFailed(_)) => ("rerunError", "setup"),
+        (false, Outcome::TimedOut(_)) => ("rerunFailure", "timeout"),
+        (false, _) => ("rerunFailure", "failure"),
+    };
+
+    ::std::format!(
+        "      <{} message=\"{}\" type=\"{}\">\n        <stackTrace>{}</stackTrace>\n      </{}>\n",
+        element,
+        escape(failed.message().lines().next().unwrap_or_default()),
+        kind,
+        escape(&failed.to_string()),
+        element,
+    )
+}
+
+/// The expected and actual values of the failure, if any, so that CI servers
+/// may show them apart from the trace.
+fn properties(failed: &Failed) -> ::std::string::String {
+    let properties = [("expected", failed.expected()), ("actual", failed.actual())]
+        .into_iter()
+        .filter_map(|(name, value)| {
+            Some(::std::format!("        <property name=\"{}\" value=\"{}\"/>\n", name, escape(value?)))
+        })
+        .collect::<::std::string::String>();
+
+    match properties.is_empty() {
+        true => properties,
+        false => ::std::format!("      <properties>\n{}      </properties>\n", properties),
+    }
+}
+
+fn count_failures<'a>(scenarios: impl Iterator<Item = &'a ScenarioReport>) -> usize {
+    scenarios
+        .filter(|scenario| ::core::matches!(scenario.outcome, Some(Outcome::Failed(_) | Outcome::TimedOut(_))))
+        .count()
+}
+
+fn count_errors<'a>(scenarios: impl Iterator<Item = &'a ScenarioReport>) -> usize {
+    scenarios.filter(|scenario| ::core::matches!(scenario.outcome, Some(Outcome::SetupFailed(_)))).count()
+}
+
+fn count_skipped<'a>(scenarios: impl Iterator<Item = &'a ScenarioReport>) -> usize {
+    scenarios.filter(|scenario| ::core::matches!(scenario.outcome, None | Some(Outcome::Skipped))).count()
+}
+
+fn sum_durations<'a>(scenarios: impl Iterator<Item = &'a ScenarioReport>) -> ::core::time::Duration {
+    scenarios.map(ScenarioReport::elapsed).sum()
+}
+
+fn escape(text: &str) -> ::std::string::String {
+    text.chars().fold(::std::string::String::with_capacity(text.len()), |mut escaped, char| {
+        match char {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            char if char.is_control() && !::core::matches!(char, '\n' | '\r' | '\t') => {},
+            char => escaped.push(char),
+        }
+
+        escaped
+    })
+}
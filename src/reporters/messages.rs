@@ -0,0 +1,613 @@
+use crate::reporters::document::*;
+use crate::reporters::json::*;
+use crate::reporters::*;
+
+const PROTOCOL_VERSION: &str = "27.0.0";
+
+/// Renders newline-delimited [Cucumber Messages](https://github.com/cucumber/messages),
+/// which the HTML formatter and Cucumber Reports consume.
+///
+/// Every feature is sent as the source of its [`Document`], with one pickle and
+/// one test case per scenario. Identifiers are assigned incrementally, and
+/// timestamps of steps are derived from when their scenario started.
+pub(crate) fn render(scenarios: &[&ScenarioReport], run: &Run) -> ::std::string::String {
+    let documents =
+        features(scenarios).iter().map(|scenarios| Document::new(scenarios)).collect::<::std::vec::Vec<_>>();
+
+    let mut envelopes = ::std::vec![envelope("meta", meta())];
+    let mut ids = Ids::default();
+
+    let pickles = documents
+        .iter()
+        .flat_map(|document| {
+            let pickles = Pickle::of(document, &mut ids);
+
+            envelopes.push(envelope(
+                "source",
+                Json::object([
+                    ("uri", (*document.uri).into()),
+                    ("data", (*document.source).into()),
+                    ("mediaType", "text/x.cucumber.gherkin+plain".into()),
+                ]),
+            ));
+            envelopes.push(envelope("gherkinDocument", gherkin_document(document)));
+            envelopes.extend(pickles.iter().map(|pickle| envelope("pickle", pickle.render(&document.uri))));
+
+            pickles
+        })
+        .collect::<::std::vec::Vec<_>>();
+
+    envelopes.extend(
+        [HookKind::BeforeAll, HookKind::Setup, HookKind::Before, HookKind::After]
+            .into_iter()
+            .filter(|kind| pickles.iter().any(|pickle| pickle.scenario.hooks.iter().any(|hook| hook.kind == *kind)))
+            .map(|kind| {
+                envelope(
+                    "hook",
+                    Json::object([
+                        ("id", kind.name().into()),
+                        ("name", kind.name().into()),
+                        ("sourceReference", Json::object([])),
+                    ]),
+                )
+            }),
+    );
+
+    envelopes.push(envelope("testRunStarted", Json::object([("timestamp", timestamp(run.started))])));
+
+    let cases = pickles.iter().map(|pickle| TestCase::of(pickle, &mut ids)).collect::<::std::vec::Vec<_>>();
+
+    envelopes.extend(cases.iter().map(|case| envelope("testCase", case.render())));
+    envelopes.extend(cases.iter().flat_map(|case| case.run(&mut ids, run)));
+
+    let success = scenarios.iter().all(|scenario| {
+        !::core::matches!(scenario.outcome, Some(Outcome::Failed(_) | Outcome::SetupFailed(_) | Outcome::TimedOut(_)))
+    });
+
+    envelopes.push(envelope(
+        "testRunFinished",
+        Json::object([("success", success.into()), ("timestamp", timestamp(run.finished))]),
+    ));
+
+    envelopes.iter().map(|envelope| ::std::format!("{}\n", envelope)).collect()
+}
+
+#[derive(::core::default::Default)]
+struct Ids {
+    next: usize,
+}
+
+impl Ids {
+    fn next(&mut self) -> ::std::string::String {
+        self.next += 1;
+        self.next.to_string()
+    }
+}
+
+struct Pickle<'a> {
+    id: ::std::string::String,
+
+    scenario: &'a ScenarioReport,
+    node: ::std::string::String,
+
+    /// Identifiers of pickle steps, alongside the steps they were made from.
+    steps: ::std::vec::Vec<(::std::string::String, StepNode<'a>)>,
+    tags: ::std::vec::Vec<(&'a str, ::std::string::String)>,
+}
+
+impl<'a> Pickle<'a> {
+    fn of(document: &Document<'a>, ids: &mut Ids) -> ::std::vec::Vec<Self> {
+        let feature_tags = tag_nodes(&document.feature.tags, (&document.uri, document.line));
+
+        let scenarios = document.children.iter().flat_map(|child| match child {
+            Child::Rule(rule) => {
+                let mut tags = feature_tags.clone();
+                tags.extend(tag_nodes(&rule.rule.tags, (&document.uri, rule.line)));
+
+                rule.children.iter().map(|child| (child, tags.clone())).collect()
+            },
+            child => ::std::vec![(child, feature_tags.clone())],
+        });
+
+        scenarios
+            .filter_map(|(child, inherited)| match child {
+                Child::Scenario(scenario) => Some((scenario, inherited)),
+                _ => None,
+            })
+            .map(|(scenario, inherited)| {
+                let own = tag_nodes(&scenario.tags, (&document.uri, scenario.line));
+
+                Self {
+                    id: ids.next(),
+
+                    scenario: scenario.scenario,
+                    node: node(&document.uri, scenario.line),
+
+                    steps: scenario.steps.iter().map(|step| (ids.next(), *step)).collect(),
+                    tags: scenario
+                        .scenario
+                        .tags
+                        .iter()
+                        .filter_map(|tag| {
+                            let (_, id) = own.iter().chain(&inherited).find(|(name, _)| *name == &**tag)?;
+                            Some((&**tag, id.clone()))
+                        })
+                        .collect(),
+                }
+            })
+            .collect()
+    }
+
+    fn render(&self, uri: &str) -> Json {
+        let types = self.steps.iter().scan("Unknown", |kind, (_, step)| {
+            *kind = match step.step.label {
+                StepLabel::Given => "Context",
+                StepLabel::When => "Action",
+                StepLabel::Then => "Outcome",
+                StepLabel::And | StepLabel::But => kind,
+            };
+
+            Some(*kind)
+        });
+
+        Json::object([
+            ("id", (*self.id).into()),
+            ("uri", uri.into()),
+            ("name", (*self.scenario.description).into()),
+            ("language", "en".into()),
+            (
+                "steps",
+                self.steps
+                    .iter()
+                    .zip(types)
+                    .map(|((id, step), kind)| {
+                        let json = Json::object([
+                            ("astNodeIds", [node(uri, step.line)].into_iter().collect()),
+                            ("id", (**id).into()),
+                            ("text", (*step.step.description).into()),
+                            ("type", kind.into()),
+                        ]);
+
+                        match &step.step.argument {
+                            Some(argument) => json.with("argument", pickle_argument(argument)),
+                            None => json,
+                        }
+                    })
+                    .collect(),
+            ),
+            (
+                "tags",
+                self.tags
+                    .iter()
+                    .map(|(name, id)| Json::object([("name", tagged(name).into()), ("astNodeId", (**id).into())]))
+                    .collect(),
+            ),
+            ("astNodeIds", [&*self.node].into_iter().collect()),
+        ])
+    }
+}
+
+struct TestCase<'a> {
+    id: ::std::string::String,
+    pickle: &'a Pickle<'a>,
+
+    /// Hooks that ran before the steps, the steps, and hooks that ran after
+    /// them, in order. Hooks are included if they ran in any attempt.
+    steps: ::std::vec::Vec<TestStep<'a>>,
+}
+
+struct TestStep<'a> {
+    id: ::std::string::String,
+    source: TestStepSource<'a>,
+}
+
+enum TestStepSource<'a> {
+    Hook(HookKind),
+
+    /// The pickle step, and where the step sits among those of its scenario.
+    Step(&'a str, usize),
+}
+
+/// The outcomes of the steps and hooks of one attempt of a scenario.
+struct Execution<'a> {
+    steps: ::std::vec::Vec<(&'a StepOutcome, ::core::time::Duration)>,
+    hooks: &'a [HookReport],
+    started: ::std::time::SystemTime,
+}
+
+impl<'a> TestCase<'a> {
+    fn of(pickle: &'a Pickle<'a>, ids: &mut Ids) -> Self {
+        let scenario = pickle.scenario;
+
+        let ran = |kind: &HookKind| {
+            scenario
+                .hooks
+                .iter()
+                .chain(scenario.attempts.iter().flat_map(|attempt| &attempt.hooks))
+                .any(|hook| hook.kind == *kind)
+        };
+
+        let hooks = |kinds: &[HookKind], ids: &mut Ids| {
+            kinds
+                .iter()
+                .filter(|kind| ran(kind))
+                .map(|kind| TestStep {
+                    id: ids.next(),
+                    source: TestStepSource::Hook(*kind),
+                })
+                .collect::<::std::vec::Vec<_>>()
+        };
+
+        let mut steps = hooks(&[HookKind::BeforeAll, HookKind::Setup, HookKind::Before], ids);
+
+        steps.extend(pickle.steps.iter().enumerate().map(|(index, (id, _))| TestStep {
+            id: ids.next(),
+            source: TestStepSource::Step(id, index),
+        }));
+
+        steps.extend(hooks(&[HookKind::After], ids));
+
+        Self {
+            id: ids.next(),
+            pickle,
+
+            steps,
+        }
+    }
+
+    fn render(&self) -> Json {
+        Json::object([
+            ("id", (*self.id).into()),
+            ("pickleId", (*self.pickle.id).into()),
+            (
+                "testSteps",
+                self.steps
+                    .iter()
+                    .map(|step| match step.source {
+                        TestStepSource::Hook(kind) =>
+                            Json::object([("id", (*step.id).into()), ("hookId", kind.name().into())]),
+                        TestStepSource::Step(pickle_step, _) => Json::object([
+                            ("id", (*step.id).into()),
+                            ("pickleStepId", pickle_step.into()),
+                            ("stepDefinitionIds", Json::Array(::std::vec::Vec::new())),
+                            ("stepMatchArgumentsLists", Json::Array(::std::vec::Vec::new())),
+                        ]),
+                    })
+                    .collect(),
+            ),
+        ])
+    }
+
+    /// Envelopes from when the test case started to when it finished, once
+    /// per attempt of a retried scenario. Scenarios that never ran are taken to
+    /// have been skipped when the run started.
+    fn run(&self, ids: &mut Ids, run: &Run) -> ::std::vec::Vec<Json> {
+        let scenario = self.pickle.scenario;
+
+        let attempts = scenario.attempts.iter().map(|attempt| Execution {
+            steps: attempt.steps.iter().map(|(outcome, duration)| (outcome, *duration)).collect(),
+            hooks: &attempt.hooks,
+            started: attempt.started,
+        });
+
+        let last = Execution {
+            steps: scenario.steps.iter().map(|step| (&step.outcome, step.duration)).collect(),
+            hooks: &scenario.hooks,
+            started: scenario.started.unwrap_or(run.started),
+        };
+
+        let mut envelopes = ::std::vec::Vec::new();
+
+        for (attempt, execution) in attempts.chain([last]).enumerate() {
+            let retried = attempt < scenario.attempts.len();
+            envelopes.extend(self.execute(ids, (attempt, &execution, retried)));
+        }
+
+        envelopes
+    }
+
+    fn execute(
+        &self,
+        ids: &mut Ids,
+        (attempt, execution, retried): (usize, &Execution<'_>, bool),
+    ) -> ::std::vec::Vec<Json> {
+        let started = ids.next();
+        let mut now = execution.started;
+
+        let mut envelopes = ::std::vec![envelope(
+            "testCaseStarted",
+            Json::object([
+                ("attempt", attempt.into()),
+                ("id", (*started).into()),
+                ("testCaseId", (*self.id).into()),
+                ("timestamp", timestamp(now)),
+            ])
+        )];
+
+        for step in &self.steps {
+            let (outcome, duration) = execution.outcome(step);
+
+            envelopes.push(envelope(
+                "testStepStarted",
+                Json::object([
+                    ("testCaseStartedId", (*started).into()),
+                    ("testStepId", (*step.id).into()),
+                    ("timestamp", timestamp(now)),
+                ]),
+            ));
+
+            now += duration;
+
+            envelopes.push(envelope(
+                "testStepFinished",
+                Json::object([
+                    ("testCaseStartedId", (*started).into()),
+                    ("testStepId", (*step.id).into()),
+                    ("testStepResult", result(outcome, duration)),
+                    ("timestamp", timestamp(now)),
+                ]),
+            ));
+        }
+
+        envelopes.push(envelope(
+            "testCaseFinished",
+            Json::object([
+                ("testCaseStartedId", (*started).into()),
+                ("timestamp", timestamp(now)),
+                ("willBeRetried", retried.into()),
+            ]),
+        ));
+
+        envelopes
+    }
+}
+
+impl Execution<'_> {
+    /// Steps and hooks that did not run in this attempt are skipped.
+    fn outcome(&self, step: &TestStep<'_>) -> (&StepOutcome, ::core::time::Duration) {
+        let outcome = match step.source {
+            TestStepSource::Hook(kind) =>
+                self.hooks.iter().find(|hook| hook.kind == kind).map(|hook| (&hook.outcome, hook.duration)),
+            TestStepSource::Step(_, index) => self.steps.get(index).copied(),
+        };
+
+        outcome.unwrap_or((&StepOutcome::Skipped, ::core::time::Duration::ZERO))
+    }
+}
+
+fn result(outcome: &StepOutcome, duration: ::core::time::Duration) -> Json {
+    let status = outcome.status().name().to_uppercase();
+
+    let result = Json::object([("duration", self::duration(duration)), ("status", status.into())]);
+
+    match outcome.failed() {
+        Some(failed) => result.with("message", failed.to_string()).with("exception", exception(failed)),
+        None => result,
+    }
+}
+
+fn gherkin_document(document: &Document<'_>) -> Json {
+    fn children(uri: &str, nodes: &[Child<'_>]) -> Json {
+        nodes
+            .iter()
+            .map(|child| match child {
+                Child::Background(background) => Json::object([(
+                    "background",
+                    Json::object([
+                        ("id", node(uri, background.line).into()),
+                        ("location", location(background.line)),
+                        ("keyword", "Background".into()),
+                        ("name", "".into()),
+                        ("description", "".into()),
+                        ("steps", steps(uri, &background.steps)),
+                    ]),
+                )]),
+
+                Child::Scenario(scenario) => {
+                    let own = scenario
+                        .scenario
+                        .steps
+                        .iter()
+                        .zip(&scenario.steps)
+                        .filter(|(step, _)| step.origin == StepOrigin::Scenario)
+                        .map(|(_, step)| *step)
+                        .collect::<::std::vec::Vec<_>>();
+
+                    Json::object([(
+                        "scenario",
+                        Json::object([
+                            ("id", node(uri, scenario.line).into()),
+                            ("location", location(scenario.line)),
+                            ("tags", tags(&scenario.tags, (uri, scenario.line))),
+                            ("keyword", "Scenario".into()),
+                            ("name", (*scenario.scenario.description).into()),
+                            ("description", scenario.scenario.narrative.as_deref().unwrap_or_default().into()),
+                            ("steps", steps(uri, &own)),
+                            ("examples", Json::Array(::std::vec::Vec::new())),
+                        ]),
+                    )])
+                },
+
+                Child::Rule(rule) => Json::object([(
+                    "rule",
+                    Json::object([
+                        ("id", node(uri, rule.line).into()),
+                        ("location", location(rule.line)),
+                        ("tags", tags(&rule.rule.tags, (uri, rule.line))),
+                        ("keyword", "Rule".into()),
+                        ("name", (*rule.rule.name("Rule")).into()),
+                        ("description", rule.rule.narrative.as_deref().unwrap_or_default().into()),
+                        ("children", children(uri, &rule.children)),
+                    ]),
+                )]),
+            })
+            .collect()
+    }
+
+    fn steps(uri: &str, steps: &[StepNode<'_>]) -> Json {
+        steps
+            .iter()
+            .map(|step| {
+                let keyword_type = match step.step.label {
+                    StepLabel::Given => "Context",
+                    StepLabel::When => "Action",
+                    StepLabel::Then => "Outcome",
+                    StepLabel::And | StepLabel::But => "Conjunction",
+                };
+
+                let json = Json::object([
+                    ("id", node(uri, step.line).into()),
+                    ("location", location(step.line)),
+                    ("keyword", ::std::format!("{} ", step.step.label.keyword()).into()),
+                    ("keywordType", keyword_type.into()),
+                    ("text", (*step.step.description).into()),
+                ]);
+
+                match &step.step.argument {
+                    Some(StepArgument::DataTable(table)) =>
+                        json.with("dataTable", data_table(uri, (table, step.line + 1))),
+                    Some(StepArgument::DocString(doc_string)) =>
+                        json.with("docString", doc_string_node(doc_string, step.line + 1)),
+                    None => json,
+                }
+            })
+            .collect()
+    }
+
+    /// Rows follow one another from `line`.
+    fn data_table(uri: &str, (table, line): (&DataTable, usize)) -> Json {
+        let rows = table.rows().iter().enumerate().map(|(index, cells)| {
+            Json::object([
+                ("id", node(uri, line + index).into()),
+                ("location", location(line + index)),
+                (
+                    "cells",
+                    cells
+                        .iter()
+                        .map(|cell| Json::object([("location", location(line + index)), ("value", (**cell).into())]))
+                        .collect(),
+                ),
+            ])
+        });
+
+        Json::object([("location", location(line)), ("rows", rows.collect())])
+    }
+
+    fn doc_string_node(doc_string: &DocString, line: usize) -> Json {
+        let json = Json::object([
+            ("location", location(line)),
+            ("content", doc_string.content().into()),
+            ("delimiter", "\"\"\"".into()),
+        ]);
+
+        match doc_string.content_type() {
+            Some(media_type) => json.with("mediaType", media_type),
+            None => json,
+        }
+    }
+
+    fn tags(tags: &[impl AsRef<str>], (uri, line): (&str, usize)) -> Json {
+        tag_nodes(tags, (uri, line))
+            .into_iter()
+            .map(|(name, id)| {
+                Json::object([("location", location(line - 1)), ("name", tagged(name).into()), ("id", id.into())])
+            })
+            .collect()
+    }
+
+    Json::object([
+        ("uri", (*document.uri).into()),
+        (
+            "feature",
+            Json::object([
+                ("location", location(document.line)),
+                ("tags", tags(&document.feature.tags, (&document.uri, document.line))),
+                ("language", "en".into()),
+                ("keyword", "Feature".into()),
+                ("name", (*document.feature.name("Feature")).into()),
+                ("description", document.feature.narrative.as_deref().unwrap_or_default().into()),
+                ("children", children(&document.uri, &document.children)),
+            ]),
+        ),
+        ("comments", Json::Array(::std::vec::Vec::new())),
+    ])
+}
+
+fn meta() -> Json {
+    Json::object([
+        ("protocolVersion", PROTOCOL_VERSION.into()),
+        (
+            "implementation",
+            Json::object([("name", "litmus".into()), ("version", ::core::env!("CARGO_PKG_VERSION").into())]),
+        ),
+        ("runtime", Json::object([("name", "rust".into())])),
+        ("os", Json::object([("name", ::std::env::consts::OS.into())])),
+        ("cpu", Json::object([("name", ::std::env::consts::ARCH.into())])),
+    ])
+}
+
+fn envelope(kind: &'static str, message: Json) -> Json {
+    Json::object([(kind, message)])
+}
+
+/// Nodes of a Gherkin document are identified by the line they are written on,
+/// as no two nodes share one.
+/// Where the failure was raised stands in for a stack trace.
+fn exception(failed: &Failed) -> Json {
+    let exception = Json::object([("type", "litmus::Failed".into()), ("message", failed.message().into())]);
+
+    match failed.location() {
+        Some(location) => exception.with("stackTrace", ::std::format!("at {}", location)),
+        None => exception,
+    }
+}
+
+fn pickle_argument(argument: &StepArgument) -> Json {
+    match argument {
+        StepArgument::DataTable(table) => {
+            let rows = table.rows().iter().map(|cells| {
+                Json::object([("cells", cells.iter().map(|cell| Json::object([("value", (**cell).into())])).collect())])
+            });
+
+            Json::object([("dataTable", Json::object([("rows", rows.collect())]))])
+        },
+        StepArgument::DocString(doc_string) => {
+            let json = Json::object([("content", doc_string.content().into())]);
+
+            Json::object([("docString", match doc_string.content_type() {
+                Some(media_type) => json.with("mediaType", media_type),
+                None => json,
+            })])
+        },
+    }
+}
+
+fn node(uri: &str, line: usize) -> ::std::string::String {
+    ::std::format!("{}:{}", uri, line)
+}
+
+/// Tags are written together on the line before the keyword they apply to.
+fn tag_nodes<'a>(
+    tags: &'a [impl AsRef<str>],
+    (uri, line): (&str, usize),
+) -> ::std::vec::Vec<(&'a str, ::std::string::String)> {
+    tags.iter()
+        .enumerate()
+        .map(|(index, tag)| (tag.as_ref(), ::std::format!("{}:{}", node(uri, line - 1), index + 1)))
+        .collect()
+}
+
+fn location(line: usize) -> Json {
+    Json::object([("line", line.into())])
+}
+
+fn timestamp(time: ::std::time::SystemTime) -> Json {
+    duration(time.duration_since(::std::time::UNIX_EPOCH).unwrap_or_default())
+}
+
+fn duration(duration: ::core::time::Duration) -> Json {
+    Json::object([
+        ("seconds", u128::from(duration.as_secs()).into()),
+        ("nanos", u128::from(duration.subsec_nanos()).into()),
+    ])
+}
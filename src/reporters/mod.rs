@@ -0,0 +1,617 @@
+mod cucumber;
+mod document;
+pub(crate) mod json;
+mod junit;
+mod messages;
+pub(crate) mod pretty;
+mod trace;
+
+use crate::arguments::*;
+use crate::models::*;
+use crate::utils::aliases;
+
+/// Collects the outcome of every scenario turned into a trial, so that reports
+/// can be written once all trials have run.
+pub(crate) struct Collector {
+    started: ::std::time::SystemTime,
+
+    features: ::std::sync::atomic::AtomicUsize,
+    scenarios: ::std::sync::Mutex<::std::vec::Vec<ScenarioReport>>,
+
+    /// Set if a `before_all` hook failed and trials are to fail with it rather
+    /// than run.
+    before_all: ::std::sync::OnceLock<Failed>,
+
+    /// Whether failures printed to the console are coloured, known once
+    /// arguments are parsed.
+    colored: ::std::sync::atomic::AtomicBool,
+}
+
+#[derive(::core::clone::Clone)]
+pub(crate) struct Ancestor {
+    pub(crate) index: usize,
+    pub(crate) description: ::core::option::Option<aliases::string::String>,
+
+    /// Those declared on the feature or rule itself, leaving out those a rule
+    /// inherits from its feature.
+    pub(crate) tags: ::std::vec::Vec<aliases::string::String>,
+    pub(crate) narrative: ::core::option::Option<aliases::string::String>,
+}
+
+pub(crate) struct ScenarioReport {
+    pub(crate) feature: Ancestor,
+    pub(crate) rule: ::core::option::Option<Ancestor>,
+
+    pub(crate) description: aliases::string::String,
+    pub(crate) tags: ::std::vec::Vec<aliases::string::String>,
+    pub(crate) ignored: bool,
+
+    pub(crate) narrative: ::core::option::Option<aliases::string::String>,
+
+    /// Steps of the backgrounds that apply to the scenario come first.
+    pub(crate) steps: ::std::vec::Vec<StepReport>,
+    pub(crate) hooks: ::std::vec::Vec<HookReport>,
+
+    /// `None` if the scenario never ran, either because it is ignored or
+    /// because it was filtered out.
+    pub(crate) outcome: ::core::option::Option<Outcome>,
+    pub(crate) started: ::core::option::Option<::std::time::SystemTime>,
+    pub(crate) duration: ::core::time::Duration,
+
+    /// Earlier runs of a retried scenario, oldest first. Steps, hooks and the
+    /// outcome above are those of its last run.
+    pub(crate) attempts: ::std::vec::Vec<Attempt>,
+}
+
+/// A run of a scenario that failed, after which the scenario was retried.
+pub(crate) struct Attempt {
+    /// In the order of the steps of the scenario.
+    pub(crate) steps: ::std::vec::Vec<(StepOutcome, ::core::time::Duration)>,
+    pub(crate) hooks: ::std::vec::Vec<HookReport>,
+
+    /// Never `Outcome::Passed`.
+    pub(crate) outcome: Outcome,
+    pub(crate) started: ::std::time::SystemTime,
+    pub(crate) duration: ::core::time::Duration,
+}
+
+pub(crate) enum Outcome {
+    Passed,
+    Failed(Failed),
+
+    /// The world of the scenario could not be built, so none of its steps
+    /// ran.
+    SetupFailed(Failed),
+
+    /// The scenario ran past its timeout, and was given up on while a step or
+    /// hook was still running.
+    TimedOut(Failed),
+
+    /// The scenario never started, as the run failed fast before its turn.
+    Skipped,
+}
+
+pub(crate) struct StepReport {
+    pub(crate) label: StepLabel,
+    pub(crate) description: aliases::string::String,
+    pub(crate) argument: ::core::option::Option<StepArgument>,
+    pub(crate) origin: StepOrigin,
+
+    pub(crate) outcome: StepOutcome,
+    pub(crate) duration: ::core::time::Duration,
+}
+
+pub(crate) enum StepOutcome {
+    Passed,
+    Failed(Failed),
+
+    /// The step never ran, because a step or hook before it failed or because
+    /// the scenario is ignored.
+    Skipped,
+}
+
+/// What reports call the outcome of a step, following Cucumber.
+#[derive(::core::clone::Clone, ::core::marker::Copy, ::core::cmp::PartialEq, ::core::cmp::Eq)]
+pub(crate) enum Status {
+    Passed,
+    Failed,
+    Pending,
+    Undefined,
+    Ambiguous,
+    Skipped,
+}
+
+#[derive(::core::clone::Clone, ::core::marker::Copy, ::core::cmp::PartialEq, ::core::cmp::Eq)]
+pub(crate) enum StepOrigin {
+    FeatureBackground,
+    RuleBackground,
+    Scenario,
+}
+
+/// Scenario hooks that ran around the steps of a scenario. Step hooks are
+/// reported as part of the step they surround.
+pub(crate) struct HookReport {
+    pub(crate) kind: HookKind,
+
+    pub(crate) outcome: StepOutcome,
+    pub(crate) duration: ::core::time::Duration,
+}
+
+#[derive(::core::clone::Clone, ::core::marker::Copy, ::core::cmp::PartialEq, ::core::cmp::Eq)]
+pub(crate) enum HookKind {
+    /// Only reported when a failing `before_all` hook fails every trial.
+    BeforeAll,
+
+    /// Building the world, only reported if it failed.
+    Setup,
+
+    Before,
+    After,
+}
+
+/// Outcomes of the steps and hooks of a scenario, in the order they ran.
+///
+/// Clones share their records, so that a scenario running on a thread of its
+/// own can be told what it was running when it timed out.
+#[derive(::core::default::Default, ::core::clone::Clone)]
+pub(crate) struct Trace(aliases::sync::Arc<::std::sync::Mutex<Records>>);
+
+#[derive(::core::default::Default)]
+struct Records {
+    steps: ::std::vec::Vec<(StepOutcome, ::core::time::Duration)>,
+    hooks: ::std::vec::Vec<HookReport>,
+
+    /// The step or hook running, if any, and since when.
+    running: ::core::option::Option<(Running, ::std::time::Instant)>,
+
+    /// Set once the scenario timed out, after which nothing is recorded.
+    abandoned: bool,
+}
+
+#[derive(::core::clone::Clone, ::core::marker::Copy)]
+enum Running {
+    Step,
+    Hook(HookKind),
+}
+
+impl Trace {
+    pub(crate) fn step(&mut self, step: impl FnOnce() -> Fallible) -> Fallible {
+        let (result, duration) = self.run(Running::Step, step);
+
+        self.record(|records| records.steps.push((result.clone().into(), duration)));
+        result
+    }
+
+    /// Every scenario builds a world, so only failures are recorded.
+    pub(crate) fn setup<World>(&mut self, factory: impl FnOnce() -> Fallible<World>) -> Fallible<World> {
+        let (result, duration) = self.run(Running::Hook(HookKind::Setup), factory);
+
+        self.record(|records| {
+            if let Err(ref failed) = result {
+                records.hooks.push(HookReport {
+                    kind: HookKind::Setup,
+                    outcome: StepOutcome::Failed(failed.clone()),
+                    duration,
+                });
+            }
+        });
+
+        result
+    }
+
+    pub(crate) fn failed_setup(&self) -> bool {
+        self.records().hooks.iter().any(|hook| hook.kind == HookKind::Setup)
+    }
+
+    pub(crate) fn hook(&mut self, kind: HookKind, hook: impl FnOnce() -> Fallible) -> Fallible {
+        let (result, duration) = self.run(Running::Hook(kind), hook);
+
+        self.record(|records| {
+            records.hooks.push(HookReport {
+                kind,
+                outcome: result.clone().into(),
+                duration,
+            })
+        });
+
+        result
+    }
+
+    /// Fails whatever is running with `failed`, unless something already timed
+    /// out on its own, as asynchronous steps do, and stops recording.
+    ///
+    /// The records are moved to a trace of their own, which clones held by
+    /// the abandoned run do not share.
+    pub(crate) fn time_out(&mut self, failed: Failed) -> Failed {
+        let records = {
+            let mut records = self.records();
+            records.abandoned = true;
+
+            let timed_out = records
+                .steps
+                .iter()
+                .map(|(outcome, _)| outcome)
+                .chain(records.hooks.iter().map(|hook| &hook.outcome))
+                .filter_map(StepOutcome::failed)
+                .any(|failed| ::core::matches!(failed.kind, FailedKind::TimedOut));
+
+            match records.running.take().filter(|_| !timed_out) {
+                Some((Running::Step, start)) =>
+                    records.steps.push((StepOutcome::Failed(failed.clone()), start.elapsed())),
+                Some((Running::Hook(kind), start)) => records.hooks.push(HookReport {
+                    kind,
+                    outcome: StepOutcome::Failed(failed.clone()),
+                    duration: start.elapsed(),
+                }),
+                None => {},
+            }
+
+            Records {
+                steps: ::core::mem::take(&mut records.steps),
+                hooks: ::core::mem::take(&mut records.hooks),
+                ..::core::default::Default::default()
+            }
+        };
+
+        *self = Self(aliases::sync::Arc::new(::std::sync::Mutex::new(records)));
+        failed
+    }
+
+    fn run<T>(&mut self, running: Running, run: impl FnOnce() -> T) -> (T, ::core::time::Duration) {
+        let start = ::std::time::Instant::now();
+        self.records().running = Some((running, start));
+
+        (run(), start.elapsed())
+    }
+
+    fn record(&mut self, record: impl FnOnce(&mut Records)) {
+        let mut records = self.records();
+        records.running = None;
+
+        if !records.abandoned {
+            record(&mut records);
+        }
+    }
+
+    fn records(&self) -> ::std::sync::MutexGuard<'_, Records> {
+        self.0.lock().unwrap_or_else(::std::sync::PoisonError::into_inner)
+    }
+}
+
+impl ::core::default::Default for Collector {
+    fn default() -> Self {
+        Self {
+            started: ::std::time::SystemTime::now(),
+
+            features: ::core::default::Default::default(),
+            scenarios: ::core::default::Default::default(),
+
+            before_all: ::core::default::Default::default(),
+
+            colored: ::core::default::Default::default(),
+        }
+    }
+}
+
+impl Collector {
+    pub(crate) fn feature<World>(&self, feature: &Feature<World>) -> Ancestor {
+        Ancestor {
+            index: self.features.fetch_add(1, ::std::sync::atomic::Ordering::Relaxed),
+            description: feature.description.clone(),
+            tags: sorted(feature.tags.as_ref()),
+            narrative: feature.narrative.clone(),
+        }
+    }
+
+    /// Returns the index that the outcome of the scenario is later recorded
+    /// under.
+    pub(crate) fn register(&self, scenario: ScenarioReport) -> usize {
+        let mut scenarios = self.scenarios.lock().unwrap_or_else(::std::sync::PoisonError::into_inner);
+        scenarios.push(scenario);
+        scenarios.len() - 1
+    }
+
+    pub(crate) fn record<T>(
+        &self,
+        index: usize,
+        record: impl FnOnce(&mut ScenarioReport) -> T,
+    ) -> ::core::option::Option<T> {
+        let mut scenarios = self.scenarios.lock().unwrap_or_else(::std::sync::PoisonError::into_inner);
+        scenarios.get_mut(index).map(record)
+    }
+
+    pub(crate) fn inspect<T>(
+        &self,
+        index: usize,
+        inspect: impl FnOnce(&ScenarioReport) -> T,
+    ) -> ::core::option::Option<T> {
+        let scenarios = self.scenarios.lock().unwrap_or_else(::std::sync::PoisonError::into_inner);
+        scenarios.get(index).map(inspect)
+    }
+
+    pub(crate) fn fail_trials(&self, failed: Failed) {
+        let _ = self.before_all.set(failed);
+    }
+
+    pub(crate) fn failed_before_all(&self) -> ::core::option::Option<&Failed> {
+        self.before_all.get()
+    }
+
+    pub(crate) fn set_colored(&self, colored: bool) {
+        self.colored.store(colored, ::std::sync::atomic::Ordering::Relaxed);
+    }
+
+    pub(crate) fn colored(&self) -> bool {
+        self.colored.load(::std::sync::atomic::Ordering::Relaxed)
+    }
+
+    /// Lists scenarios that only passed once retried, which libtest reports as
+    /// having passed like any other.
+    pub(crate) fn print_flaky(&self) {
+        let scenarios = self.scenarios.lock().unwrap_or_else(::std::sync::PoisonError::into_inner);
+        let flaky = scenarios.iter().filter(|scenario| scenario.flaky()).collect::<::std::vec::Vec<_>>();
+
+        if flaky.is_empty() {
+            return;
+        }
+
+        ::std::println!("flaky:");
+        flaky.iter().for_each(|scenario| {
+            ::std::println!("    {} (passed after {})", scenario.description, retries(scenario.attempts.len()))
+        });
+        ::std::println!();
+    }
+
+    /// Counts scenarios that never started because the run failed fast,
+    /// which libtest reports as having passed.
+    pub(crate) fn print_skipped(&self) {
+        let scenarios = self.scenarios.lock().unwrap_or_else(::std::sync::PoisonError::into_inner);
+        let skipped = scenarios.iter().filter(|scenario| ::core::matches!(scenario.outcome, Some(Outcome::Skipped)));
+
+        match skipped.count() {
+            0 => {},
+            1 => ::std::println!("fail-fast: 1 scenario was skipped without running\n"),
+            count => ::std::println!("fail-fast: {} scenarios were skipped without running\n", count),
+        }
+    }
+
+    pub(crate) fn write(&self, reports: &[(crate::engine::configurations::ReportFormat, aliases::path::Path)]) {
+        let scenarios = self.scenarios.lock().unwrap_or_else(::std::sync::PoisonError::into_inner);
+
+        // Scenarios that never ran without being ignored were filtered out, and
+        // are left out of reports altogether.
+        let scenarios = scenarios
+            .iter()
+            .filter(|scenario| scenario.outcome.is_some() || scenario.ignored)
+            .collect::<::std::vec::Vec<_>>();
+
+        let run = Run {
+            started: self.started,
+            finished: ::std::time::SystemTime::now(),
+        };
+
+        for (format, path) in reports {
+            let contents = match format {
+                crate::engine::configurations::ReportFormat::JUnit => self::junit::render(&scenarios),
+                crate::engine::configurations::ReportFormat::CucumberJson => self::cucumber::render(&scenarios),
+                crate::engine::configurations::ReportFormat::CucumberMessages =>
+                    self::messages::render(&scenarios, &run),
+            };
+
+            let written = path
+                .parent()
+                .filter(|parent| !parent.as_os_str().is_empty())
+                .map_or(Ok(()), ::std::fs::create_dir_all)
+                .and_then(|()| ::std::fs::write(path, contents));
+
+            if let Err(err) = written {
+                ::std::eprintln!("error: failed to write report to `{}`: {}", path.display(), err);
+            }
+        }
+    }
+}
+
+/// When the run started and finished, for reports that timestamp events.
+pub(crate) struct Run {
+    pub(crate) started: ::std::time::SystemTime,
+    pub(crate) finished: ::std::time::SystemTime,
+}
+
+impl Ancestor {
+    /// Features and rules without a description are named after their position.
+    pub(crate) fn name(&self, kind: &str) -> aliases::string::String {
+        match self.description {
+            Some(ref description) => description.clone(),
+            None => ::std::format!("{} #{}", kind, self.index + 1).into(),
+        }
+    }
+}
+
+impl StepLabel {
+    pub(crate) fn keyword(self) -> &'static str {
+        match self {
+            Self::Given => "Given",
+            Self::When => "When",
+            Self::Then => "Then",
+
+            Self::And => "And",
+            Self::But => "But",
+        }
+    }
+}
+
+impl Outcome {
+    pub(crate) fn failed(&self) -> ::core::option::Option<&Failed> {
+        match self {
+            Self::Passed | Self::Skipped => None,
+            Self::Failed(failed) | Self::SetupFailed(failed) | Self::TimedOut(failed) => Some(failed),
+        }
+    }
+}
+
+impl StepOutcome {
+    pub(crate) fn status(&self) -> Status {
+        match self {
+            Self::Passed => Status::Passed,
+            Self::Failed(failed) => match failed.kind {
+                FailedKind::Failed => Status::Failed,
+                FailedKind::Pending => Status::Pending,
+                FailedKind::Undefined => Status::Undefined,
+                FailedKind::Ambiguous => Status::Ambiguous,
+                FailedKind::TimedOut => Status::Failed,
+            },
+            Self::Skipped => Status::Skipped,
+        }
+    }
+
+    pub(crate) fn failed(&self) -> ::core::option::Option<&Failed> {
+        match self {
+            Self::Failed(failed) => Some(failed),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn timed_out(&self) -> bool {
+        self.failed().is_some_and(|failed| ::core::matches!(failed.kind, FailedKind::TimedOut))
+    }
+}
+
+impl From<Fallible> for StepOutcome {
+    fn from(result: Fallible) -> Self {
+        match result {
+            Ok(()) => Self::Passed,
+            Err(failed) => Self::Failed(failed),
+        }
+    }
+}
+
+impl Status {
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Self::Passed => "passed",
+            Self::Failed => "failed",
+            Self::Pending => "pending",
+            Self::Undefined => "undefined",
+            Self::Ambiguous => "ambiguous",
+            Self::Skipped => "skipped",
+        }
+    }
+}
+
+impl HookKind {
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Self::BeforeAll => "before_all",
+            Self::Setup => "setup",
+            Self::Before => "before_scenario",
+            Self::After => "after_scenario",
+        }
+    }
+}
+
+impl ScenarioReport {
+    /// Folds the trace of a run of the scenario into its report.
+    pub(crate) fn finish(&mut self, trace: Trace) {
+        let mut records = trace.records();
+
+        self.steps.iter_mut().zip(::core::mem::take(&mut records.steps)).for_each(|(step, (outcome, duration))| {
+            step.outcome = outcome;
+            step.duration = duration;
+        });
+
+        self.hooks = ::core::mem::take(&mut records.hooks);
+    }
+
+    /// Sets the last run of the scenario aside as a failed attempt, so that it
+    /// can run again. Nothing is set aside if it passed.
+    pub(crate) fn retry(&mut self) {
+        let Some(outcome) = self.outcome.take().filter(|outcome| outcome.failed().is_some()) else {
+            return;
+        };
+
+        self.attempts.push(Attempt {
+            steps: self
+                .steps
+                .iter_mut()
+                .map(|step| {
+                    (
+                        ::core::mem::replace(&mut step.outcome, StepOutcome::Skipped),
+                        ::core::mem::take(&mut step.duration),
+                    )
+                })
+                .collect(),
+            hooks: ::core::mem::take(&mut self.hooks),
+
+            outcome,
+            started: self.started.take().unwrap_or_else(::std::time::SystemTime::now),
+            duration: ::core::mem::take(&mut self.duration),
+        });
+    }
+
+    /// How long every run of the scenario took together.
+    pub(crate) fn elapsed(&self) -> ::core::time::Duration {
+        self.duration + self.attempts.iter().map(|attempt| attempt.duration).sum::<::core::time::Duration>()
+    }
+
+    /// Whether the scenario passed, but only once retried.
+    pub(crate) fn flaky(&self) -> bool {
+        ::core::matches!(self.outcome, Some(Outcome::Passed)) && !self.attempts.is_empty()
+    }
+
+    /// Names the step or hook that was running when the scenario timed out,
+    /// once the trace is folded in.
+    pub(crate) fn interrupted(&self, failed: Failed) -> Failed {
+        let step = self
+            .steps
+            .iter()
+            .find(|step| step.outcome.timed_out())
+            .map(|step| ::std::format!("`{} {}`", step.label.keyword(), step.description));
+
+        let hook = self.hooks.iter().find(|hook| hook.outcome.timed_out()).map(|hook| match hook.kind {
+            HookKind::Setup => ::std::string::String::from("world setup"),
+            kind => ::std::format!("the `{}` hook", kind.name()),
+        });
+
+        match step.or(hook) {
+            Some(running) => Failed {
+                message: ::std::format!("{} while running {}", failed.message, running).into(),
+                ..failed
+            },
+            None => failed,
+        }
+    }
+}
+
+/// Groups scenarios by the feature they belong to, in the order features were
+/// registered.
+pub(crate) fn features<'a>(scenarios: &[&'a ScenarioReport]) -> ::std::vec::Vec<::std::vec::Vec<&'a ScenarioReport>> {
+    let mut features = ::std::collections::BTreeMap::<usize, ::std::vec::Vec<&ScenarioReport>>::new();
+
+    scenarios.iter().for_each(|scenario| features.entry(scenario.feature.index).or_default().push(scenario));
+
+    features.into_values().collect()
+}
+
+/// "1 retry", "2 retries" and so on.
+pub(crate) fn retries(count: usize) -> ::std::string::String {
+    match count {
+        1 => ::std::string::String::from("1 retry"),
+        count => ::std::format!("{} retries", count),
+    }
+}
+
+/// Tags are kept without the `@` that reports write them with.
+pub(crate) fn tagged(tag: &str) -> ::std::string::String {
+    ::std::format!("@{}", tag)
+}
+
+/// Tags as written on the line above a Gherkin keyword.
+pub(crate) fn tag_line(tags: &[impl AsRef<str>]) -> ::std::string::String {
+    tags.iter().map(|tag| tagged(tag.as_ref())).collect::<::std::vec::Vec<_>>().join(" ")
+}
+
+pub(crate) fn sorted(tags: ::core::option::Option<&Tags>) -> ::std::vec::Vec<aliases::string::String> {
+    let mut tags = tags.into_iter().flatten().cloned().collect::<::std::vec::Vec<_>>();
+    tags.sort();
+    tags
+}
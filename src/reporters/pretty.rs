@@ -0,0 +1,318 @@
+use crate::reporters::*;
+
+/// Console output of the native engine, which prints scenarios beneath their
+/// feature and rule as Gherkin would have them, each step with a glyph for its
+/// outcome and how long it took.
+///
+/// Scenarios are expected in the order they were defined. Backgrounds are
+/// printed once, beneath their feature or rule, with the outcomes of the first
+/// scenario that ran them, and later scenarios only show background steps that
+/// did not pass.
+pub(crate) struct Pretty {
+    colored: bool,
+
+    /// Of the feature being printed.
+    tally: ::core::option::Option<Tally>,
+    rule: ::core::option::Option<usize>,
+}
+
+/// How many scenarios of a feature, and how many of their steps, ended how.
+struct Tally {
+    feature: usize,
+
+    scenarios: [usize; 4],
+    steps: [usize; 6],
+}
+
+const SCENARIO_STATUSES: [&str; 4] = ["passed", "failed", "skipped", "ignored"];
+
+const STEP_STATUSES: [Status; 6] =
+    [Status::Passed, Status::Failed, Status::Pending, Status::Undefined, Status::Ambiguous, Status::Skipped];
+
+impl Pretty {
+    pub(crate) fn new(colored: bool) -> Self {
+        Self {
+            colored,
+
+            tally: None,
+            rule: None,
+        }
+    }
+
+    pub(crate) fn scenario(&mut self, scenario: &ScenarioReport) -> ::std::string::String {
+        let mut rendered = ::std::string::String::new();
+        let mut printed = ::std::vec::Vec::new();
+
+        if self.tally.as_ref().is_none_or(|tally| tally.feature != scenario.feature.index) {
+            rendered.push_str(&self.finish());
+
+            self.tally = Some(Tally {
+                feature: scenario.feature.index,
+
+                scenarios: ::core::default::Default::default(),
+                steps: ::core::default::Default::default(),
+            });
+            self.rule = None;
+
+            rendered.push_str(&self.header(&scenario.feature, ("Feature", 0)));
+            rendered.push_str(&self.background(scenario, (StepOrigin::FeatureBackground, 2)));
+            printed.push(StepOrigin::FeatureBackground);
+        }
+
+        let indent = match scenario.rule {
+            Some(ref rule) => {
+                if self.rule != Some(rule.index) {
+                    self.rule = Some(rule.index);
+
+                    rendered.push_str(&self.header(rule, ("Rule", 2)));
+                    rendered.push_str(&self.background(scenario, (StepOrigin::RuleBackground, 4)));
+                    printed.push(StepOrigin::RuleBackground);
+                }
+
+                4
+            },
+            None => 2,
+        };
+
+        rendered.push_str(&self.body(scenario, (indent, &printed)));
+        self.count(scenario);
+
+        rendered
+    }
+
+    /// Summarises the feature printed last, if any.
+    pub(crate) fn finish(&mut self) -> ::std::string::String {
+        let Some(tally) = self.tally.take() else {
+            return ::std::string::String::new();
+        };
+
+        let scenarios = summary(SCENARIO_STATUSES.into_iter().zip(tally.scenarios), ("scenario", "scenarios"));
+        let steps = summary(STEP_STATUSES.map(Status::name).into_iter().zip(tally.steps), ("step", "steps"));
+
+        ::std::format!("\n  {}\n  {}\n", self.paint(&scenarios, DIM), self.paint(&steps, DIM))
+    }
+
+    fn header(&self, ancestor: &Ancestor, (kind, indent): (&str, usize)) -> ::std::string::String {
+        let tags = match ancestor.tags.is_empty() {
+            true => ::std::string::String::new(),
+            false => ::std::format!("{:indent$}{}\n", "", self.paint(&tag_line(&ancestor.tags), CYAN),),
+        };
+
+        ::std::format!(
+            "\n{}{:indent$}{}: {}\n{}",
+            tags,
+            "",
+            kind,
+            ancestor.name(kind),
+            self.narrative(ancestor.narrative.as_deref(), indent + 2),
+        )
+    }
+
+    /// Dimmed, below the line of the feature, rule or scenario it describes.
+    fn narrative(&self, narrative: ::core::option::Option<&str>, indent: usize) -> ::std::string::String {
+        narrative
+            .into_iter()
+            .flat_map(str::lines)
+            .map(|line| ::std::format!("{:indent$}{}\n", "", self.paint(line, DIM)))
+            .collect()
+    }
+
+    fn background(&self, scenario: &ScenarioReport, (origin, indent): (StepOrigin, usize)) -> ::std::string::String {
+        let steps = scenario.steps.iter().filter(|step| step.origin == origin).collect::<::std::vec::Vec<_>>();
+
+        if steps.is_empty() {
+            return ::std::string::String::new();
+        }
+
+        let mut rendered = ::std::format!("\n{:indent$}Background:\n", "");
+        steps.into_iter().for_each(|step| rendered.push_str(&self.step(step, indent + 2)));
+
+        rendered
+    }
+
+    /// Background steps are only shown if they did not pass, unless their
+    /// background was just `printed` with them.
+    fn body(&self, scenario: &ScenarioReport, (indent, printed): (usize, &[StepOrigin])) -> ::std::string::String {
+        let (note, color) = match scenario.outcome {
+            None => (Some(::std::string::String::from("ignored")), YELLOW),
+            Some(Outcome::Skipped) => (Some(::std::string::String::from("skipped, the run failed fast")), CYAN),
+            Some(Outcome::Passed) if scenario.flaky() =>
+                (Some(::std::format!("passed after {}", retries(scenario.attempts.len()))), YELLOW),
+            Some(Outcome::Passed) => (None, GREEN),
+            Some(_) => (None, RED),
+        };
+
+        let mut rendered = ::std::format!(
+            "\n{:indent$}{} {}{}\n",
+            "",
+            self.paint("Scenario:", color),
+            scenario.description,
+            note.map(|note| self.paint(&::std::format!(" ({})", note), DIM)).unwrap_or_default(),
+        );
+
+        rendered.push_str(&self.narrative(scenario.narrative.as_deref(), indent + 2));
+
+        if ::core::matches!(scenario.outcome, None | Some(Outcome::Skipped)) {
+            return rendered;
+        }
+
+        let hooks = |kinds: &'static [HookKind]| {
+            scenario
+                .hooks
+                .iter()
+                .filter(move |hook| kinds.contains(&hook.kind) && hook.outcome.status() != Status::Passed)
+        };
+
+        let steps = scenario.steps.iter().filter(|step| {
+            step.origin == StepOrigin::Scenario
+                || !printed.contains(&step.origin)
+                    && !::core::matches!(step.outcome.status(), Status::Passed | Status::Skipped)
+        });
+
+        hooks(&[HookKind::BeforeAll, HookKind::Setup, HookKind::Before])
+            .for_each(|hook| rendered.push_str(&self.hook(hook, indent + 2)));
+        steps.for_each(|step| rendered.push_str(&self.step(step, indent + 2)));
+        hooks(&[HookKind::After]).for_each(|hook| rendered.push_str(&self.hook(hook, indent + 2)));
+
+        let failures = scenario
+            .attempts
+            .iter()
+            .filter_map(|attempt| attempt.outcome.failed().cloned())
+            .collect::<::std::vec::Vec<_>>();
+
+        if !failures.is_empty() {
+            let summary = match failures.len() {
+                1 => ::std::string::String::from("Failed once before, with"),
+                count => ::std::format!("Failed {} times before, with", count),
+            };
+
+            rendered.push_str(&self.failure(&Failed::listing(summary, &failures), indent + 2));
+        }
+
+        rendered
+    }
+
+    fn step(&self, step: &StepReport, indent: usize) -> ::std::string::String {
+        let mut rendered = self.line(
+            &step.outcome,
+            (&::std::format!("{} {}", step.label.keyword(), step.description), step.duration, indent),
+        );
+
+        step.argument.iter().flat_map(StepArgument::lines).for_each(|line| {
+            rendered.push_str(&::std::format!("{:indent$}{}\n", "", line, indent = indent + 4));
+        });
+
+        if let Some(failed) = step.outcome.failed() {
+            rendered.push_str(&self.failure(failed, indent + 4));
+        }
+
+        rendered
+    }
+
+    fn hook(&self, hook: &HookReport, indent: usize) -> ::std::string::String {
+        let name = match hook.kind {
+            HookKind::Setup => ::std::string::String::from("world setup"),
+            kind => ::std::format!("{} hook", kind.name()),
+        };
+
+        let mut rendered = self.line(&hook.outcome, (&name, hook.duration, indent));
+
+        if let Some(failed) = hook.outcome.failed() {
+            rendered.push_str(&self.failure(failed, indent + 4));
+        }
+
+        rendered
+    }
+
+    /// Steps and hooks that never ran have no duration worth printing.
+    fn line(
+        &self,
+        outcome: &StepOutcome,
+        (text, duration, indent): (&str, ::core::time::Duration, usize),
+    ) -> ::std::string::String {
+        let (glyph, color) = match outcome.status() {
+            Status::Passed => ("✔", GREEN),
+            Status::Failed => ("✘", RED),
+            Status::Pending => ("⏸", YELLOW),
+            Status::Undefined => ("?", YELLOW),
+            Status::Ambiguous => ("‼", YELLOW),
+            Status::Skipped => ("↷", CYAN),
+        };
+
+        let duration = match outcome {
+            StepOutcome::Skipped => ::std::string::String::new(),
+            _ => self.paint(&::std::format!(" ({:.2?})", duration), DIM),
+        };
+
+        ::std::format!("{:indent$}{} {}{}\n", "", self.paint(glyph, color), self.paint(text, color), duration)
+    }
+
+    fn failure(&self, failed: &Failed, indent: usize) -> ::std::string::String {
+        failed.render(self.colored).lines().map(|line| ::std::format!("{:indent$}{}\n", "", line)).collect()
+    }
+
+    fn count(&mut self, scenario: &ScenarioReport) {
+        let Some(ref mut tally) = self.tally else {
+            return;
+        };
+
+        let status = match scenario.outcome {
+            Some(Outcome::Passed) => 0,
+            Some(Outcome::Failed(_) | Outcome::SetupFailed(_) | Outcome::TimedOut(_)) => 1,
+            Some(Outcome::Skipped) => 2,
+            None => 3,
+        };
+
+        tally.scenarios[status] += 1;
+
+        if scenario.outcome.is_some() {
+            scenario.steps.iter().for_each(|step| {
+                let status =
+                    STEP_STATUSES.iter().position(|status| *status == step.outcome.status()).unwrap_or_default();
+                tally.steps[status] += 1;
+            });
+        }
+    }
+
+    fn paint(&self, text: &str, color: &str) -> ::std::string::String {
+        match self.colored {
+            true => ::std::format!("\x1b[{}m{}\x1b[0m", color, text),
+            false => text.to_owned(),
+        }
+    }
+}
+
+const GREEN: &str = "32";
+const RED: &str = "31";
+const YELLOW: &str = "33";
+const CYAN: &str = "36";
+const DIM: &str = "2";
+
+/// "3 scenarios (2 passed, 1 failed)", leaving out statuses that none ended
+/// with.
+fn summary<'a>(
+    counts: impl Iterator<Item = (&'a str, usize)>,
+    (singular, plural): (&str, &str),
+) -> ::std::string::String {
+    let counts = counts.filter(|(_, count)| *count > 0).collect::<::std::vec::Vec<_>>();
+    let total = counts.iter().map(|(_, count)| count).sum::<usize>();
+
+    let noun = match total {
+        1 => singular,
+        _ => plural,
+    };
+
+    match counts.is_empty() {
+        true => ::std::format!("0 {}", noun),
+        false => ::std::format!(
+            "{} {} ({})",
+            total,
+            noun,
+            counts
+                .iter()
+                .map(|(status, count)| ::std::format!("{} {}", count, status))
+                .collect::<::std::vec::Vec<_>>()
+                .join(", "),
+        ),
+    }
+}
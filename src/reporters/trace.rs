@@ -0,0 +1,68 @@
+use crate::reporters::*;
+
+impl ScenarioReport {
+    /// Renders every step of the scenario with its outcome, in the style of
+    /// libtest, so that failures show which step failed and which never ran.
+    ///
+    /// Hooks are only rendered if they failed, and diffs of failures are
+    /// coloured if `colored`. Failures of earlier attempts of a retried
+    /// scenario are listed last.
+    pub(crate) fn trace(&self, colored: bool) -> ::std::string::String {
+        let mut trace = ::std::format!("Scenario: {}\n", self.description);
+
+        let hooks = |kind: HookKind| {
+            self.hooks.iter().filter(move |hook| hook.kind == kind && hook.outcome.status() != Status::Passed).map(
+                move |hook| match kind {
+                    HookKind::Setup => (::std::string::String::from("world setup"), None, &hook.outcome),
+                    kind => (::std::format!("{} hook", kind.name()), None, &hook.outcome),
+                },
+            )
+        };
+
+        let steps = self.steps.iter().map(|step| {
+            (::std::format!("{} {}", step.label.keyword(), step.description), step.argument.as_ref(), &step.outcome)
+        });
+
+        let before = hooks(HookKind::BeforeAll).chain(hooks(HookKind::Setup)).chain(hooks(HookKind::Before));
+
+        for (line, argument, outcome) in before.chain(steps).chain(hooks(HookKind::After)) {
+            let status = match outcome.status() {
+                _ if outcome.timed_out() => "TIMED OUT",
+                Status::Passed => "ok",
+                Status::Failed => "FAILED",
+                status => status.name(),
+            };
+
+            trace.push_str(&::std::format!("  {} ... {}\n", line, status));
+
+            argument.into_iter().flat_map(StepArgument::lines).for_each(|line| {
+                trace.push_str(&::std::format!("      {}\n", line));
+            });
+
+            if let Some(failed) = outcome.failed() {
+                failed.render(colored).lines().for_each(|line| {
+                    trace.push_str(&::std::format!("    {}\n", line));
+                });
+            }
+        }
+
+        if !self.attempts.is_empty() {
+            let failures = self
+                .attempts
+                .iter()
+                .filter_map(|attempt| attempt.outcome.failed().cloned())
+                .collect::<::std::vec::Vec<_>>();
+            let summary = match failures.len() {
+                1 => ::std::string::String::from("Failed once before, with"),
+                count => ::std::format!("Failed {} times before, with", count),
+            };
+
+            Failed::listing(summary, &failures).render(colored).lines().for_each(|line| {
+                trace.push_str(&::std::format!("  {}\n", line));
+            });
+        }
+
+        trace.truncate(trace.trim_end().len());
+        trace
+    }
+}
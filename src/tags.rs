@@ -0,0 +1,308 @@
+use crate::models::*;
+
+/// A parsed [Cucumber tag expression](https://github.com/cucumber/tag-expressions),
+/// such as `@smoke and not (@slow or @flaky)`.
+///
+/// `not` binds tighter than `and`, which binds tighter than `or`. Tags are
+/// compared without their leading `@`, so `@smoke` matches both tags set as
+/// `"smoke"` with a builder and tags read as `@smoke` from a feature file.
+/// Spaces and parentheses within a tag are escaped with a backslash.
+#[derive(::core::fmt::Debug, ::core::clone::Clone)]
+pub struct Expression(Node);
+
+#[derive(::core::fmt::Debug, ::core::clone::Clone)]
+enum Node {
+    Tag(::std::string::String),
+    Not(::std::boxed::Box<Node>),
+    And(::std::boxed::Box<Node>, ::std::boxed::Box<Node>),
+    Or(::std::boxed::Box<Node>, ::std::boxed::Box<Node>),
+}
+
+#[derive(::core::fmt::Debug)]
+pub struct Error {
+    expression: ::std::string::String,
+    /// In characters, pointing at the offending token or at the end of the
+    /// expression.
+    position: usize,
+    message: ::std::string::String,
+}
+
+impl Expression {
+    pub fn matches(&self, tags: &Tags) -> bool {
+        self.0.matches(tags)
+    }
+}
+
+impl Node {
+    fn matches(&self, tags: &Tags) -> bool {
+        match self {
+            Self::Tag(name) => tags.iter().any(|tag| *tag == **name),
+            Self::Not(node) => !node.matches(tags),
+            Self::And(left, right) => left.matches(tags) && right.matches(tags),
+            Self::Or(left, right) => left.matches(tags) || right.matches(tags),
+        }
+    }
+}
+
+impl ::core::str::FromStr for Expression {
+    type Err = Error;
+
+    fn from_str(expression: &str) -> Result<Self, Self::Err> {
+        let tokens = tokenize(expression)?;
+
+        let mut parser = Parser {
+            expression,
+            tokens: tokens.into_iter().peekable(),
+        };
+
+        let node = parser.or()?;
+
+        match parser.tokens.next() {
+            None => Ok(Self(node)),
+            Some((Token::Close, position)) => Err(parser.error(position, "unmatched `)`")),
+            Some((token, position)) =>
+                Err(parser.error(position, ::std::format!("expected `and` or `or` before {}", token.name()))),
+        }
+    }
+}
+
+#[derive(::core::cmp::PartialEq, ::core::cmp::Eq)]
+enum Token {
+    Tag(::std::string::String),
+    And,
+    Or,
+    Not,
+    Open,
+    Close,
+}
+
+impl Token {
+    fn name(&self) -> ::std::string::String {
+        match self {
+            Self::Tag(name) => ::std::format!("tag `@{}`", name),
+            Self::And => "`and`".to_owned(),
+            Self::Or => "`or`".to_owned(),
+            Self::Not => "`not`".to_owned(),
+            Self::Open => "`(`".to_owned(),
+            Self::Close => "`)`".to_owned(),
+        }
+    }
+}
+
+/// Tokens with the position of their first character.
+fn tokenize(expression: &str) -> Result<::std::vec::Vec<(Token, usize)>, Error> {
+    let mut tokens = ::std::vec::Vec::new();
+    let mut chars = expression.chars().enumerate().peekable();
+
+    while let Some((position, char)) = chars.next() {
+        match char {
+            char if char.is_whitespace() => {},
+            '(' => tokens.push((Token::Open, position)),
+            ')' => tokens.push((Token::Close, position)),
+
+            char => {
+                let mut word = ::std::string::String::new();
+                let mut next = Some((position, char));
+
+                while let Some((position, char)) = next {
+                    match char {
+                        '\\' => match chars.next() {
+                            Some((_, escaped)) => word.push(escaped),
+                            None => return Err(error(expression, position, "`\\` must be followed by a character")),
+                        },
+                        char => word.push(char),
+                    }
+
+                    next = chars.next_if(|(_, char)| !char.is_whitespace() && *char != '(' && *char != ')');
+                }
+
+                let token = match word.as_str() {
+                    "and" => Token::And,
+                    "or" => Token::Or,
+                    "not" => Token::Not,
+
+                    tag => match tag.strip_prefix('@') {
+                        Some(name) if !name.is_empty() => Token::Tag(name.to_owned()),
+                        _ => {
+                            return Err(error(
+                                expression,
+                                position,
+                                ::std::format!("expected a tag starting with `@`, found `{}`", tag),
+                            ));
+                        },
+                    },
+                };
+
+                tokens.push((token, position));
+            },
+        }
+    }
+
+    Ok(tokens)
+}
+
+struct Parser<'a> {
+    expression: &'a str,
+    tokens: ::core::iter::Peekable<::std::vec::IntoIter<(Token, usize)>>,
+}
+
+impl Parser<'_> {
+    fn or(&mut self) -> Result<Node, Error> {
+        let mut node = self.and()?;
+
+        while self.tokens.next_if(|(token, _)| *token == Token::Or).is_some() {
+            node = Node::Or(node.into(), self.and()?.into());
+        }
+
+        Ok(node)
+    }
+
+    fn and(&mut self) -> Result<Node, Error> {
+        let mut node = self.not()?;
+
+        while self.tokens.next_if(|(token, _)| *token == Token::And).is_some() {
+            node = Node::And(node.into(), self.not()?.into());
+        }
+
+        Ok(node)
+    }
+
+    fn not(&mut self) -> Result<Node, Error> {
+        match self.tokens.next() {
+            Some((Token::Not, _)) => Ok(Node::Not(self.not()?.into())),
+            Some((Token::Tag(name), _)) => Ok(Node::Tag(name)),
+
+            Some((Token::Open, position)) => {
+                let node = self.or()?;
+
+                match self.tokens.next() {
+                    Some((Token::Close, _)) => Ok(node),
+                    Some((token, position)) =>
+                        Err(self.error(position, ::std::format!("expected `and`, `or` or `)`, found {}", token.name()))),
+                    None => Err(self.error(position, "unmatched `(`")),
+                }
+            },
+
+            Some((token, position)) =>
+                Err(self.error(position, ::std::format!("expected a tag, `not` or `(`, found {}", token.name()))),
+            None => Err(self.error(self.expression.chars().count(), "expected a tag, `not` or `(`")),
+        }
+    }
+
+    fn error(&self, position: usize, message: impl Into<::std::string::String>) -> Error {
+        error(self.expression, position, message)
+    }
+}
+
+fn error(expression: &str, position: usize, message: impl Into<::std::string::String>) -> Error {
+    Error {
+        expression: expression.to_owned(),
+        position,
+        message: message.into(),
+    }
+}
+
+impl ::core::fmt::Display for Error {
+    fn fmt(&self, formatter: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        ::core::writeln!(formatter, "invalid tag expression: {}", self.message)?;
+        ::core::writeln!(formatter, "  {}", self.expression)?;
+        ::core::write!(formatter, "  {}^", " ".repeat(self.position))
+    }
+}
+
+impl ::std::error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matches(expression: &str, tags: &[&'static str]) -> bool {
+        let expression = expression.parse::<Expression>().unwrap_or_else(|err| ::core::panic!("{}", err));
+        expression.matches(&tags.iter().map(|tag| ::std::borrow::Cow::Borrowed(*tag)).collect())
+    }
+
+    #[test]
+    fn expressions_match_as_their_precedence_and_parentheses_say() {
+        let cases: &[(&str, &[&str], bool)] = &[
+            ("@a", &["a"], true),
+            ("@a", &["b"], false),
+            ("@a", &[], false),
+            ("not @a", &[], true),
+            ("not not @a", &["a"], true),
+            // `and` binds tighter than `or`.
+            ("@a or @b and @c", &["a"], true),
+            ("@a or @b and @c", &["b"], false),
+            ("@a and @b or @c", &["c"], true),
+            ("@a and @b or @c", &["a"], false),
+            // `not` binds tighter than `and` and `or`.
+            ("not @a and @b", &["b"], true),
+            ("not @a and @b", &["a", "b"], false),
+            ("not @a or @b", &["a", "b"], true),
+            ("not @a or @b", &["a"], false),
+            // Parentheses override both.
+            ("(@a or @b) and @c", &["b"], false),
+            ("(@a or @b) and @c", &["b", "c"], true),
+            ("not (@a and @b)", &["a"], true),
+            ("not (@a and @b)", &["a", "b"], false),
+            ("((@a))", &["a"], true),
+            ("(@a)and(@b)", &["a", "b"], true),
+            // Escaped spaces and parentheses are part of the tag.
+            ("@with\\ space", &["with space"], true),
+            ("@f\\(x\\)", &["f(x)"], true),
+            ("@f\\(x\\)", &["f"], false),
+        ];
+
+        for &(expression, tags, expected) in cases {
+            ::core::assert_eq!(matches(expression, tags), expected, "`{}` against {:?}", expression, tags);
+        }
+    }
+
+    #[test]
+    fn expressions_match_tags_however_their_at_sign_was_written() {
+        let tags = ["@db", "smoke"].into_iter().map(::std::borrow::Cow::Borrowed).map(untagged).collect::<Tags>();
+        let expression = "@db and @smoke".parse::<Expression>().unwrap_or_else(|err| ::core::panic!("{}", err));
+
+        ::core::assert!(expression.matches(&tags));
+    }
+
+    #[test]
+    fn malformed_expressions_point_at_the_offending_token() {
+        let cases = [
+            ("", 0, "expected a tag, `not` or `(`"),
+            ("   ", 3, "expected a tag, `not` or `(`"),
+            ("@a and", 6, "expected a tag, `not` or `(`"),
+            ("smoke", 0, "expected a tag starting with `@`, found `smoke`"),
+            ("@a and @", 7, "expected a tag starting with `@`, found `@`"),
+            ("@a @b", 3, "expected `and` or `or` before tag `@b`"),
+            ("(@a", 0, "unmatched `(`"),
+            ("@a)", 2, "unmatched `)`"),
+            ("(@a @b)", 4, "expected `and`, `or` or `)`, found tag `@b`"),
+            ("@a and or @b", 7, "expected a tag, `not` or `(`, found `or`"),
+            ("@a and \\", 7, "`\\` must be followed by a character"),
+            // Positions are in characters rather than bytes.
+            ("@é and or @b", 7, "expected a tag, `not` or `(`, found `or`"),
+        ];
+
+        for (expression, position, message) in cases {
+            match expression.parse::<Expression>() {
+                Err(err) => {
+                    ::core::assert_eq!(err.position, position, "`{}`", expression);
+                    ::core::assert_eq!(err.message, message, "`{}`", expression);
+                },
+                Ok(parsed) => ::core::panic!("`{}` parsed as {:?}", expression, parsed),
+            }
+        }
+    }
+
+    #[test]
+    fn errors_print_a_caret_under_the_offending_token() {
+        let Err(err) = "@a @b".parse::<Expression>() else {
+            ::core::panic!("expected an error");
+        };
+
+        ::core::assert_eq!(
+            err.to_string(),
+            "invalid tag expression: expected `and` or `or` before tag `@b`\n  @a @b\n     ^"
+        );
+    }
+}
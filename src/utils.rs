@@ -40,3 +40,55 @@ pub mod aliases {
         pub type Arc<T> = ::triomphe::Arc<T>;
     }
 }
+
+/// Which side of a diff a line belongs to.
+#[derive(::core::clone::Clone, ::core::marker::Copy, ::core::cmp::PartialEq, ::core::cmp::Eq)]
+pub(crate) enum Change {
+    Same,
+    Expected,
+    Actual,
+}
+
+/// Lines of both sides, matched along their longest common subsequence.
+pub(crate) fn diff<'a, T>(expected: &'a [T], actual: &'a [T]) -> ::std::vec::Vec<(Change, &'a T)>
+where
+    T: ::core::cmp::PartialEq,
+{
+    let mut lengths = ::std::vec![::std::vec![0_usize; actual.len() + 1]; expected.len() + 1];
+
+    for (i, expected_line) in expected.iter().enumerate().rev() {
+        for (j, actual_line) in actual.iter().enumerate().rev() {
+            lengths[i][j] = match expected_line == actual_line {
+                true => lengths[i + 1][j + 1] + 1,
+                false => lengths[i + 1][j].max(lengths[i][j + 1]),
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    let mut lines = ::std::vec::Vec::new();
+
+    while i < expected.len() || j < actual.len() {
+        match (expected.get(i), actual.get(j)) {
+            (Some(expected_line), Some(actual_line)) if expected_line == actual_line => {
+                lines.push((Change::Same, expected_line));
+                (i, j) = (i + 1, j + 1);
+            },
+            (Some(expected_line), Some(_)) if lengths[i + 1][j] >= lengths[i][j + 1] => {
+                lines.push((Change::Expected, expected_line));
+                i += 1;
+            },
+            (_, Some(actual_line)) => {
+                lines.push((Change::Actual, actual_line));
+                j += 1;
+            },
+            (Some(expected_line), None) => {
+                lines.push((Change::Expected, expected_line));
+                i += 1;
+            },
+            (None, None) => break,
+        }
+    }
+
+    lines
+}